@@ -11,6 +11,13 @@ use clap::Parser;
 pub enum Backend {
     Rust,
     Mdbook,
+    Solidity,
+    C,
+    Protobuf,
+    OpenRpc,
+    Catalogue,
+    Markdown,
+    Html,
 }
 
 impl std::fmt::Display for Backend {
@@ -18,6 +25,13 @@ impl std::fmt::Display for Backend {
         f.write_str(match self {
             Backend::Rust => "rust",
             Backend::Mdbook => "doc-mdbook",
+            Backend::Solidity => "solidity",
+            Backend::C => "c",
+            Backend::Protobuf => "protobuf",
+            Backend::OpenRpc => "openrpc",
+            Backend::Catalogue => "catalogue",
+            Backend::Markdown => "doc-markdown",
+            Backend::Html => "doc-html",
         })
     }
 }
@@ -28,6 +42,13 @@ impl std::str::FromStr for Backend {
         match s.to_lowercase().as_str() {
             "rust" => Ok(Backend::Rust),
             "doc-mdbook" => Ok(Backend::Mdbook),
+            "solidity" => Ok(Backend::Solidity),
+            "c" => Ok(Backend::C),
+            "protobuf" => Ok(Backend::Protobuf),
+            "openrpc" => Ok(Backend::OpenRpc),
+            "catalogue" => Ok(Backend::Catalogue),
+            "doc-markdown" => Ok(Backend::Markdown),
+            "doc-html" => Ok(Backend::Html),
             _ => Err("Unrecognized backend".into()),
         }
     }
@@ -38,6 +59,13 @@ impl From<Backend> for zksync_error_codegen::arguments::Backend {
         match value {
             Backend::Rust => Self::Rust,
             Backend::Mdbook => Self::Mdbook,
+            Backend::Solidity => Self::Solidity,
+            Backend::C => Self::C,
+            Backend::Protobuf => Self::Protobuf,
+            Backend::OpenRpc => Self::OpenRpc,
+            Backend::Catalogue => Self::Catalogue,
+            Backend::Markdown => Self::Markdown,
+            Backend::Html => Self::Html,
         }
     }
 }
@@ -1,21 +1,20 @@
 use std::collections::BTreeMap;
+use std::io::IsTerminal as _;
 
-use zksync_error_codegen::arguments::{BackendOutput, ResolutionMode};
+use clap::ColorChoice;
+use zksync_error_codegen::arguments::{
+    BackendOutput, ExplanationFormat, ModelFilter, ModelSource, ResolutionMode,
+};
 
 use crate::{arguments::Mode, error::ApplicationError};
 
-use super::Arguments;
+use super::{Arguments, ExplainArguments, Resolution};
 
-impl TryFrom<Arguments> for zksync_error_codegen::arguments::GenerationArguments {
+impl TryFrom<Resolution> for ResolutionMode {
     type Error = ApplicationError;
 
-    fn try_from(value: Arguments) -> Result<Self, Self::Error> {
-        let Arguments {
-            sources,
-            backend,
-            verbose,
-            output_directory,
-            backend_args,
+    fn try_from(value: Resolution) -> Result<Self, Self::Error> {
+        let Resolution {
             remap,
             mode,
             lock_file,
@@ -33,7 +32,7 @@ impl TryFrom<Arguments> for zksync_error_codegen::arguments::GenerationArguments
         };
 
         const DEFAULT_LOCK_FILE_NAME: &str = "zksync-error.lock";
-        let resolution_mode = match mode {
+        Ok(match mode {
             Mode::NoLock => ResolutionMode::NoLock {
                 override_links: override_map.into_iter().collect(),
             },
@@ -44,17 +43,91 @@ impl TryFrom<Arguments> for zksync_error_codegen::arguments::GenerationArguments
             Mode::Frozen => ResolutionMode::Reproducible {
                 lock_file: lock_file.unwrap_or(DEFAULT_LOCK_FILE_NAME.to_owned()),
             },
-        };
+        })
+    }
+}
+
+impl TryFrom<Arguments> for zksync_error_codegen::arguments::GenerationArguments {
+    type Error = ApplicationError;
+
+    fn try_from(value: Arguments) -> Result<Self, Self::Error> {
+        let Arguments {
+            command: _,
+            sources,
+            backend,
+            verbose,
+            output_directory,
+            backend_args,
+            resolution,
+            include_domains,
+            exclude_domains,
+            include_components,
+            exclude_components,
+        } = value;
+
+        let backend = backend.ok_or_else(|| ApplicationError::InvalidArgument {
+            argument: "--backend".into(),
+            reason: "a backend is required to generate files".into(),
+        })?;
 
         Ok(zksync_error_codegen::arguments::GenerationArguments {
             verbose,
             input_links: sources,
-            mode: resolution_mode,
+            mode: resolution.try_into()?,
             outputs: vec![BackendOutput {
                 output_path: output_directory.into(),
                 backend: backend.into(),
                 arguments: backend_args,
             }],
+            filter: ModelFilter {
+                include_domains,
+                exclude_domains,
+                include_components,
+                exclude_components,
+            },
+        })
+    }
+}
+
+impl TryFrom<ExplainArguments> for zksync_error_codegen::arguments::ExplanationArguments {
+    type Error = ApplicationError;
+
+    fn try_from(value: ExplainArguments) -> Result<Self, Self::Error> {
+        let ExplainArguments {
+            error,
+            sources,
+            dump,
+            resolution,
+            json,
+            color,
+            verbose,
+        } = value;
+
+        let source = match dump {
+            Some(path) => ModelSource::Dump(path.into()),
+            None => ModelSource::Links {
+                input_links: sources,
+                mode: resolution.try_into()?,
+            },
+        };
+        let format = if json {
+            ExplanationFormat::Json
+        } else {
+            let color = match color {
+                ColorChoice::Always => true,
+                ColorChoice::Never => false,
+                ColorChoice::Auto => {
+                    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+                }
+            };
+            ExplanationFormat::Text { color }
+        };
+
+        Ok(zksync_error_codegen::arguments::ExplanationArguments {
+            verbose,
+            source,
+            error,
+            format,
         })
     }
 }
@@ -0,0 +1,33 @@
+use clap::ColorChoice;
+
+use super::Resolution;
+
+#[derive(Debug, clap::Args)]
+pub struct ExplainArguments {
+    /// Code of the error, e.g. `11001`, or its identifier, e.g. `core-seq-1`.
+    pub error: String,
+
+    /// Source JSON file. Should be repeated for every file.
+    #[arg(long = "source", required_unless_present = "dump")]
+    pub sources: Vec<String>,
+
+    /// Dump of the error hierarchy, `resources/error-model-dump.json` in a
+    /// generated Rust crate, to read the model from instead of the sources.
+    #[arg(long = "dump", conflicts_with = "sources")]
+    pub dump: Option<String>,
+
+    #[command(flatten)]
+    pub resolution: Resolution,
+
+    /// Print the error as JSON.
+    #[arg(long = "json")]
+    pub json: bool,
+
+    /// Highlight the explanation with colors.
+    #[arg(long = "color", default_value = "auto")]
+    pub color: ColorChoice,
+
+    /// Be verbose and produce debug output.
+    #[arg(long = "verbose", short = 'v')]
+    pub verbose: bool,
+}
@@ -1,12 +1,16 @@
 pub mod backend;
 pub mod conversion;
+pub mod explain;
 pub mod mode;
+pub mod resolution;
 
 use clap::Error as ClapError;
 use clap::Parser;
 
 pub use backend::Backend;
+pub use explain::ExplainArguments;
 pub use mode::Mode;
+pub use resolution::Resolution;
 
 ///
 /// Generates one of the following:
@@ -17,9 +21,14 @@ pub use mode::Mode;
 #[command(
     version,
     about,
-    long_about = "Generator of the error handling code in ZKsync components."
+    long_about = "Generator of the error handling code in ZKsync components.",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
 )]
 pub struct Arguments {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Source JSON file. Should be repeated for every file.
     #[arg(long = "source")]
     pub sources: Vec<String>,
@@ -27,8 +36,9 @@ pub struct Arguments {
     /// Selected backend.
     #[arg(short = 'b',
           long = "backend",
+          required = true,
           value_parser = clap::value_parser!(Backend))]
-    pub backend: Backend,
+    pub backend: Option<Backend>,
 
     /// Be verbose and produce debug output.
     #[arg(long = "verbose", short = 'v')]
@@ -47,17 +57,31 @@ pub struct Arguments {
     )]
     pub backend_args: Vec<(String, String)>,
 
-    /// Remap links. Accepts a JSON.
-    #[arg(long = "remap")]
-    pub remap: Option<String>,
+    #[command(flatten)]
+    pub resolution: Resolution,
+
+    /// Keep only the given domain, by name or encoding. Can be repeated.
+    #[arg(long = "include-domain")]
+    pub include_domains: Vec<String>,
+
+    /// Drop the given domain, by name or encoding. Can be repeated.
+    #[arg(long = "exclude-domain")]
+    pub exclude_domains: Vec<String>,
 
-    /// Build mode for dependency resolution
-    #[arg(long = "mode", default_value = "normal")]
-    pub mode: Mode,
+    /// Keep only the given component, by name or as `<domain>-<component>` encodings. Can be repeated.
+    #[arg(long = "include-component")]
+    pub include_components: Vec<String>,
+
+    /// Drop the given component, by name or as `<domain>-<component>` encodings. Can be repeated.
+    #[arg(long = "exclude-component")]
+    pub exclude_components: Vec<String>,
+}
 
-    /// Lock file path for dependency resolution
-    #[arg(long = "lock-file")]
-    pub lock_file: Option<String>,
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Explain an error of the model, given its code, e.g. `11001`, or its
+    /// identifier, e.g. `core-seq-1`.
+    Explain(ExplainArguments),
 }
 
 ///
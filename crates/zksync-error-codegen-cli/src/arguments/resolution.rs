@@ -0,0 +1,17 @@
+use super::Mode;
+
+/// Resolution of the links between the description files.
+#[derive(Debug, clap::Args)]
+pub struct Resolution {
+    /// Remap links. Accepts a JSON.
+    #[arg(long = "remap")]
+    pub remap: Option<String>,
+
+    /// Build mode for dependency resolution
+    #[arg(long = "mode", default_value = "normal")]
+    pub mode: Mode,
+
+    /// Lock file path for dependency resolution
+    #[arg(long = "lock-file")]
+    pub lock_file: Option<String>,
+}
@@ -4,12 +4,23 @@ pub mod error;
 use clap::Parser;
 
 use arguments::Arguments;
+use arguments::Command;
 
 use error::ApplicationError;
+use zksync_error_codegen::load_and_explain;
 use zksync_error_codegen::load_and_generate;
 
 fn main_inner(arguments: Arguments) -> Result<(), ApplicationError> {
-    Ok(load_and_generate(arguments.try_into()?).map_err(Box::new)?)
+    match arguments.command {
+        Some(Command::Explain(arguments)) => {
+            print!(
+                "{}",
+                load_and_explain(arguments.try_into()?).map_err(Box::new)?
+            );
+            Ok(())
+        }
+        None => Ok(load_and_generate(arguments.try_into()?).map_err(Box::new)?),
+    }
 }
 
 fn main() {
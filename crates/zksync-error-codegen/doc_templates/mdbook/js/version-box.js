@@ -1,41 +1,45 @@
 document.addEventListener("DOMContentLoaded", function() {
-    // Get the base URL from the mdBook configuration
-    const baseUrl = document.location.pathname.split('/').slice(0, -2).join('/');
+    // mdBook defines `path_to_root`, the path from the current page to the
+    // root of the book. Books of all versions are built next to each other,
+    // along with `versions.json` mapping the names of versions to their
+    // directories.
+    const pathToRoot = typeof path_to_root === "string" ? path_to_root : "";
+    const bookRoot = new URL(pathToRoot || "./", window.location.href);
+    const siteRoot = new URL("../", bookRoot);
+    const currentVersion = bookRoot.href.slice(siteRoot.href.length).replace(/\/$/, "");
+    const currentPage = window.location.href.slice(bookRoot.href.length);
 
     // Function to create version selector
     function createVersionSelector(versions) {
         const versionSelector = document.createElement("select");
         versionSelector.id = "version-selector";
 
-        // Get the current path
-        const currentPath = window.location.pathname;
-
         // Iterate over the versions object
-        for (const [versionName, versionUrl] of Object.entries(versions)) {
+        for (const [versionName, versionDirectory] of Object.entries(versions)) {
             const option = document.createElement("option");
-            option.value = baseUrl + versionUrl; // Prepend base URL
+            option.value = versionDirectory;
             option.textContent = versionName;
 
-            // Check if the current URL matches this option's value
-            if (currentPath === option.value + '/') {
+            // Check if the current book is this version
+            if (versionDirectory === currentVersion) {
                 option.selected = true; // Set this option as selected
             }
 
             versionSelector.appendChild(option);
         }
 
-        // Event listener to handle version change
+        // Event listener to handle version change: open the same page in the
+        // selected version.
         versionSelector.addEventListener("change", function() {
             const selectedVersion = versionSelector.value;
-            // Redirect to the selected version URL
-            window.location.href = selectedVersion;
+            window.location.href = new URL(selectedVersion + "/" + currentPage, siteRoot).href;
         });
 
         return versionSelector;
     }
 
     // Fetch versions from JSON file
-    fetch(baseUrl + '/versions.json')
+    fetch(new URL("versions.json", siteRoot))
         .then(response => {
             if (!response.ok) {
                 throw new Error('Network response was not ok ' + response.statusText);
@@ -6,6 +6,8 @@
 
 use std::path::PathBuf;
 
+pub use zksync_error_model::filter::ModelFilter;
+
 /// Configuration for a single backend output.
 ///
 /// Each backend output represents a target format for the generated code,
@@ -68,12 +70,21 @@ pub struct GenerationArguments {
     pub mode: ResolutionMode,
     /// List of backend outputs to generate
     pub outputs: Vec<BackendOutput>,
+    /// Domains and components to keep in the model passed to the backends
+    pub filter: ModelFilter,
 }
 
 #[derive(Clone, Debug)]
 pub enum Backend {
     Rust,
     Mdbook,
+    Solidity,
+    C,
+    Protobuf,
+    OpenRpc,
+    Catalogue,
+    Markdown,
+    Html,
 }
 
 impl std::fmt::Display for Backend {
@@ -81,6 +92,49 @@ impl std::fmt::Display for Backend {
         f.write_str(match self {
             Backend::Rust => "rust",
             Backend::Mdbook => "doc-mdbook",
+            Backend::Solidity => "solidity",
+            Backend::C => "c",
+            Backend::Protobuf => "protobuf",
+            Backend::OpenRpc => "openrpc",
+            Backend::Catalogue => "catalogue",
+            Backend::Markdown => "doc-markdown",
+            Backend::Html => "doc-html",
         })
     }
 }
+
+/// Where the model to explain errors from is loaded from.
+pub enum ModelSource {
+    /// Description files, resolved as for code generation.
+    Links {
+        /// List of input link strings to process
+        input_links: Vec<String>,
+        /// Dependency resolution mode configuration
+        mode: ResolutionMode,
+    },
+    /// Dump of the error hierarchy shipped with a generated Rust crate, i.e.
+    /// its `resources/error-model-dump.json`.
+    Dump(PathBuf),
+}
+
+/// Output format of the explanation of an error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExplanationFormat {
+    /// Human-readable text, highlighted with ANSI escape codes if `color` is set.
+    Text { color: bool },
+    /// JSON object describing the error.
+    Json,
+}
+
+/// Arguments to explain an error of the model, like `rustc --explain`.
+pub struct ExplanationArguments {
+    /// Enable verbose logging while loading the model
+    pub verbose: bool,
+    /// Source of the model
+    pub source: ModelSource,
+    /// Encoded code of the error, e.g. `11001`, or its identifier, e.g.
+    /// `core-seq-1` or `[core-seq-1]`
+    pub error: String,
+    /// Output format
+    pub format: ExplanationFormat,
+}
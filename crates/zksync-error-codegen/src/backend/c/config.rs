@@ -0,0 +1,46 @@
+use crate::backend::IBackendConfig;
+use crate::backend::arguments::ArgumentError;
+
+pub struct CBackendConfig {
+    /// Prefix of all C identifiers, also naming the header and the shim
+    /// library, `zksync_error` by default.
+    pub prefix: String,
+}
+
+impl Default for CBackendConfig {
+    fn default() -> Self {
+        Self {
+            prefix: Self::DEFAULT_PREFIX.to_owned(),
+        }
+    }
+}
+
+impl CBackendConfig {
+    pub const DEFAULT_PREFIX: &str = "zksync_error";
+}
+
+impl IBackendConfig for CBackendConfig {
+    fn parse_arguments(
+        args: impl Iterator<Item = (String, String)>,
+    ) -> Result<Self, ArgumentError> {
+        let mut config = CBackendConfig::default();
+        for (arg, val) in args {
+            match arg.as_str() {
+                "prefix" => {
+                    let valid = val.starts_with(|c: char| c.is_ascii_alphabetic())
+                        && val.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                    if !valid {
+                        return Err(ArgumentError::InvalidArgument {
+                            value: val,
+                            argument: arg,
+                            message: "expected a C identifier".into(),
+                        });
+                    }
+                    config.prefix = val
+                }
+                _ => return Err(ArgumentError::UnsupportedArgument { argument: arg }),
+            }
+        }
+        Ok(config)
+    }
+}
@@ -0,0 +1,9 @@
+use zksync_error_model::error::ModelValidationError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GenerationError {
+    #[error(transparent)]
+    ModelError(#[from] ModelValidationError),
+    #[error("rustfmt: {0:#?}")]
+    FormatError(#[from] rustfmt_wrapper::Error),
+}
@@ -0,0 +1,321 @@
+pub mod config;
+pub mod error;
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use config::CBackendConfig;
+use error::GenerationError;
+use proc_macro2::Literal;
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use zksync_error_model::inner::Model;
+
+use super::Backend;
+use super::File;
+use crate::backend::rust::util::snake_case;
+
+pub struct CBackend {
+    config: CBackendConfig,
+    model: Model,
+}
+
+/// Converts a name or an identifier encoding into a part of a C macro name,
+/// e.g. `GenericError` into `GENERIC_ERROR`.
+fn macro_case(name: &str) -> String {
+    snake_case(name)
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// NUL-terminated byte string literal, to be passed to C as `const char *`.
+fn c_string(value: &str) -> Literal {
+    let mut bytes: Vec<u8> = value.bytes().filter(|&b| b != 0).collect();
+    bytes.push(0);
+    Literal::byte_string(&bytes)
+}
+
+impl CBackend {
+    const PREAMBLE: &str = "AUTOGENERATED BASED ON A SET OF JSON FILES, DO NOT EDIT MANUALLY";
+
+    fn macro_prefix(&self) -> String {
+        self.config.prefix.to_ascii_uppercase()
+    }
+
+    fn shim_crate_name(&self) -> String {
+        format!("{}_c", self.config.prefix)
+    }
+
+    fn generate_header(&self) -> File {
+        let prefix = &self.config.prefix;
+        let macro_prefix = self.macro_prefix();
+        let mut content = String::new();
+
+        writeln!(content, "/*\n * {}\n */", Self::PREAMBLE).unwrap();
+        writeln!(
+            content,
+            "#ifndef {macro_prefix}_H\n#define {macro_prefix}_H\n"
+        )
+        .unwrap();
+        writeln!(content, "#include <stdint.h>\n").unwrap();
+        writeln!(content, "#ifdef __cplusplus\nextern \"C\" {{\n#endif\n").unwrap();
+
+        writeln!(
+            content,
+            "/* An encoded error code is domain * 10000 + component * 1000 + error. */
+#define {macro_prefix}_ENCODE(domain, component, error) \\
+    ((uint32_t)(domain) * 10000u + (uint32_t)(component) * 1000u + (uint32_t)(error))
+#define {macro_prefix}_DOMAIN_CODE(code) (((uint32_t)(code) / 10000u) % 10u)
+#define {macro_prefix}_COMPONENT_CODE(code) (((uint32_t)(code) / 1000u) % 10u)
+#define {macro_prefix}_ERROR_CODE(code) ((uint32_t)(code) % 1000u)
+"
+        )
+        .unwrap();
+
+        writeln!(content, "/* Domains. */\nenum {prefix}_domain {{").unwrap();
+        for domain in self.model.domains.values() {
+            let identifier = &domain.meta.identifier;
+            writeln!(
+                content,
+                "    {macro_prefix}_DOMAIN_{} = {}, /* {} */",
+                macro_case(&identifier.encoding),
+                identifier.code,
+                identifier.name
+            )
+            .unwrap();
+        }
+        writeln!(content, "}};\n").unwrap();
+
+        for domain in self.model.domains.values() {
+            let domain_identifier = &domain.meta.identifier;
+            let domain_encoding = macro_case(&domain_identifier.encoding);
+            writeln!(
+                content,
+                "/* Components of the domain {}. */\nenum {prefix}_{}_component {{",
+                domain_identifier.name,
+                snake_case(&domain_identifier.encoding)
+            )
+            .unwrap();
+            for component in domain.components.values() {
+                let identifier = &component.meta.identifier;
+                writeln!(
+                    content,
+                    "    {macro_prefix}_COMPONENT_{domain_encoding}_{} = {}, /* {} */",
+                    macro_case(&identifier.encoding),
+                    identifier.code,
+                    identifier.name
+                )
+                .unwrap();
+            }
+            writeln!(content, "}};\n").unwrap();
+        }
+
+        for component in self.model.components() {
+            let identifier = &component.meta.identifier;
+            let component_prefix = format!(
+                "{macro_prefix}_{}_{}",
+                macro_case(&component.meta.domain.identifier.encoding),
+                macro_case(&identifier.encoding)
+            );
+            writeln!(
+                content,
+                "/* Encoded codes of the errors of the component {}. */",
+                identifier.name
+            )
+            .unwrap();
+            for error in &component.errors {
+                writeln!(
+                    content,
+                    "#define {component_prefix}_{} {}u /* {} */",
+                    macro_case(&error.name),
+                    error.encoded_code(),
+                    error.get_identifier()
+                )
+                .unwrap();
+            }
+            content.push('\n');
+        }
+
+        writeln!(
+            content,
+            "/*
+ * Implemented by the library `{}`. The functions look up the encoded
+ * `code` and return static NUL-terminated strings, or NULL if no error has
+ * this code or, for the summary, if the error is not documented.
+ */
+const char *{prefix}_identifier(uint32_t code);
+const char *{prefix}_name(uint32_t code);
+const char *{prefix}_message(uint32_t code);
+const char *{prefix}_summary(uint32_t code);
+",
+            self.shim_crate_name()
+        )
+        .unwrap();
+
+        writeln!(
+            content,
+            "#ifdef __cplusplus\n}}\n#endif\n\n#endif /* {macro_prefix}_H */"
+        )
+        .unwrap();
+
+        File {
+            relative_path: PathBuf::from("include").join(format!("{prefix}.h")),
+            content,
+        }
+    }
+
+    fn generate_shim_manifest(&self) -> File {
+        File {
+            relative_path: PathBuf::from("shim/Cargo.toml"),
+            content: format!(
+                r#"######################################
+# {}
+######################################
+
+[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+[lib]
+crate-type = ["cdylib", "staticlib"]
+"#,
+                Self::PREAMBLE,
+                self.shim_crate_name()
+            ),
+        }
+    }
+
+    fn generate_shim(&self) -> Result<File, GenerationError> {
+        let prefix = &self.config.prefix;
+        let entries = self.model.errors().map(|error| {
+            let code = error.encoded_code();
+            let identifier = c_string(&error.get_identifier().to_string());
+            let name = c_string(&error.name);
+            let message = c_string(&error.message);
+            let summary = match error
+                .documentation
+                .as_ref()
+                .and_then(|documentation| documentation.summary.as_ref())
+            {
+                Some(summary) => {
+                    let summary = c_string(summary);
+                    quote! { Some(#summary) }
+                }
+                None => quote! { None },
+            };
+            quote! {
+                ErrorEntry {
+                    code: #code,
+                    identifier: #identifier,
+                    name: #name,
+                    message: #message,
+                    summary: #summary,
+                },
+            }
+        });
+
+        let function = |suffix: &str, doc: &str, getter: TokenStream| {
+            let name = format_ident!("{prefix}_{suffix}");
+            quote! {
+                #[doc = #doc]
+                #[no_mangle]
+                pub extern "C" fn #name(code: u32) -> *const c_char {
+                    lookup(code)
+                        #getter
+                        .map_or(core::ptr::null(), |string| string.as_ptr().cast())
+                }
+            }
+        };
+        let functions = [
+            function(
+                "identifier",
+                " Identifier of the error, e.g. `[core-seq-1]`.",
+                quote! { .map(|entry| entry.identifier) },
+            ),
+            function(
+                "name",
+                " Name of the error.",
+                quote! { .map(|entry| entry.name) },
+            ),
+            function(
+                "message",
+                " Template of the error message, with the names of fields in braces.",
+                quote! { .map(|entry| entry.message) },
+            ),
+            function(
+                "summary",
+                " Summary of the documentation of the error, if any.",
+                quote! { .and_then(|entry| entry.summary) },
+            ),
+        ];
+
+        let contents = quote! {
+            #![allow(clippy::unreadable_literal)]
+
+            use core::ffi::c_char;
+
+            /// Strings are NUL-terminated.
+            struct ErrorEntry {
+                /// Encoded code, compared as a whole so that codes with
+                /// out-of-range parts, e.g. `911001`, match no error.
+                code: u32,
+                identifier: &'static [u8],
+                name: &'static [u8],
+                message: &'static [u8],
+                summary: Option<&'static [u8]>,
+            }
+
+            static ERRORS: &[ErrorEntry] = &[ #( #entries )* ];
+
+            fn lookup(code: u32) -> Option<&'static ErrorEntry> {
+                ERRORS.iter().find(|entry| entry.code == code)
+            }
+
+            #( #functions )*
+        };
+
+        let content =
+            rustfmt_wrapper::rustfmt(format!("//\n// {}\n//\n{contents}", Self::PREAMBLE))?;
+        Ok(File {
+            relative_path: PathBuf::from("shim/src/lib.rs"),
+            content,
+        })
+    }
+}
+
+impl Backend for CBackend {
+    type Config = CBackendConfig;
+    type GenerationError = GenerationError;
+
+    fn get_name() -> &'static str {
+        "c"
+    }
+
+    fn get_language_name() -> &'static str {
+        "c"
+    }
+
+    fn generate(&mut self) -> Result<Vec<File>, Self::GenerationError> {
+        Ok(vec![
+            self.generate_header(),
+            self.generate_shim_manifest(),
+            self.generate_shim()?,
+        ])
+    }
+
+    fn new(config: Self::Config, model: &Model) -> Self {
+        Self {
+            config,
+            model: model.clone(),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+use crate::backend::IBackendConfig;
+use crate::backend::arguments::ArgumentError;
+use crate::backend::arguments::parse_bool;
+
+/// Column of the catalogue, named in arguments as in the header, e.g.
+/// `likely_causes`.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    strum_macros::AsRefStr,
+    strum_macros::EnumString,
+    strum_macros::VariantArray,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum Column {
+    /// Identifier of the error, e.g. `[core-seq-1]`.
+    Identifier,
+    /// Encoded code of the error, e.g. `11001`.
+    Code,
+    Domain,
+    Component,
+    Name,
+    /// Message template.
+    Message,
+    /// Summary of the documentation.
+    Summary,
+    /// Description of the documentation.
+    Description,
+    /// Likely causes of the error.
+    LikelyCauses,
+    /// Fixes of all likely causes.
+    Fixes,
+    /// Owners of the likely causes, with their versions.
+    Owners,
+    /// Description files the error is defined in.
+    Origins,
+    Visibility,
+}
+
+impl Column {
+    pub const DEFAULT: &[Column] = &[
+        Column::Identifier,
+        Column::Code,
+        Column::Domain,
+        Column::Component,
+        Column::Name,
+        Column::Message,
+        Column::Summary,
+        Column::LikelyCauses,
+        Column::Owners,
+        Column::Origins,
+    ];
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum_macros::AsRefStr, strum_macros::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum Format {
+    Csv,
+    Jsonl,
+}
+
+pub struct CatalogueBackendConfig {
+    /// Columns of the catalogue, in order.
+    pub columns: Vec<Column>,
+    /// Formats to produce, each in its own file.
+    pub formats: Vec<Format>,
+    /// Leave internal domains, components and errors out of the catalogue.
+    pub hide_internal: bool,
+}
+
+impl Default for CatalogueBackendConfig {
+    fn default() -> Self {
+        Self {
+            columns: Column::DEFAULT.to_vec(),
+            formats: vec![Format::Csv, Format::Jsonl],
+            hide_internal: false,
+        }
+    }
+}
+
+/// Parses a comma-separated list without duplicates, e.g. `code,name`.
+fn parse_list<T: FromStr + PartialEq>(
+    arg: &str,
+    val: &str,
+    expected: &str,
+) -> Result<Vec<T>, ArgumentError> {
+    let invalid = |message: String| ArgumentError::InvalidArgument {
+        value: val.to_owned(),
+        argument: arg.to_owned(),
+        message,
+    };
+    let mut result = vec![];
+    for item in val.split(',').map(str::trim) {
+        let parsed = T::from_str(item)
+            .map_err(|_| invalid(format!("unknown item `{item}`, expected {expected}")))?;
+        if result.contains(&parsed) {
+            return Err(invalid(format!("`{item}` is listed twice")));
+        }
+        result.push(parsed);
+    }
+    Ok(result)
+}
+
+impl IBackendConfig for CatalogueBackendConfig {
+    fn parse_arguments(
+        args: impl Iterator<Item = (String, String)>,
+    ) -> Result<Self, ArgumentError> {
+        let mut config = CatalogueBackendConfig::default();
+        for (arg, val) in args {
+            match arg.as_str() {
+                "columns" => {
+                    let expected = <Column as strum::VariantArray>::VARIANTS
+                        .iter()
+                        .map(AsRef::as_ref)
+                        .collect::<Vec<&str>>()
+                        .join(", ");
+                    config.columns = parse_list(&arg, &val, &expected)?;
+                }
+                "formats" => config.formats = parse_list(&arg, &val, "csv, jsonl")?,
+                "hide_internal" => parse_bool(&arg, &val, &mut config.hide_internal)?,
+                _ => return Err(ArgumentError::UnsupportedArgument { argument: arg }),
+            }
+        }
+        Ok(config)
+    }
+}
@@ -0,0 +1,9 @@
+use zksync_error_model::error::ModelValidationError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GenerationError {
+    #[error(transparent)]
+    ModelError(#[from] ModelValidationError),
+    #[error(transparent)]
+    Serialization(#[from] serde_json::Error),
+}
@@ -0,0 +1,201 @@
+pub mod config;
+pub mod error;
+
+use std::path::PathBuf;
+
+use config::CatalogueBackendConfig;
+use config::Column;
+use config::Format;
+use error::GenerationError;
+use serde_json::Value;
+use zksync_error_model::inner::Model;
+use zksync_error_model::unpacked::ErrorDescription;
+use zksync_error_model::unpacked::UnpackedModel;
+use zksync_error_model::unpacked::flatten;
+
+use super::Backend;
+use super::File;
+
+pub struct CatalogueBackend {
+    config: CatalogueBackendConfig,
+    model: Model,
+}
+
+/// Value of a cell of the catalogue.
+enum Cell {
+    Text(String),
+    Number(u32),
+    List(Vec<String>),
+    Empty,
+}
+
+impl Cell {
+    fn text(value: impl Into<String>) -> Self {
+        Cell::Text(value.into())
+    }
+
+    /// Items of lists are put on separate lines of the cell.
+    fn to_csv(&self) -> String {
+        let text = match self {
+            Cell::Text(text) => text.clone(),
+            Cell::Number(number) => number.to_string(),
+            Cell::List(items) => items.join("\n"),
+            Cell::Empty => String::new(),
+        };
+        csv_field(&text)
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            Cell::Text(text) => Value::from(text.as_str()),
+            Cell::Number(number) => Value::from(*number),
+            Cell::List(items) => Value::from(items.as_slice()),
+            Cell::Empty => Value::Null,
+        }
+    }
+}
+
+/// Quotes a CSV field if needed, as described in RFC 4180.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_owned()
+    }
+}
+
+fn cell(model: &UnpackedModel, error: &ErrorDescription, column: Column) -> Cell {
+    let likely_causes = error
+        .documentation
+        .iter()
+        .flat_map(|documentation| &documentation.likely_causes);
+    match column {
+        Column::Identifier => Cell::text(&error.identifier),
+        Column::Code => Cell::Number(model.encoded_code(error)),
+        Column::Domain => Cell::text(&error.domain),
+        Column::Component => Cell::text(&error.component),
+        Column::Name => Cell::text(&error.name),
+        Column::Message => Cell::text(&error.message),
+        Column::Summary => match &error.documentation {
+            Some(documentation) if !documentation.summary.is_empty() => {
+                Cell::text(&documentation.summary)
+            }
+            _ => Cell::Empty,
+        },
+        Column::Description => match &error.documentation {
+            Some(documentation) => Cell::text(&documentation.description),
+            None => Cell::Empty,
+        },
+        Column::LikelyCauses => {
+            Cell::List(likely_causes.map(|cause| cause.cause.clone()).collect())
+        }
+        Column::Fixes => Cell::List(
+            likely_causes
+                .flat_map(|cause| cause.fixes.iter().cloned())
+                .collect(),
+        ),
+        Column::Owners => {
+            let mut owners: Vec<String> = vec![];
+            for owner in likely_causes.filter_map(|cause| cause.owner.as_ref()) {
+                let owner = format!("{}@{}", owner.name, owner.version);
+                if !owners.contains(&owner) {
+                    owners.push(owner);
+                }
+            }
+            Cell::List(owners)
+        }
+        Column::Origins => Cell::List(error.origins.clone()),
+        Column::Visibility => Cell::text(match error.visibility.is_public() {
+            true => "public",
+            false => "internal",
+        }),
+    }
+}
+
+impl CatalogueBackend {
+    /// Errors of the catalogue, ordered by their encoded codes.
+    fn errors<'a>(&self, model: &'a UnpackedModel) -> Vec<&'a ErrorDescription> {
+        let mut errors: Vec<_> = model.errors.values().collect();
+        errors.sort_by_key(|error| model.encoded_code(error));
+        errors
+    }
+
+    fn generate_csv(&self, model: &UnpackedModel) -> File {
+        let columns = &self.config.columns;
+        let header: Vec<_> = columns.iter().map(|column| column.as_ref()).collect();
+        let mut content = header.join(",") + "\n";
+        for error in self.errors(model) {
+            let row: Vec<_> = columns
+                .iter()
+                .map(|column| cell(model, error, *column).to_csv())
+                .collect();
+            content.push_str(&row.join(","));
+            content.push('\n');
+        }
+        File {
+            relative_path: PathBuf::from("catalogue.csv"),
+            content,
+        }
+    }
+
+    /// One JSON object per line, with the keys in the order of the columns.
+    fn generate_jsonl(&self, model: &UnpackedModel) -> Result<File, GenerationError> {
+        let mut content = String::new();
+        for error in self.errors(model) {
+            let entries = self
+                .config
+                .columns
+                .iter()
+                .map(|column| {
+                    Ok(format!(
+                        "{}:{}",
+                        serde_json::to_string(column.as_ref())?,
+                        serde_json::to_string(&cell(model, error, *column).to_json())?
+                    ))
+                })
+                .collect::<Result<Vec<_>, serde_json::Error>>()?;
+            content.push('{');
+            content.push_str(&entries.join(","));
+            content.push_str("}\n");
+        }
+        Ok(File {
+            relative_path: PathBuf::from("catalogue.jsonl"),
+            content,
+        })
+    }
+}
+
+impl Backend for CatalogueBackend {
+    type Config = CatalogueBackendConfig;
+    type GenerationError = GenerationError;
+
+    fn get_name() -> &'static str {
+        "catalogue"
+    }
+
+    fn get_language_name() -> &'static str {
+        "catalogue"
+    }
+
+    fn generate(&mut self) -> Result<Vec<File>, Self::GenerationError> {
+        let mut model = flatten(&self.model);
+        if self.config.hide_internal {
+            model = model.without_internal();
+        }
+        self.config
+            .formats
+            .iter()
+            .map(|format| match format {
+                Format::Csv => Ok(self.generate_csv(&model)),
+                Format::Jsonl => self.generate_jsonl(&model),
+            })
+            .collect()
+    }
+
+    fn new(config: Self::Config, model: &Model) -> Self {
+        Self {
+            config,
+            model: model.clone(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+use crate::backend::IBackendConfig;
+use crate::backend::arguments::ArgumentError;
+use crate::backend::markdown::config::MarkdownBackendConfig;
+
+#[derive(Default)]
+pub struct HtmlBackendConfig {
+    /// Configuration of the Markdown document rendered to HTML.
+    pub markdown: MarkdownBackendConfig,
+    /// CSS file embedded in the page instead of the built-in stylesheet.
+    pub stylesheet: Option<PathBuf>,
+}
+
+impl IBackendConfig for HtmlBackendConfig {
+    fn parse_arguments(
+        args: impl Iterator<Item = (String, String)>,
+    ) -> Result<Self, ArgumentError> {
+        let mut config = HtmlBackendConfig::default();
+        for (arg, val) in args {
+            match arg.as_str() {
+                "stylesheet" => config.stylesheet = Some(PathBuf::from(val)),
+                _ => config.markdown.parse_argument(arg, val)?,
+            }
+        }
+        Ok(config)
+    }
+}
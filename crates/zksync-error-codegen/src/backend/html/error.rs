@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GenerationError {
+    #[error(transparent)]
+    Markdown(#[from] crate::backend::markdown::error::GenerationError),
+    #[error("Error processing template for HTML backend: {0:?}")]
+    TemplateError(#[from] tera::Error),
+    #[error("Unable to read stylesheet {path}: {error}")]
+    Stylesheet {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+}
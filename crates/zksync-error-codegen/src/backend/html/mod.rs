@@ -0,0 +1,95 @@
+pub mod config;
+pub mod error;
+
+use std::path::PathBuf;
+
+use config::HtmlBackendConfig;
+use error::GenerationError;
+use include_dir::Dir;
+use include_dir::include_dir;
+use tera::Tera;
+use zksync_error_model::inner::Model;
+
+use super::Backend;
+use super::File;
+use super::markdown::MarkdownBackend;
+
+static TEMPLATES_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/doc_templates/html");
+
+const PAGE_TEMPLATE: &str = "page.html";
+const STYLESHEET: &str = "style.css";
+
+/// Documents all errors in a single self-contained HTML page, rendered from
+/// the document of the Markdown backend.
+pub struct HtmlBackend {
+    title: String,
+    stylesheet: Option<PathBuf>,
+    markdown: MarkdownBackend,
+}
+
+impl HtmlBackend {
+    pub const FILE_NAME: &str = "errors.html";
+
+    fn builtin_template(name: &str) -> &'static str {
+        TEMPLATES_DIR
+            .get_file(name)
+            .and_then(|file| file.contents_utf8())
+            .unwrap_or_else(|| panic!("Missing template `{name}`"))
+    }
+
+    fn stylesheet(&self) -> Result<String, GenerationError> {
+        match &self.stylesheet {
+            Some(path) => {
+                std::fs::read_to_string(path).map_err(|error| GenerationError::Stylesheet {
+                    path: path.clone(),
+                    error,
+                })
+            }
+            None => Ok(Self::builtin_template(STYLESHEET).to_owned()),
+        }
+    }
+}
+
+impl Backend for HtmlBackend {
+    type Config = HtmlBackendConfig;
+    type GenerationError = GenerationError;
+
+    fn new(config: Self::Config, model: &Model) -> Self {
+        Self {
+            title: config.markdown.title.clone(),
+            stylesheet: config.stylesheet,
+            markdown: MarkdownBackend::new(config.markdown, model),
+        }
+    }
+
+    fn get_name() -> &'static str {
+        "html"
+    }
+
+    fn get_language_name() -> &'static str {
+        "html"
+    }
+
+    fn generate(&mut self) -> Result<Vec<File>, Self::GenerationError> {
+        let markdown = self.markdown.render()?;
+        let options =
+            pulldown_cmark::Options::ENABLE_TABLES | pulldown_cmark::Options::ENABLE_STRIKETHROUGH;
+        let mut content = String::new();
+        pulldown_cmark::html::push_html(
+            &mut content,
+            pulldown_cmark::Parser::new_ext(&markdown, options),
+        );
+
+        let mut tera = Tera::default();
+        tera.add_raw_template(PAGE_TEMPLATE, Self::builtin_template(PAGE_TEMPLATE))?;
+        let mut context = tera::Context::new();
+        context.insert("title", &self.title);
+        context.insert("stylesheet", &self.stylesheet()?);
+        context.insert("content", &content);
+
+        Ok(vec![File {
+            relative_path: PathBuf::from(Self::FILE_NAME),
+            content: tera.render(PAGE_TEMPLATE, &context)?,
+        }])
+    }
+}
@@ -0,0 +1,47 @@
+use crate::backend::IBackendConfig;
+use crate::backend::arguments::ArgumentError;
+use crate::backend::arguments::parse_bool;
+use crate::backend::mdbook::config::MDBookBackendConfig;
+
+pub struct MarkdownBackendConfig {
+    /// Leave internal domains, components and errors out of the document.
+    pub hide_internal: bool,
+    pub title: String,
+    /// List all domains, components and errors at the top of the document.
+    pub table_of_contents: bool,
+}
+
+impl Default for MarkdownBackendConfig {
+    fn default() -> Self {
+        Self {
+            hide_internal: false,
+            title: MDBookBackendConfig::DEFAULT_TITLE.to_owned(),
+            table_of_contents: true,
+        }
+    }
+}
+
+impl MarkdownBackendConfig {
+    /// Applies an argument of the Markdown backend to the configuration.
+    pub(crate) fn parse_argument(&mut self, arg: String, val: String) -> Result<(), ArgumentError> {
+        match arg.as_str() {
+            "hide_internal" => parse_bool(&arg, &val, &mut self.hide_internal)?,
+            "table_of_contents" => parse_bool(&arg, &val, &mut self.table_of_contents)?,
+            "title" => self.title = val,
+            _ => return Err(ArgumentError::UnsupportedArgument { argument: arg }),
+        }
+        Ok(())
+    }
+}
+
+impl IBackendConfig for MarkdownBackendConfig {
+    fn parse_arguments(
+        args: impl Iterator<Item = (String, String)>,
+    ) -> Result<Self, ArgumentError> {
+        let mut config = MarkdownBackendConfig::default();
+        for (arg, val) in args {
+            config.parse_argument(arg, val)?;
+        }
+        Ok(config)
+    }
+}
@@ -0,0 +1,5 @@
+#[derive(Debug, thiserror::Error)]
+pub enum GenerationError {
+    #[error("Error processing template for Markdown backend: {0:?}")]
+    TemplateError(#[from] tera::Error),
+}
@@ -0,0 +1,160 @@
+pub mod config;
+pub mod error;
+
+use std::path::PathBuf;
+
+use config::MarkdownBackendConfig;
+use error::GenerationError;
+use include_dir::Dir;
+use include_dir::include_dir;
+use tera::Tera;
+use zksync_error_model::inner::Model;
+use zksync_error_model::unpacked::flatten;
+
+use super::Backend;
+use super::File;
+use super::mdbook;
+use super::mdbook::ErrorPage;
+
+static TEMPLATES_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/doc_templates/markdown");
+
+const DOCUMENT_TEMPLATE: &str = "document.md";
+
+/// Documents all errors in a single Markdown file, with an anchor per domain,
+/// component and error; the errors are documented as in the MDBook.
+pub struct MarkdownBackend {
+    config: MarkdownBackendConfig,
+    model: Model,
+}
+
+impl MarkdownBackend {
+    pub const FILE_NAME: &str = "errors.md";
+
+    fn initialize_tera() -> Result<Tera, GenerationError> {
+        fn template(directory: &'static Dir, name: &'static str) -> (&'static str, &'static str) {
+            let content = directory
+                .get_file(name)
+                .and_then(|file| file.contents_utf8())
+                .unwrap_or_else(|| panic!("Missing template `{name}`"));
+            (name, content)
+        }
+        let mut tera = Tera::default();
+        tera.add_raw_templates([
+            template(&mdbook::TEMPLATES_DIR, mdbook::MACROS_TEMPLATE),
+            template(&TEMPLATES_DIR, DOCUMENT_TEMPLATE),
+        ])?;
+        Ok(tera)
+    }
+
+    /// The Markdown document, where errors link to each other by anchors.
+    pub(crate) fn render(&self) -> Result<String, GenerationError> {
+        let tera = Self::initialize_tera()?;
+        let mut model = flatten(&self.model);
+        if self.config.hide_internal {
+            model = model.without_internal();
+        }
+        let errors: Vec<_> = model
+            .errors
+            .values()
+            .map(|error| {
+                let link = format!("#{}", mdbook::anchor(error));
+                ErrorPage::new(&model, error, link.clone(), link)
+            })
+            .collect();
+
+        let mut context = tera::Context::new();
+        context.insert("title", &self.config.title);
+        context.insert("table_of_contents", &self.config.table_of_contents);
+        context.insert("domains", &model.domains.values().collect::<Vec<_>>());
+        context.insert("components", &model.components.values().collect::<Vec<_>>());
+        context.insert("errors", &errors);
+        Ok(collapse_blank_lines(
+            &tera.render(DOCUMENT_TEMPLATE, &context)?,
+        ))
+    }
+}
+
+/// Leaves at most one blank line between blocks, as the templates shared with
+/// the MDBook leave many, and trims trailing whitespace. Fenced code blocks are
+/// kept verbatim, and two trailing spaces, a hard line break, are kept.
+fn collapse_blank_lines(document: &str) -> String {
+    let mut result = String::with_capacity(document.len());
+    // Marker of the fence of the code block the line is in, e.g. "```".
+    let mut fence: Option<&str> = None;
+    let mut blank = true;
+    for line in document.lines() {
+        let trimmed = line.trim();
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+                result.push_str(line.trim_end());
+            } else {
+                result.push_str(line);
+            }
+            result.push('\n');
+            continue;
+        }
+        if trimmed.is_empty() {
+            if !blank {
+                blank = true;
+                result.push('\n');
+            }
+            continue;
+        }
+        blank = false;
+        fence = ["```", "~~~"]
+            .into_iter()
+            .find(|marker| trimmed.starts_with(marker));
+        let content = line.trim_end();
+        result.push_str(content);
+        if fence.is_none() && line[content.len()..].starts_with("  ") {
+            result.push_str("  ");
+        }
+        result.push('\n');
+    }
+    result
+}
+
+impl Backend for MarkdownBackend {
+    type Config = MarkdownBackendConfig;
+    type GenerationError = GenerationError;
+
+    fn new(config: Self::Config, model: &Model) -> Self {
+        Self {
+            config,
+            model: model.clone(),
+        }
+    }
+
+    fn get_name() -> &'static str {
+        "markdown"
+    }
+
+    fn get_language_name() -> &'static str {
+        "markdown"
+    }
+
+    fn generate(&mut self) -> Result<Vec<File>, Self::GenerationError> {
+        Ok(vec![File {
+            relative_path: PathBuf::from(Self::FILE_NAME),
+            content: self.render()?,
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::collapse_blank_lines;
+
+    #[test]
+    fn test_collapse_blank_lines() {
+        assert_eq!(
+            collapse_blank_lines("\n\n# Title \n\n\n\nfirst  \nsecond\t\n\n"),
+            "# Title\n\nfirst  \nsecond\n\n"
+        );
+        assert_eq!(
+            collapse_blank_lines("```rust \nlet x = 1;   \n\n\n    ~~~\n```\n\n\nafter"),
+            "```rust\nlet x = 1;   \n\n\n    ~~~\n```\n\nafter\n"
+        );
+    }
+}
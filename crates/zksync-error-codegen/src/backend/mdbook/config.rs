@@ -1,13 +1,187 @@
+use std::path::PathBuf;
+
+use zksync_error_model::link::Link;
+
 use crate::backend::IBackendConfig;
 use crate::backend::arguments::ArgumentError;
+use crate::backend::arguments::parse_bool;
+use crate::description::parsers::link::parse_str;
+
+/// Pages of the book the errors are documented in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, strum_macros::AsRefStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum Grouping {
+    /// A page per domain, documenting its components and their errors.
+    Domain,
+    /// A page per domain and a page per component, documenting its errors.
+    Component,
+    /// A page per domain, component and error.
+    #[default]
+    Error,
+}
 
-#[derive(Default)]
-pub struct MDBookBackendConfig;
+pub struct MDBookBackendConfig {
+    /// Leave internal domains, components and errors out of the book.
+    pub hide_internal: bool,
+    pub title: String,
+    pub authors: Vec<String>,
+    /// URL the book is served at, e.g. `/zksync-error/`; see `site-url` of
+    /// mdBook.
+    pub site_url: Option<String>,
+    /// Directory of templates replacing the built-in templates with the same
+    /// relative paths, e.g. `error.md`.
+    pub templates: Option<PathBuf>,
+    pub grouping: Grouping,
+    /// Add a page listing all errors by identifier and code.
+    pub lookup_table: bool,
+    /// Add `errors/README.md`, listing all errors with a filter.
+    pub error_index: bool,
+    /// Redirect `errors/<code>` and `errors/<identifier>` to the
+    /// documentation of every error, e.g. `errors/11001`.
+    pub redirects: bool,
+    /// Add the selector of versions of the book.
+    pub version_box: bool,
+    /// GitHub repository of the descriptions, e.g. `matter-labs/zksync-error`,
+    /// to link local origins of errors to.
+    pub repository: Option<String>,
+    /// Branch of `repository` to edit local origins on.
+    pub branch: String,
+    /// Link every error to the description file it is defined in.
+    pub edit_links: bool,
+    /// Name of the version of the model given to the backend.
+    pub version: String,
+    /// Earlier versions of the model, oldest first, each with the link to its
+    /// root description file. If any, a book is rendered for every version.
+    pub versions: Vec<(String, Link)>,
+}
+
+impl MDBookBackendConfig {
+    pub const DEFAULT_TITLE: &str = "ZKsync public errors documentation";
+    pub const DEFAULT_AUTHOR: &str = "Matter Labs";
+    pub const DEFAULT_BRANCH: &str = "main";
+    pub const DEFAULT_VERSION: &str = "latest";
+}
+
+/// Versions name the directories of their books.
+fn parse_version_name(arg: &str, name: &str) -> Result<String, ArgumentError> {
+    if name.is_empty()
+        || name.starts_with('.')
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._-".contains(c))
+    {
+        return Err(ArgumentError::InvalidArgument {
+            value: name.to_owned(),
+            argument: arg.to_owned(),
+            message: "expected a version name of letters, digits, `.`, `_` and `-`".into(),
+        });
+    }
+    Ok(name.to_owned())
+}
+
+impl Default for MDBookBackendConfig {
+    fn default() -> Self {
+        Self {
+            hide_internal: false,
+            title: Self::DEFAULT_TITLE.to_owned(),
+            authors: vec![Self::DEFAULT_AUTHOR.to_owned()],
+            site_url: None,
+            templates: None,
+            grouping: Grouping::default(),
+            lookup_table: false,
+            error_index: false,
+            redirects: false,
+            version_box: true,
+            repository: None,
+            branch: Self::DEFAULT_BRANCH.to_owned(),
+            edit_links: false,
+            version: Self::DEFAULT_VERSION.to_owned(),
+            versions: vec![],
+        }
+    }
+}
 
 impl IBackendConfig for MDBookBackendConfig {
     fn parse_arguments(
-        _args: impl Iterator<Item = (String, String)>,
+        args: impl Iterator<Item = (String, String)>,
     ) -> Result<Self, ArgumentError> {
-        Ok(MDBookBackendConfig)
+        let mut config = MDBookBackendConfig::default();
+        for (arg, val) in args {
+            match arg.as_str() {
+                "hide_internal" => parse_bool(&arg, &val, &mut config.hide_internal)?,
+                "lookup_table" => parse_bool(&arg, &val, &mut config.lookup_table)?,
+                "error_index" => parse_bool(&arg, &val, &mut config.error_index)?,
+                "redirects" => parse_bool(&arg, &val, &mut config.redirects)?,
+                "version_box" => parse_bool(&arg, &val, &mut config.version_box)?,
+                "edit_links" => parse_bool(&arg, &val, &mut config.edit_links)?,
+                "title" => config.title = val,
+                "authors" => {
+                    config.authors = val
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|author| !author.is_empty())
+                        .map(String::from)
+                        .collect()
+                }
+                "site_url" => config.site_url = Some(val),
+                "templates" => config.templates = Some(PathBuf::from(val)),
+                "grouping" => {
+                    config.grouping = match val.as_str() {
+                        "domain" => Grouping::Domain,
+                        "component" => Grouping::Component,
+                        "error" => Grouping::Error,
+                        _ => {
+                            return Err(ArgumentError::InvalidArgument {
+                                value: val,
+                                argument: arg,
+                                message: "expected one of `domain`, `component`, `error`".into(),
+                            });
+                        }
+                    }
+                }
+                "repository" => {
+                    let repository = val.trim_matches('/');
+                    if repository.split('/').count() != 2
+                        || repository.split('/').any(str::is_empty)
+                    {
+                        return Err(ArgumentError::InvalidArgument {
+                            value: val,
+                            argument: arg,
+                            message:
+                                "expected a GitHub repository, e.g. `matter-labs/zksync-error`"
+                                    .into(),
+                        });
+                    }
+                    config.repository = Some(repository.to_owned());
+                }
+                "branch" => config.branch = val,
+                "version" => config.version = parse_version_name(&arg, &val)?,
+                _ => match arg.strip_prefix("version.") {
+                    Some(name) => {
+                        let name = parse_version_name(&arg, name)?;
+                        let link =
+                            parse_str(&val).map_err(|error| ArgumentError::InvalidArgument {
+                                value: val.clone(),
+                                argument: arg.clone(),
+                                message: error.to_string(),
+                            })?;
+                        config.versions.push((name, link));
+                    }
+                    None => return Err(ArgumentError::UnsupportedArgument { argument: arg }),
+                },
+            }
+        }
+        let mut names = vec![&config.version];
+        for (name, _) in &config.versions {
+            if names.contains(&name) {
+                return Err(ArgumentError::InvalidArgument {
+                    value: name.clone(),
+                    argument: format!("version.{name}"),
+                    message: "every version should have a distinct name".into(),
+                });
+            }
+            names.push(name);
+        }
+        Ok(config)
     }
 }
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use zksync_error_model::error::ModelValidationError;
 
 #[derive(Debug, thiserror::Error)]
@@ -6,4 +8,11 @@ pub enum GenerationError {
     ModelError(#[from] ModelValidationError),
     #[error("Error processing template for MDBook backend: {0:?}")]
     TemplateError(#[from] tera::Error),
+    #[error("Unable to read templates from {path}: {error}")]
+    TemplateDirectory {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Template {path} does not replace any template of the MDBook backend")]
+    UnknownTemplate { path: PathBuf },
 }
@@ -1,8 +1,11 @@
 pub mod config;
 pub mod error;
 
+use std::collections::BTreeMap;
+use std::path::Path;
 use std::path::PathBuf;
 
+use config::Grouping;
 use config::MDBookBackendConfig;
 use error::GenerationError;
 use include_dir::Dir;
@@ -13,54 +16,322 @@ use super::File;
 
 use include_dir::include_dir;
 use zksync_error_model::inner::Model;
+use zksync_error_model::link::github::GithubLink;
+use zksync_error_model::unpacked::ErrorDescription;
 use zksync_error_model::unpacked::UnpackedModel;
 use zksync_error_model::unpacked::flatten;
 
 pub struct MDBookBackend {
-    _config: MDBookBackendConfig,
+    config: MDBookBackendConfig,
     model: Model,
+    /// Earlier versions of the model, oldest first.
+    versions: Vec<(String, Model)>,
 }
 
-static TEMPLATES_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/doc_templates/mdbook");
+/// Directory the books of all versions are built into, next to the file
+/// listing the versions for the version selector.
+pub const VERSIONED_BUILD_DIRECTORY: &str = "book";
 
-fn initialize_tera() -> Result<Tera, <MDBookBackend as Backend>::GenerationError> {
-    let mut tera = Tera::default();
-    for file in TEMPLATES_DIR.files() {
-        if let Some(path) = file.path().to_str() {
-            if let Ok(contents) = std::str::from_utf8(file.contents()) {
-                tera.add_raw_template(path, contents)?;
-            }
+/// Directory of the index of errors and of the redirects to their pages.
+pub const ERRORS_DIRECTORY: &str = "errors";
+
+/// Versions of the book where an error first appeared and last changed.
+#[derive(Clone, serde::Serialize)]
+struct Revision {
+    since: String,
+    changed_in: String,
+}
+
+pub(crate) static TEMPLATES_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/doc_templates/mdbook");
+
+/// Template of the macro `error_details`, documenting an error; shared with the
+/// other documentation backends.
+pub(crate) const MACROS_TEMPLATE: &str = "macros.md";
+
+/// An error as seen by the templates: its description, along with where it is
+/// documented in the book.
+#[derive(serde::Serialize)]
+pub(crate) struct ErrorPage<'a> {
+    #[serde(flatten)]
+    error: &'a ErrorDescription,
+    /// Code of the error, as in `StructuredErrorCode`.
+    encoded_code: u32,
+    /// Anchor of the documentation of the error, e.g. `core-seq-1`.
+    anchor: String,
+    /// Path of the documentation of the error relative to `src`, with the
+    /// anchor if it shares the page with other errors.
+    link: String,
+    /// Path of the rendered documentation of the error relative to the root
+    /// of the book, e.g. `domains/Core/Sequencer/GenericError.html`.
+    url: String,
+    /// URL to edit the description of the error at.
+    edit_url: Option<String>,
+    /// Versions the error appeared and changed in, if the book has versions.
+    revision: Option<Revision>,
+}
+
+impl<'a> ErrorPage<'a> {
+    /// An error documented at `link` and rendered at `url`, without edit link
+    /// and revision.
+    pub(crate) fn new(
+        model: &UnpackedModel,
+        error: &'a ErrorDescription,
+        link: String,
+        url: String,
+    ) -> Self {
+        Self {
+            error,
+            encoded_code: model.encoded_code(error),
+            anchor: anchor(error),
+            link,
+            url,
+            edit_url: None,
+            revision: None,
         }
     }
-    Ok(tera)
+}
+
+/// Anchor of the documentation of an error, e.g. `core-seq-1`.
+pub(crate) fn anchor(error: &ErrorDescription) -> String {
+    error
+        .identifier
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_owned()
+}
+
+/// Redirect from a path of the book, relative to its root, to a URL relative
+/// to the redirect.
+#[derive(serde::Serialize)]
+struct Redirect {
+    from: String,
+    to: String,
+}
+
+/// Path of the HTML page rendered by mdBook from a Markdown file, keeping the
+/// anchor if any; `README.md` files become `index.html`.
+fn html_path(link: &str) -> String {
+    let (path, anchor) = match link.split_once('#') {
+        Some((path, anchor)) => (path, format!("#{anchor}")),
+        None => (link, String::new()),
+    };
+    let path = match path.strip_suffix("README.md") {
+        Some(directory) => format!("{directory}index.html"),
+        None => format!("{}.html", path.trim_end_matches(".md")),
+    };
+    path + &anchor
+}
+
+fn read_builtin_templates(directory: &Dir, templates: &mut BTreeMap<String, String>) {
+    for file in directory.files() {
+        if let (Some(path), Some(contents)) = (file.path().to_str(), file.contents_utf8()) {
+            templates.insert(path.to_owned(), contents.to_owned());
+        }
+    }
+    for directory in directory.dirs() {
+        read_builtin_templates(directory, templates);
+    }
+}
+
+/// Collects the files of a directory of user templates, keyed by their paths
+/// relative to `root`.
+fn read_templates(
+    root: &Path,
+    directory: &Path,
+    templates: &mut BTreeMap<String, String>,
+) -> Result<(), GenerationError> {
+    let io_error = |error| GenerationError::TemplateDirectory {
+        path: directory.to_owned(),
+        error,
+    };
+    for entry in std::fs::read_dir(directory).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.is_dir() {
+            read_templates(root, &path, templates)?;
+            continue;
+        }
+        let name = path
+            .strip_prefix(root)
+            .expect("Templates are read from the templates directory")
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if TEMPLATES_DIR.get_file(&name).is_none() {
+            return Err(GenerationError::UnknownTemplate { path });
+        }
+        let content =
+            std::fs::read_to_string(&path).map_err(|error| GenerationError::TemplateDirectory {
+                path: path.clone(),
+                error,
+            })?;
+        templates.insert(name, content);
+    }
+    Ok(())
 }
 
 impl MDBookBackend {
-    fn copy_as_is(&mut self, filename: &str) -> Result<File, GenerationError> {
-        let content = TEMPLATES_DIR
-            .get_file(filename)
-            .unwrap_or_else(|| panic!("Missing file `{filename}`"))
-            .contents_utf8()
-            .unwrap_or_else(|| {
-                panic!("Internal error: decoding utf-8 string from file {filename}.")
-            });
-
-        eprintln!("Copy as is: {filename}: \n{content}");
+    /// Built-in templates, replaced by the ones of the user, if any.
+    fn templates(&self) -> Result<BTreeMap<String, String>, GenerationError> {
+        let mut templates = BTreeMap::new();
+        read_builtin_templates(&TEMPLATES_DIR, &mut templates);
+        if let Some(directory) = &self.config.templates {
+            read_templates(directory, directory, &mut templates)?;
+        }
+        Ok(templates)
+    }
+
+    fn initialize_tera(templates: &BTreeMap<String, String>) -> Result<Tera, GenerationError> {
+        let mut tera = Tera::default();
+        tera.add_raw_templates(templates.iter().filter(|(path, _)| {
+            path.ends_with(".md") || path.ends_with(".toml") || path.ends_with(".html")
+        }))?;
+        Ok(tera)
+    }
+
+    fn copy_as_is(
+        templates: &BTreeMap<String, String>,
+        filename: &str,
+    ) -> Result<File, GenerationError> {
+        let content = templates
+            .get(filename)
+            .unwrap_or_else(|| panic!("Missing file `{filename}`"));
+
         Ok(File {
             relative_path: PathBuf::from(filename),
-            content: content.into(),
+            content: content.clone(),
+        })
+    }
+
+    /// URL to edit the description of an error at, given by the first of its
+    /// origins on GitHub, or local to `repository`.
+    fn edit_url(&self, error: &ErrorDescription) -> Option<String> {
+        if !self.config.edit_links {
+            return None;
+        }
+        error.origins.iter().find_map(|origin| {
+            if let Some(link) = GithubLink::from_url(origin) {
+                return Some(link.to_edit_url());
+            }
+            let repository = self.config.repository.as_ref()?;
+            let path = Path::new(origin);
+            if origin.contains("://") || path.is_absolute() || origin.starts_with('<') {
+                return None;
+            }
+            let path = origin.trim_start_matches("./");
+            Some(format!(
+                "https://github.com/{repository}/edit/{}/{path}",
+                self.config.branch
+            ))
+        })
+    }
+
+    fn error_pages<'a>(
+        &self,
+        model: &'a UnpackedModel,
+        revisions: &BTreeMap<String, Revision>,
+    ) -> Vec<ErrorPage<'a>> {
+        model
+            .errors
+            .values()
+            .map(|error| {
+                let anchor = anchor(error);
+                let domain_name = &error.domain;
+                let component_name = &error.component;
+                let link = match self.config.grouping {
+                    Grouping::Domain => format!("domains/{domain_name}/README.md#{anchor}"),
+                    Grouping::Component => {
+                        format!("domains/{domain_name}/{component_name}/README.md#{anchor}")
+                    }
+                    Grouping::Error => {
+                        format!("domains/{domain_name}/{component_name}/{}.md", error.name)
+                    }
+                };
+                let url = html_path(&link);
+                ErrorPage {
+                    edit_url: self.edit_url(error),
+                    revision: revisions.get(&error.identifier).cloned(),
+                    ..ErrorPage::new(model, error, link, url)
+                }
+            })
+            .collect()
+    }
+
+    /// Context shared by all pages; `root` is the path from the page to
+    /// `src`.
+    fn context(&self, model: &UnpackedModel, errors: &[ErrorPage], root: &str) -> tera::Context {
+        let mut context = tera::Context::new();
+        context.insert("domains", &model.domains.values().collect::<Vec<_>>());
+        context.insert("components", &model.components.values().collect::<Vec<_>>());
+        context.insert("errors", errors);
+        context.insert("grouping", self.config.grouping.as_ref());
+        context.insert("lookup_table", &self.config.lookup_table);
+        context.insert("error_index", &self.config.error_index);
+        context.insert("root", root);
+        context
+    }
+
+    /// Redirects from `errors/<code>` and `errors/<identifier>` to the
+    /// documentation of every error.
+    fn redirects(&self, errors: &[ErrorPage]) -> Vec<Redirect> {
+        if !self.config.redirects {
+            return vec![];
+        }
+        errors
+            .iter()
+            .flat_map(|error| {
+                [error.encoded_code.to_string(), error.anchor.clone()].map(|name| Redirect {
+                    from: format!("/{ERRORS_DIRECTORY}/{name}/index.html"),
+                    to: format!("../../{}", error.url),
+                })
+            })
+            .collect()
+    }
+
+    fn generate_book_toml(
+        &self,
+        tera: &Tera,
+        errors: &[ErrorPage],
+        version: Option<&str>,
+    ) -> Result<File, GenerationError> {
+        let mut context = tera::Context::new();
+        context.insert("redirects", &self.redirects(errors));
+        context.insert(
+            "build_dir",
+            &version.map(|version| format!("../{VERSIONED_BUILD_DIRECTORY}/{version}")),
+        );
+        context.insert("title", &self.config.title);
+        context.insert("authors", &self.config.authors);
+        // Books of versions are served next to each other.
+        let site_url = self.config.site_url.as_ref().map(|url| match version {
+            Some(version) => format!("{}/{version}/", url.trim_end_matches('/')),
+            None => url.clone(),
+        });
+        context.insert("site_url", &site_url);
+        context.insert(
+            "git_repository_url",
+            &self
+                .config
+                .repository
+                .as_ref()
+                .map(|repository| format!("https://github.com/{repository}")),
+        );
+        context.insert("version_box", &self.config.version_box);
+        let content = tera.render("book.toml", &context)?;
+
+        Ok(File {
+            relative_path: PathBuf::from("book.toml"),
+            content,
         })
     }
 
     fn generate_summary(
-        &mut self,
+        &self,
         tera: &Tera,
         model: &UnpackedModel,
+        errors: &[ErrorPage],
     ) -> Result<File, GenerationError> {
-        let mut context = tera::Context::new();
-        context.insert("domains", &model.domains.values().collect::<Vec<_>>());
-        context.insert("components", &model.components.values().collect::<Vec<_>>());
-        context.insert("errors", &model.errors.values().collect::<Vec<_>>());
+        let context = self.context(model, errors, "");
         let content = tera.render("SUMMARY.md", &context)?;
 
         Ok(File {
@@ -69,16 +340,61 @@ impl MDBookBackend {
         })
     }
 
+    fn generate_error_index(
+        &self,
+        tera: &Tera,
+        model: &UnpackedModel,
+        errors: &[ErrorPage],
+    ) -> Result<File, GenerationError> {
+        let context = self.context(model, errors, "../");
+        let content = tera.render("index.md", &context)?;
+
+        Ok(File {
+            relative_path: PathBuf::from(format!("src/{ERRORS_DIRECTORY}/README.md")),
+            content,
+        })
+    }
+
+    /// Page redirecting to a URL, relative to the page.
+    fn generate_redirect(
+        tera: &Tera,
+        relative_path: PathBuf,
+        url: &str,
+    ) -> Result<File, GenerationError> {
+        let mut context = tera::Context::new();
+        context.insert("url", url);
+        let content = tera.render("redirect.html", &context)?;
+
+        Ok(File {
+            relative_path,
+            content,
+        })
+    }
+
+    fn generate_lookup_table(
+        &self,
+        tera: &Tera,
+        model: &UnpackedModel,
+        errors: &[ErrorPage],
+    ) -> Result<File, GenerationError> {
+        let context = self.context(model, errors, "");
+        let content = tera.render("lookup.md", &context)?;
+
+        Ok(File {
+            relative_path: PathBuf::from("src/lookup.md"),
+            content,
+        })
+    }
+
     fn generate_component(
-        &mut self,
+        &self,
         tera: &Tera,
         component: &zksync_error_model::unpacked::ComponentMetadata,
         model: &UnpackedModel,
+        errors: &[ErrorPage],
     ) -> Result<File, GenerationError> {
-        let mut context = tera::Context::new();
+        let mut context = self.context(model, errors, "../../../");
         context.insert("component", component);
-        context.insert("components", &model.components.values().collect::<Vec<_>>());
-        context.insert("errors", &model.errors.values().collect::<Vec<_>>());
         let content = tera.render("component.md", &context)?;
         let domain_name = &component.domain_name;
         let component_name = &component.identifier.name;
@@ -91,15 +407,14 @@ impl MDBookBackend {
         })
     }
     fn generate_domain(
-        &mut self,
+        &self,
         tera: &Tera,
         domain: &zksync_error_model::unpacked::DomainMetadata,
         model: &UnpackedModel,
+        errors: &[ErrorPage],
     ) -> Result<File, GenerationError> {
-        let mut context = tera::Context::new();
+        let mut context = self.context(model, errors, "../../");
         context.insert("domain", domain);
-        context.insert("components", &model.components.values().collect::<Vec<_>>());
-        context.insert("errors", &model.errors.values().collect::<Vec<_>>());
         let content = tera.render("domain.md", &context)?;
         let domain_name = &domain.identifier.name;
 
@@ -110,22 +425,21 @@ impl MDBookBackend {
     }
 
     fn generate_error(
-        &mut self,
+        &self,
         tera: &Tera,
         domain: &zksync_error_model::unpacked::DomainMetadata,
         component: &zksync_error_model::unpacked::ComponentMetadata,
-        error: &zksync_error_model::unpacked::ErrorDescription,
+        error: &ErrorPage,
         model: &UnpackedModel,
+        errors: &[ErrorPage],
     ) -> Result<File, GenerationError> {
-        let mut context = tera::Context::new();
+        let mut context = self.context(model, errors, "../../../");
         context.insert("domain", domain);
-        context.insert("components", &model.components.values().collect::<Vec<_>>());
-        context.insert("errors", &model.errors.values().collect::<Vec<_>>());
         context.insert("error", error);
         let content = tera.render("error.md", &context)?;
         let domain_name = &domain.identifier.name;
         let component_name = &component.identifier.name;
-        let error_name = &error.name;
+        let error_name = &error.error.name;
 
         Ok(File {
             relative_path: PathBuf::from(format!(
@@ -135,14 +449,98 @@ impl MDBookBackend {
         })
     }
 }
+impl MDBookBackend {
+    /// Passes earlier versions of the model, oldest first, to render a book
+    /// for each version, named as given by the configuration.
+    pub fn with_versions(mut self, versions: Vec<(String, Model)>) -> Self {
+        self.versions = versions;
+        self
+    }
+
+    fn unpack(&self, model: &Model) -> UnpackedModel {
+        let model = flatten(model);
+        if self.config.hide_internal {
+            model.without_internal()
+        } else {
+            model
+        }
+    }
+
+    /// Files of the book of a version of the model.
+    fn generate_book(
+        &self,
+        tera: &Tera,
+        templates: &BTreeMap<String, String>,
+        model: &UnpackedModel,
+        revisions: &BTreeMap<String, Revision>,
+        version: Option<&str>,
+    ) -> Result<Vec<File>, GenerationError> {
+        let errors = self.error_pages(model, revisions);
+
+        let mut results = vec![
+            self.generate_summary(tera, model, &errors)?,
+            self.generate_book_toml(tera, &errors, version)?,
+        ];
+        if self.config.version_box {
+            results.push(Self::copy_as_is(templates, "css/version-box.css")?);
+            results.push(Self::copy_as_is(templates, "js/version-box.js")?);
+        }
+        if self.config.lookup_table {
+            results.push(self.generate_lookup_table(tera, model, &errors)?);
+        }
+        if self.config.error_index {
+            results.push(self.generate_error_index(tera, model, &errors)?);
+        }
+
+        for domain in model.domains.values() {
+            results.push(self.generate_domain(tera, domain, model, &errors)?);
+            if self.config.grouping == Grouping::Domain {
+                continue;
+            }
+            for component in model.components.values() {
+                if component.domain_name == domain.identifier.name {
+                    results.push(self.generate_component(tera, component, model, &errors)?);
+                    if self.config.grouping == Grouping::Component {
+                        continue;
+                    }
+                    for error in &errors {
+                        if error.error.component == component.identifier.name {
+                            results.push(
+                                self.generate_error(
+                                    tera, domain, component, error, model, &errors,
+                                )?,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// Whether two versions of an error are described alike, wherever they come
+/// from.
+fn same_description(left: &ErrorDescription, right: &ErrorDescription) -> bool {
+    ErrorDescription {
+        origins: vec![],
+        ..left.clone()
+    } == ErrorDescription {
+        origins: vec![],
+        ..right.clone()
+    }
+}
+
 impl Backend for MDBookBackend {
     type Config = MDBookBackendConfig;
     type GenerationError = GenerationError;
 
     fn new(config: Self::Config, model: &Model) -> Self {
         Self {
-            _config: config,
+            config,
             model: model.clone(),
+            versions: vec![],
         }
     }
 
@@ -155,32 +553,94 @@ impl Backend for MDBookBackend {
     }
 
     fn generate(&mut self) -> Result<Vec<File>, Self::GenerationError> {
-        let tera = initialize_tera()?;
+        let templates = self.templates()?;
+        let tera = Self::initialize_tera(&templates)?;
 
-        let model = flatten(&self.model);
-        let mut results = vec![
-            self.generate_summary(&tera, &model)?,
-            self.copy_as_is("book.toml")?,
-            self.copy_as_is("css/version-box.css")?,
-            self.copy_as_is("js/version-box.js")?,
-        ];
+        if self.versions.is_empty() {
+            let model = self.unpack(&self.model);
+            return self.generate_book(&tera, &templates, &model, &BTreeMap::new(), None);
+        }
 
-        for domain in model.domains.values() {
-            results.push(self.generate_domain(&tera, domain, &model)?);
-            for component in model.components.values() {
-                if component.domain_name == domain.identifier.name {
-                    results.push(self.generate_component(&tera, component, &model)?);
-                    for error in model.errors.values() {
-                        if error.component == component.identifier.name {
-                            results.push(
-                                self.generate_error(&tera, domain, component, error, &model)?,
-                            );
-                        }
-                    }
+        let versions: Vec<_> = self
+            .versions
+            .iter()
+            .map(|(name, model)| (name.as_str(), self.unpack(model)))
+            .chain([(self.config.version.as_str(), self.unpack(&self.model))])
+            .collect();
+
+        let mut results = vec![];
+        let mut revisions = BTreeMap::new();
+        let mut previous: BTreeMap<&str, &ErrorDescription> = BTreeMap::new();
+        for (name, model) in &versions {
+            for error in model.errors.values() {
+                let changed = previous
+                    .get(error.identifier.as_str())
+                    .is_none_or(|previous| !same_description(previous, error));
+                if changed {
+                    let revision = revisions
+                        .entry(error.identifier.clone())
+                        .or_insert_with(|| Revision {
+                            since: name.to_string(),
+                            changed_in: name.to_string(),
+                        });
+                    revision.changed_in = name.to_string();
                 }
             }
+            previous = model
+                .errors
+                .values()
+                .map(|error| (error.identifier.as_str(), error))
+                .collect();
+
+            let book = self.generate_book(&tera, &templates, model, &revisions, Some(name))?;
+            results.extend(book.into_iter().map(|file| File {
+                relative_path: PathBuf::from(name).join(file.relative_path),
+                content: file.content,
+            }));
         }
 
+        // The root of the site and its redirects lead to the latest version.
+        let build_directory = PathBuf::from(VERSIONED_BUILD_DIRECTORY);
+        let latest = &self.config.version;
+        results.push(Self::generate_redirect(
+            &tera,
+            build_directory.join("index.html"),
+            &format!("{latest}/"),
+        )?);
+        if self.config.error_index {
+            results.push(Self::generate_redirect(
+                &tera,
+                build_directory.join(ERRORS_DIRECTORY).join("index.html"),
+                &format!("../{latest}/{ERRORS_DIRECTORY}/"),
+            )?);
+        }
+        let (_, model) = versions
+            .last()
+            .expect("The latest version is always present");
+        for redirect in self.redirects(&self.error_pages(model, &revisions)) {
+            let from = redirect.from.trim_start_matches('/');
+            results.push(Self::generate_redirect(
+                &tera,
+                build_directory.join(from),
+                &format!("../../{latest}/{}", from.trim_end_matches("index.html")),
+            )?);
+        }
+
+        // Newest versions first in the version selector; keys of JSON maps
+        // are sorted, hence the manual serialization.
+        let entries: Vec<_> = versions
+            .iter()
+            .rev()
+            .map(|(name, _)| {
+                let name = serde_json::Value::from(*name);
+                format!("  {name}: {name}")
+            })
+            .collect();
+        results.push(File {
+            relative_path: PathBuf::from(VERSIONED_BUILD_DIRECTORY).join("versions.json"),
+            content: format!("{{\n{}\n}}\n", entries.join(",\n")),
+        });
+
         Ok(results)
     }
 }
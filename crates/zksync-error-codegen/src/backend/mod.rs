@@ -1,7 +1,14 @@
 pub mod arguments;
+pub mod c;
+pub mod catalogue;
 pub mod file;
+pub mod html;
+pub mod markdown;
 pub mod mdbook;
+pub mod openrpc;
+pub mod protobuf;
 pub mod rust;
+pub mod solidity;
 
 use std::error::Error as StdError;
 
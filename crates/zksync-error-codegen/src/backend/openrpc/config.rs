@@ -0,0 +1,24 @@
+use crate::backend::IBackendConfig;
+use crate::backend::arguments::ArgumentError;
+use crate::backend::arguments::parse_bool;
+
+#[derive(Default)]
+pub struct OpenRpcBackendConfig {
+    /// Leave internal domains, components and errors out of the export.
+    pub hide_internal: bool,
+}
+
+impl IBackendConfig for OpenRpcBackendConfig {
+    fn parse_arguments(
+        args: impl Iterator<Item = (String, String)>,
+    ) -> Result<Self, ArgumentError> {
+        let mut config = OpenRpcBackendConfig::default();
+        for (arg, val) in args {
+            match arg.as_str() {
+                "hide_internal" => parse_bool(&arg, &val, &mut config.hide_internal)?,
+                _ => return Err(ArgumentError::UnsupportedArgument { argument: arg }),
+            }
+        }
+        Ok(config)
+    }
+}
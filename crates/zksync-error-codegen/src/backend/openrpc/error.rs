@@ -0,0 +1,13 @@
+use zksync_error_model::error::ModelValidationError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GenerationError {
+    #[error(transparent)]
+    ModelError(#[from] ModelValidationError),
+    #[error(transparent)]
+    Serialization(#[from] serde_json::Error),
+    #[error(
+        "The JSON Schema binding `{expression}` of the type `{type}` is neither a JSON type name nor a JSON object"
+    )]
+    InvalidSchema { expression: String, r#type: String },
+}
@@ -0,0 +1,152 @@
+pub mod config;
+pub mod error;
+
+use std::path::PathBuf;
+
+use config::OpenRpcBackendConfig;
+use error::GenerationError;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+use zksync_error_model::inner::ErrorDescription;
+use zksync_error_model::inner::Model;
+
+use super::Backend;
+use super::File;
+
+pub const JSON_SCHEMA_LANGUAGE_NAME: &str = "json_schema";
+
+/// Types of JSON Schema, which bindings may give instead of a whole schema.
+const JSON_TYPES: &[&str] = &[
+    "array", "boolean", "integer", "null", "number", "object", "string",
+];
+
+pub struct OpenRpcBackend {
+    config: OpenRpcBackendConfig,
+    model: Model,
+}
+
+impl OpenRpcBackend {
+    /// Key of an error in `components.errors`, e.g.
+    /// `Core.Sequencer.GenericSequencerError`.
+    fn error_key(error: &ErrorDescription) -> String {
+        format!(
+            "{}.{}.{}",
+            error.domain.identifier.name, error.component.identifier.name, error.name
+        )
+    }
+
+    /// JSON Schema of the values of a type, given by its binding: either a
+    /// type name, e.g. `string`, or a schema, e.g. `{"type": "string",
+    /// "pattern": "^0x[0-9a-f]{64}$"}`.
+    fn type_schema(&self, type_name: &str) -> Result<Value, GenerationError> {
+        if let Some(domain) = self.model.domains.get(type_name) {
+            return Ok(json!({
+                "type": "object",
+                "description": format!("An error of the domain {}.", domain.meta.identifier.name),
+            }));
+        }
+        if self
+            .model
+            .components()
+            .any(|component| component.meta.identifier.name == type_name)
+        {
+            return Ok(json!({
+                "type": "object",
+                "description": format!("An error of the component {type_name}."),
+            }));
+        }
+        match self.model.get_type(JSON_SCHEMA_LANGUAGE_NAME, type_name) {
+            Ok(binding) => {
+                let expression = binding.expression.trim();
+                if JSON_TYPES.contains(&expression) {
+                    return Ok(json!({ "type": expression }));
+                }
+                match serde_json::from_str(expression) {
+                    Ok(schema @ Value::Object(_)) => Ok(schema),
+                    _ => Err(GenerationError::InvalidSchema {
+                        expression: expression.to_owned(),
+                        r#type: type_name.to_owned(),
+                    }),
+                }
+            }
+            // Any value; the description of the type tells what it is.
+            Err(_) => Ok(match self.model.types.get(type_name) {
+                Some(description) => json!({ "description": description.meta.description }),
+                None => json!({}),
+            }),
+        }
+    }
+
+    /// JSON Schema of the `data` of an error, an object holding its fields.
+    fn data_schema(&self, error: &ErrorDescription) -> Result<Value, GenerationError> {
+        let mut properties = Map::new();
+        for field in &error.fields {
+            properties.insert(field.name.clone(), self.type_schema(&field.r#type)?);
+        }
+        let required: Vec<_> = error.fields.iter().map(|field| &field.name).collect();
+        Ok(json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        }))
+    }
+
+    /// OpenRPC error object of an error, with the schema of its data and its
+    /// documentation in extensions.
+    fn error_object(&self, error: &ErrorDescription) -> Result<Value, GenerationError> {
+        let mut object = Map::new();
+        object.insert("code".into(), json!(error.encoded_code()));
+        object.insert("message".into(), json!(error.message));
+        object.insert(
+            "x-identifier".into(),
+            json!(error.get_identifier().to_string()),
+        );
+        if let Some(summary) = error
+            .documentation
+            .as_ref()
+            .and_then(|documentation| documentation.summary.as_ref())
+        {
+            object.insert("x-summary".into(), json!(summary));
+        }
+        object.insert("x-data-schema".into(), self.data_schema(error)?);
+        Ok(Value::Object(object))
+    }
+}
+
+impl Backend for OpenRpcBackend {
+    type Config = OpenRpcBackendConfig;
+    type GenerationError = GenerationError;
+
+    fn get_name() -> &'static str {
+        "openrpc"
+    }
+
+    fn get_language_name() -> &'static str {
+        JSON_SCHEMA_LANGUAGE_NAME
+    }
+
+    fn generate(&mut self) -> Result<Vec<File>, Self::GenerationError> {
+        let mut errors = Map::new();
+        for error in self.model.errors() {
+            if self.config.hide_internal && !error.effective_visibility().is_public() {
+                continue;
+            }
+            errors.insert(Self::error_key(error), self.error_object(error)?);
+        }
+        let document = json!({ "components": { "errors": errors } });
+
+        Ok(vec![File {
+            relative_path: PathBuf::from("openrpc-errors.json"),
+            content: serde_json::to_string_pretty(&document)? + "\n",
+        }])
+    }
+
+    fn new(config: Self::Config, model: &Model) -> Self {
+        Self {
+            config,
+            model: model.clone(),
+        }
+    }
+}
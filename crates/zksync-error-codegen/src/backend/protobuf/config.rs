@@ -0,0 +1,45 @@
+use crate::backend::IBackendConfig;
+use crate::backend::arguments::ArgumentError;
+
+pub struct ProtobufBackendConfig {
+    /// Protobuf package of the generated files, e.g. `zksync.error.v1`; the
+    /// files are placed in the matching directories.
+    pub package: String,
+}
+
+impl Default for ProtobufBackendConfig {
+    fn default() -> Self {
+        Self {
+            package: "zksync_error".to_owned(),
+        }
+    }
+}
+
+impl IBackendConfig for ProtobufBackendConfig {
+    fn parse_arguments(
+        args: impl Iterator<Item = (String, String)>,
+    ) -> Result<Self, ArgumentError> {
+        let mut config = ProtobufBackendConfig::default();
+        for (arg, val) in args {
+            match arg.as_str() {
+                "package" => {
+                    let valid = val.split('.').all(|part| {
+                        part.starts_with(|c: char| c.is_ascii_alphabetic())
+                            && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                    });
+                    if !valid {
+                        return Err(ArgumentError::InvalidArgument {
+                            value: val,
+                            argument: arg,
+                            message: "expected a protobuf package name, e.g. `zksync.error.v1`"
+                                .into(),
+                        });
+                    }
+                    config.package = val
+                }
+                _ => return Err(ArgumentError::UnsupportedArgument { argument: arg }),
+            }
+        }
+        Ok(config)
+    }
+}
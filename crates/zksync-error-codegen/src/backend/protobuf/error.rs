@@ -0,0 +1,11 @@
+use zksync_error_model::error::ModelValidationError;
+
+use super::schema::InvalidProtobufType;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GenerationError {
+    #[error(transparent)]
+    ModelError(#[from] ModelValidationError),
+    #[error(transparent)]
+    InvalidProtobufType(#[from] InvalidProtobufType),
+}
@@ -0,0 +1,195 @@
+pub mod config;
+pub mod error;
+pub mod schema;
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use config::ProtobufBackendConfig;
+use error::GenerationError;
+use schema::DETAIL_MESSAGE;
+use schema::ProtoMessage;
+use zksync_error_model::inner::DomainDescription;
+use zksync_error_model::inner::ErrorDescription;
+use zksync_error_model::inner::Model;
+
+use super::Backend;
+use super::File;
+
+pub struct ProtobufBackend {
+    config: ProtobufBackendConfig,
+    model: Model,
+}
+
+/// Comment describing an error, e.g. `[core-seq-1] Generic error: {message}`.
+fn error_comment(error: &ErrorDescription) -> String {
+    let message: Vec<_> = error.message.lines().map(str::trim).collect();
+    format!("{} {}", error.get_identifier(), message.join(" "))
+}
+
+impl ProtobufBackend {
+    const PREAMBLE: &str = "AUTOGENERATED BASED ON A SET OF JSON FILES, DO NOT EDIT MANUALLY";
+
+    /// Path of a file of the package, e.g. `zksync/error/v1/core.proto`.
+    fn file_path(&self, name: &str) -> PathBuf {
+        self.config
+            .package
+            .split('.')
+            .collect::<PathBuf>()
+            .join(format!("{name}.proto"))
+    }
+
+    fn header(&self) -> String {
+        format!(
+            "//\n// {}\n//\nsyntax = \"proto3\";\n\npackage {};\n",
+            Self::PREAMBLE,
+            self.config.package
+        )
+    }
+
+    fn generate_domain(&self, domain: &DomainDescription) -> Result<File, GenerationError> {
+        let mut content = self.header();
+
+        for component in domain.components.values() {
+            let meta = &component.meta;
+            writeln!(
+                content,
+                "\n// Codes of the errors of the component `{}` of the domain `{}`.",
+                meta.identifier.name, meta.domain.identifier.name
+            )
+            .unwrap();
+            writeln!(content, "enum {} {{", schema::enum_name(meta)).unwrap();
+            for error in schema::errors_by_code(component) {
+                writeln!(content, "  // {}", error_comment(error)).unwrap();
+                writeln!(
+                    content,
+                    "  {} = {};",
+                    schema::enum_value_name(error),
+                    error.code
+                )
+                .unwrap();
+            }
+            writeln!(content, "}}").unwrap();
+
+            for message in schema::proto_messages(&self.model, component)? {
+                match message {
+                    Ok(message) => {
+                        writeln!(content, "\n// {}", error_comment(message.error)).unwrap();
+                        writeln!(content, "message {} {{", message.name).unwrap();
+                        for field in &message.fields {
+                            writeln!(
+                                content,
+                                "  {} {} = {};",
+                                field.r#type, field.field.name, field.number
+                            )
+                            .unwrap();
+                        }
+                        writeln!(content, "}}").unwrap();
+                    }
+                    Err(unsupported) => {
+                        writeln!(
+                            content,
+                            "\n// {} {} has no message: the type `{}` of its field `{}` has no protobuf binding.",
+                            unsupported.error.get_identifier(),
+                            unsupported.error.name,
+                            unsupported.field.r#type,
+                            unsupported.field.name
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+        }
+
+        Ok(File {
+            relative_path: self.file_path(&domain.meta.identifier.encoding),
+            content,
+        })
+    }
+
+    fn generate_detail(&self) -> Result<File, GenerationError> {
+        let mut content = self.header();
+        content.push('\n');
+        for domain in self.model.domains.values() {
+            let path = self.file_path(&domain.meta.identifier.encoding);
+            writeln!(content, "import \"{}\";", path.display()).unwrap();
+        }
+
+        let mut messages: Vec<ProtoMessage> = vec![];
+        for component in self.model.components() {
+            messages.extend(
+                schema::proto_messages(&self.model, component)?
+                    .into_iter()
+                    .flatten(),
+            );
+        }
+
+        writeln!(
+            content,
+            "
+// An error, e.g. in the details of a gRPC status.
+message {DETAIL_MESSAGE} {{
+  // Encoded code of the error: domain * 10000 + component * 1000 + error.
+  uint32 code = 1;
+  // Message of the error, with the values of its fields.
+  string message = 2;"
+        )
+        .unwrap();
+        if !messages.is_empty() {
+            writeln!(
+                content,
+                "  // Fields of the error, numbered {} plus the encoded code.\n  oneof fields {{",
+                schema::FIELDS_OFFSET
+            )
+            .unwrap();
+            for message in &messages {
+                writeln!(
+                    content,
+                    "    {} {} = {};",
+                    message.name,
+                    message.oneof_field_name(),
+                    message.oneof_field_number()
+                )
+                .unwrap();
+            }
+            writeln!(content, "  }}").unwrap();
+        }
+        writeln!(content, "}}").unwrap();
+
+        Ok(File {
+            relative_path: self.file_path("detail"),
+            content,
+        })
+    }
+}
+
+impl Backend for ProtobufBackend {
+    type Config = ProtobufBackendConfig;
+    type GenerationError = GenerationError;
+
+    fn get_name() -> &'static str {
+        "protobuf"
+    }
+
+    fn get_language_name() -> &'static str {
+        schema::PROTOBUF_LANGUAGE_NAME
+    }
+
+    fn generate(&mut self) -> Result<Vec<File>, Self::GenerationError> {
+        let mut files = self
+            .model
+            .domains
+            .values()
+            .map(|domain| self.generate_domain(domain))
+            .collect::<Result<Vec<_>, _>>()?;
+        files.push(self.generate_detail()?);
+        Ok(files)
+    }
+
+    fn new(config: Self::Config, model: &Model) -> Self {
+        Self {
+            config,
+            model: model.clone(),
+        }
+    }
+}
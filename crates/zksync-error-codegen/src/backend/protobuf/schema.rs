@@ -0,0 +1,209 @@
+//!
+//! Protobuf messages and enums describing the errors, shared by the protobuf
+//! backend and by the conversions of the Rust backend.
+//!
+
+use zksync_error_model::inner::ComponentDescription;
+use zksync_error_model::inner::ComponentMetadata;
+use zksync_error_model::inner::ErrorDescription;
+use zksync_error_model::inner::FieldDescription;
+use zksync_error_model::inner::Model;
+
+use crate::backend::rust::util::snake_case;
+
+pub const PROTOBUF_LANGUAGE_NAME: &str = "protobuf";
+
+/// Name of the message carrying an error in gRPC statuses.
+pub const DETAIL_MESSAGE: &str = "ErrorDetail";
+
+/// Field numbers of the messages of errors in the `oneof` of the detail
+/// message are this offset plus the encoded codes of the errors, which keeps
+/// them stable and clear of the numbers reserved by protobuf.
+pub const FIELDS_OFFSET: u32 = 100_000;
+
+/// Scalar types of protobuf, with the names of the corresponding `prost`
+/// field kinds and Rust types.
+const SCALARS: &[(&str, &str)] = &[
+    ("double", "f64"),
+    ("float", "f32"),
+    ("int32", "i32"),
+    ("int64", "i64"),
+    ("uint32", "u32"),
+    ("uint64", "u64"),
+    ("sint32", "i32"),
+    ("sint64", "i64"),
+    ("fixed32", "u32"),
+    ("fixed64", "u64"),
+    ("sfixed32", "i32"),
+    ("sfixed64", "i64"),
+    ("bool", "bool"),
+    ("string", "String"),
+    ("bytes", "Vec<u8>"),
+];
+
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "The protobuf binding `{expression}` of the type `{type}` is not a scalar type, optionally `repeated`"
+)]
+pub struct InvalidProtobufType {
+    pub expression: String,
+    pub r#type: String,
+}
+
+/// Protobuf type of a field: a scalar, e.g. `uint32`, or a list of scalars,
+/// e.g. `repeated string`.
+pub struct ProtoType {
+    pub scalar: &'static str,
+    pub repeated: bool,
+    /// Rust type of the field in `prost` messages, e.g. `Vec<String>`.
+    pub rust_type: String,
+}
+
+impl ProtoType {
+    fn parse(expression: &str) -> Option<Self> {
+        let (repeated, scalar) = match expression.trim().strip_prefix("repeated ") {
+            Some(scalar) => (true, scalar.trim()),
+            None => (false, expression.trim()),
+        };
+        let &(scalar, rust_type) = SCALARS.iter().find(|(name, _)| *name == scalar)?;
+        Some(Self {
+            scalar,
+            repeated,
+            rust_type: if repeated {
+                format!("Vec<{rust_type}>")
+            } else {
+                rust_type.to_owned()
+            },
+        })
+    }
+}
+
+impl std::fmt::Display for ProtoType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.repeated {
+            f.write_str("repeated ")?;
+        }
+        f.write_str(self.scalar)
+    }
+}
+
+pub struct ProtoField<'a> {
+    pub field: &'a FieldDescription,
+    pub r#type: ProtoType,
+    /// Field number, given by the position of the field in the error.
+    pub number: u32,
+    /// Rust expression converting `value`, a reference to the value of the
+    /// field, into the protobuf type; `Into` is used by default.
+    pub to_proto: Option<String>,
+    /// Rust expression converting `value`, the decoded protobuf field, into
+    /// a `Result` of the type of the field; `TryFrom` is used by default.
+    pub from_proto: Option<String>,
+}
+
+pub struct ProtoMessage<'a> {
+    pub error: &'a ErrorDescription,
+    /// Name of the message, e.g. `SequencerGenericError`.
+    pub name: String,
+    pub fields: Vec<ProtoField<'a>>,
+}
+
+impl ProtoMessage<'_> {
+    /// Name of the field of the message in the `oneof` of the detail
+    /// message, e.g. `sequencer_generic_error`.
+    pub fn oneof_field_name(&self) -> String {
+        snake_case(&self.name)
+    }
+
+    /// Number of the field of the message in the `oneof` of the detail
+    /// message.
+    pub fn oneof_field_number(&self) -> u32 {
+        FIELDS_OFFSET + self.error.encoded_code()
+    }
+}
+
+/// An error without a protobuf message, because the type of one of its
+/// fields has no protobuf binding.
+pub struct UnsupportedError<'a> {
+    pub error: &'a ErrorDescription,
+    pub field: &'a FieldDescription,
+}
+
+/// Prefix of the names of the messages and of the enum of a component,
+/// e.g. `Sequencer`.
+fn component_name(component: &ComponentMetadata) -> &str {
+    component
+        .bindings
+        .get(PROTOBUF_LANGUAGE_NAME)
+        .unwrap_or(&component.identifier.name)
+}
+
+/// Name of the enum of the error codes of a component, e.g.
+/// `SequencerErrorCode`.
+pub fn enum_name(component: &ComponentMetadata) -> String {
+    format!("{}ErrorCode", component_name(component))
+}
+
+/// Name of the value of an error in the enum of its component; values are
+/// prefixed with the name of the enum, as protobuf requires them to be
+/// unique in the package, e.g. `SEQUENCER_ERROR_CODE_GENERIC_ERROR`.
+pub fn enum_value_name(error: &ErrorDescription) -> String {
+    format!(
+        "{}_{}",
+        snake_case(&enum_name(&error.component)),
+        snake_case(&error.name)
+    )
+    .to_ascii_uppercase()
+}
+
+/// Errors of a component ordered by code, as protobuf requires the first
+/// value of an enum to be zero, which is the code of the generic error.
+pub fn errors_by_code(component: &ComponentDescription) -> Vec<&ErrorDescription> {
+    let mut errors: Vec<_> = component.errors.iter().collect();
+    errors.sort_by_key(|error| error.code);
+    errors
+}
+
+fn proto_message<'a>(
+    model: &Model,
+    error: &'a ErrorDescription,
+) -> Result<Result<ProtoMessage<'a>, UnsupportedError<'a>>, InvalidProtobufType> {
+    let mut fields = vec![];
+    for (number, field) in (1..).zip(&error.fields) {
+        let Ok(binding) = model.get_type(PROTOBUF_LANGUAGE_NAME, &field.r#type) else {
+            return Ok(Err(UnsupportedError { error, field }));
+        };
+        let r#type = ProtoType::parse(&binding.expression).ok_or_else(|| InvalidProtobufType {
+            expression: binding.expression.clone(),
+            r#type: field.r#type.clone(),
+        })?;
+        fields.push(ProtoField {
+            field,
+            r#type,
+            number,
+            to_proto: binding.to_proto.clone(),
+            from_proto: binding.from_proto.clone(),
+        });
+    }
+    let name = match error.bindings.get(PROTOBUF_LANGUAGE_NAME) {
+        Some(binding) => binding.expression.clone(),
+        None => format!("{}{}", component_name(&error.component), error.name),
+    };
+    Ok(Ok(ProtoMessage {
+        error,
+        name,
+        fields,
+    }))
+}
+
+/// Protobuf messages of the errors of a component, in the order of the
+/// model.
+pub fn proto_messages<'a>(
+    model: &Model,
+    component: &'a ComponentDescription,
+) -> Result<Vec<Result<ProtoMessage<'a>, UnsupportedError<'a>>>, InvalidProtobufType> {
+    component
+        .errors
+        .iter()
+        .map(|error| proto_message(model, error))
+        .collect()
+}
@@ -0,0 +1,256 @@
+//!
+//! Extra derives and attributes of the generated types, configured globally
+//! through the backend arguments and per item through the bindings.
+//!
+
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use zksync_error_model::inner::Annotations;
+use zksync_error_model::inner::ComponentMetadata;
+use zksync_error_model::inner::DomainDescription;
+use zksync_error_model::inner::LanguageName;
+
+use crate::backend::Backend as _;
+use crate::backend::rust::RustBackend;
+use crate::backend::rust::error::GenerationError;
+use crate::backend::rust::util::derives::BASE_DERIVES;
+use crate::backend::rust::util::derives::builtin_implements;
+use crate::backend::rust::util::derives::same_trait;
+
+fn rust_annotations(annotations: &BTreeMap<LanguageName, Annotations>) -> Option<&Annotations> {
+    annotations.get(RustBackend::get_language_name())
+}
+
+/// Appends derives not yet present in `result`, skipping the base ones.
+fn add_derives<'a>(result: &mut Vec<String>, derives: impl IntoIterator<Item = &'a String>) {
+    for derive in derives {
+        let known = BASE_DERIVES
+            .iter()
+            .copied()
+            .chain(result.iter().map(String::as_str))
+            .any(|existing| same_trait(existing, derive));
+        if !known {
+            result.push(derive.trim().to_owned());
+        }
+    }
+}
+
+/// Derives present in all of the lists.
+fn common_derives(mut lists: impl Iterator<Item = Vec<String>>) -> Vec<String> {
+    let Some(first) = lists.next() else {
+        return vec![];
+    };
+    lists.fold(first, |common, derives| {
+        common
+            .into_iter()
+            .filter(|derive| derives.iter().any(|other| same_trait(derive, other)))
+            .collect()
+    })
+}
+
+/// Parses a derive or an attribute; attributes may be written either as
+/// `serde(tag = "kind")` or as `#[serde(tag = "kind")]`.
+fn parse_annotation(annotation: &str, item: &str) -> Result<TokenStream, GenerationError> {
+    let annotation = annotation.trim();
+    let annotation = annotation
+        .strip_prefix("#[")
+        .and_then(|annotation| annotation.strip_suffix(']'))
+        .unwrap_or(annotation);
+    annotation
+        .parse()
+        .map_err(|_| GenerationError::InvalidAnnotation {
+            annotation: annotation.to_owned(),
+            item: item.to_owned(),
+        })
+}
+
+fn derive_tokens(derives: &[String], item: &str) -> Result<TokenStream, GenerationError> {
+    if derives.is_empty() {
+        return Ok(quote! {});
+    }
+    let derives = derives
+        .iter()
+        .map(|derive| parse_annotation(derive, item))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(quote! { #[derive( #( #derives ),* )] })
+}
+
+fn attribute_tokens<'a>(
+    attributes: impl IntoIterator<Item = &'a String>,
+    item: &str,
+) -> Result<TokenStream, GenerationError> {
+    let attributes = attributes
+        .into_iter()
+        .map(|attribute| parse_annotation(attribute, item))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(quote! { #( #[ #attributes ] )* })
+}
+
+impl RustBackend {
+    /// Extra derives of a component enum: global ones, then the ones of its
+    /// domain and of its own.
+    pub(crate) fn component_derives(&self, component: &ComponentMetadata) -> Vec<String> {
+        let mut result = vec![];
+        add_derives(&mut result, &self.config.derives);
+        for annotations in [
+            rust_annotations(&component.domain.annotations),
+            rust_annotations(&component.annotations),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            add_derives(&mut result, &annotations.derives);
+        }
+        result
+    }
+
+    /// Extra derives of a domain enum: the ones shared by all its components.
+    pub(crate) fn domain_derives(&self, domain: &DomainDescription) -> Vec<String> {
+        common_derives(
+            domain
+                .components
+                .values()
+                .map(|component| self.component_derives(&component.meta)),
+        )
+    }
+
+    /// Extra derives of the root enum: the ones shared by all domains.
+    pub(crate) fn root_derives(&self) -> Vec<String> {
+        common_derives(
+            self.model
+                .domains
+                .values()
+                .map(|domain| self.domain_derives(domain)),
+        )
+    }
+
+    pub(crate) fn component_annotation_tokens(
+        &self,
+        component: &ComponentMetadata,
+    ) -> Result<TokenStream, GenerationError> {
+        let item = &component.identifier.name;
+        let derives = derive_tokens(&self.component_derives(component), item)?;
+        let attributes = attribute_tokens(
+            self.config.attributes.iter().chain(
+                [
+                    rust_annotations(&component.domain.annotations),
+                    rust_annotations(&component.annotations),
+                ]
+                .into_iter()
+                .flatten()
+                .flat_map(|annotations| &annotations.attributes),
+            ),
+            item,
+        )?;
+        Ok(quote! { #derives #attributes })
+    }
+
+    /// Derives of domain enums are also derives of components, which are
+    /// validated before the generation.
+    pub(crate) fn domain_annotation_tokens(&self, domain: &DomainDescription) -> TokenStream {
+        derive_tokens(&self.domain_derives(domain), &domain.meta.identifier.name)
+            .expect("Derives are validated before the generation")
+    }
+
+    pub(crate) fn root_annotation_tokens(&self) -> TokenStream {
+        derive_tokens(&self.root_derives(), &self.config.root_type_name)
+            .expect("Derives are validated before the generation")
+    }
+
+    pub(crate) fn error_annotation_tokens(
+        &self,
+        error: &zksync_error_model::inner::ErrorDescription,
+    ) -> Result<TokenStream, GenerationError> {
+        attribute_tokens(
+            rust_annotations(&error.annotations)
+                .into_iter()
+                .flat_map(|annotations| &annotations.attributes),
+            &error.name,
+        )
+    }
+
+    /// All derives and attributes in use, to find the crates they refer to.
+    pub(crate) fn used_annotations(&self) -> Vec<&String> {
+        let items = self
+            .model
+            .domains
+            .values()
+            .map(|domain| &domain.meta.annotations)
+            .chain(
+                self.model
+                    .components()
+                    .map(|component| &component.meta.annotations),
+            )
+            .chain(self.model.errors().map(|error| &error.annotations));
+        self.config
+            .derives
+            .iter()
+            .chain(&self.config.attributes)
+            .chain(
+                items.filter_map(rust_annotations).flat_map(|annotations| {
+                    annotations.derives.iter().chain(&annotations.attributes)
+                }),
+            )
+            .collect()
+    }
+
+    /// Whether the type of a field implements a trait, so that it may be
+    /// derived for the errors containing the field.
+    pub(crate) fn type_implements(
+        &self,
+        type_name: &str,
+        derive: &str,
+    ) -> Result<bool, GenerationError> {
+        if let Some(domain) = self.model.domains.get(type_name) {
+            return Ok(self
+                .domain_derives(domain)
+                .iter()
+                .any(|existing| same_trait(existing, derive)));
+        }
+        if let Some(component) = self
+            .model
+            .components()
+            .find(|component| component.meta.identifier.name == type_name)
+        {
+            return Ok(self
+                .component_derives(&component.meta)
+                .iter()
+                .any(|existing| same_trait(existing, derive)));
+        }
+        let binding = self.model.get_type(Self::get_language_name(), type_name)?;
+        Ok(binding
+            .implements
+            .iter()
+            .any(|implemented| same_trait(implemented, derive))
+            || builtin_implements(&binding.expression, derive))
+    }
+
+    /// Ensures that all derives and attributes parse, and that the fields of
+    /// every error implement the extra derives of the component enum.
+    pub(crate) fn validate_annotations(&self) -> Result<(), GenerationError> {
+        for component in self.model.components() {
+            self.component_annotation_tokens(&component.meta)?;
+            for error in &component.errors {
+                self.error_annotation_tokens(error)?;
+            }
+            for derive in self.component_derives(&component.meta) {
+                for error in &component.errors {
+                    for field in &error.fields {
+                        if !self.type_implements(&field.r#type, &derive)? {
+                            return Err(GenerationError::UnsupportedDerive {
+                                derive,
+                                item: component.meta.identifier.name.clone(),
+                                error: error.name.clone(),
+                                field: field.name.clone(),
+                                r#type: field.r#type.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
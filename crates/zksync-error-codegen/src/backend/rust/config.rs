@@ -1,10 +1,94 @@
+use std::collections::BTreeMap;
+
 use crate::backend::IBackendConfig;
 use crate::backend::arguments::ArgumentError;
 use crate::backend::arguments::parse_bool;
 
-#[derive(Default)]
+/// Severity reported by the generated `miette::Diagnostic` implementations.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DiagnosticSeverity {
+    Advice,
+    Warning,
+    #[default]
+    Error,
+}
+
+/// Where the generated crate takes `zksync-error-description` from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DescriptionSource {
+    Path(String),
+    /// Git repository; without `rev`, the branch `main` is used.
+    Git {
+        url: String,
+        rev: Option<String>,
+    },
+    Registry {
+        version: String,
+    },
+}
+
+impl Default for DescriptionSource {
+    fn default() -> Self {
+        DescriptionSource::Git {
+            url: Config::SHARED_MODEL_CRATE_URL.to_owned(),
+            rev: None,
+        }
+    }
+}
+
 pub struct Config {
     pub generate_cargo_toml: bool,
+    /// Emit a buildable `Cargo.toml` instead of `Cargo.example.toml`.
+    pub complete_cargo_toml: bool,
+    pub description_source: DescriptionSource,
+    /// Specifications of the crates referenced by the type bindings, in
+    /// TOML, by crate name. A bare version is accepted as well.
+    pub dependencies: BTreeMap<String, String>,
+    /// Name of the root error type, `ZksyncError` by default.
+    pub root_type_name: String,
+    /// Name of the generated crate, `zksync_error` by default.
+    pub crate_name: String,
+    /// Path to the generated crate used in the exported macros; derived from
+    /// `crate_name` by default.
+    pub crate_path: Option<String>,
+    pub diagnostic_severity: DiagnosticSeverity,
+    /// Gate every domain behind a cargo feature `domain_<encoding>`.
+    pub domain_features: bool,
+    /// Extra derives of all generated component enums, e.g. `Hash`.
+    pub derives: Vec<String>,
+    /// Extra attributes of all generated component enums, without `#[..]`.
+    pub attributes: Vec<String>,
+    /// Omit internal domains, components and errors from the dump of the
+    /// error hierarchy shipped with the crate.
+    pub omit_internal_from_dump: bool,
+    /// Emit `tests/generated.rs` checking the identifiers, messages,
+    /// serialization and documentation of every error.
+    pub generate_tests: bool,
+    /// Base URL of the published MDBook documentation, linking every error to
+    /// `errors/<code>/`; also the fallback `url` of diagnostics for errors
+    /// without references.
+    pub documentation_url: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            generate_cargo_toml: false,
+            complete_cargo_toml: false,
+            description_source: DescriptionSource::default(),
+            dependencies: BTreeMap::new(),
+            root_type_name: Self::DEFAULT_ROOT_TYPE_NAME.to_owned(),
+            crate_name: Self::DEFAULT_CRATE_NAME.to_owned(),
+            crate_path: None,
+            diagnostic_severity: DiagnosticSeverity::default(),
+            domain_features: false,
+            omit_internal_from_dump: false,
+            generate_tests: false,
+            derives: vec![],
+            attributes: vec![],
+            documentation_url: None,
+        }
+    }
 }
 
 impl IBackendConfig for Config {
@@ -12,17 +96,116 @@ impl IBackendConfig for Config {
         args: impl Iterator<Item = (String, String)>,
     ) -> Result<Self, ArgumentError> {
         let mut config = Self::default();
+        let mut description_sources = vec![];
+        let mut description_rev = None;
         for (arg, val) in args {
             match arg.as_str() {
                 "generate_cargo_toml" => parse_bool(&arg, &val, &mut config.generate_cargo_toml)?,
-                _ => return Err(ArgumentError::UnsupportedArgument { argument: arg }),
+                "domain_features" => parse_bool(&arg, &val, &mut config.domain_features)?,
+                "complete_cargo_toml" => parse_bool(&arg, &val, &mut config.complete_cargo_toml)?,
+                "generate_tests" => parse_bool(&arg, &val, &mut config.generate_tests)?,
+                "omit_internal_from_dump" => {
+                    parse_bool(&arg, &val, &mut config.omit_internal_from_dump)?
+                }
+                "description_path" => {
+                    description_sources.push((arg, DescriptionSource::Path(val)));
+                }
+                "description_git" => {
+                    description_sources.push((
+                        arg,
+                        DescriptionSource::Git {
+                            url: val,
+                            rev: None,
+                        },
+                    ));
+                }
+                "description_version" => {
+                    description_sources.push((arg, DescriptionSource::Registry { version: val }));
+                }
+                "description_rev" => description_rev = Some((arg, val)),
+                "diagnostic_severity" => {
+                    config.diagnostic_severity = match val.as_str() {
+                        "advice" => DiagnosticSeverity::Advice,
+                        "warning" => DiagnosticSeverity::Warning,
+                        "error" => DiagnosticSeverity::Error,
+                        _ => {
+                            return Err(ArgumentError::InvalidArgument {
+                                value: val,
+                                argument: arg,
+                                message: "expected one of `advice`, `warning`, `error`".into(),
+                            });
+                        }
+                    }
+                }
+                "documentation_url" => {
+                    config.documentation_url = Some(val.trim_end_matches('/').to_owned())
+                }
+                "derive" => config.derives.extend(
+                    val.split(',')
+                        .map(str::trim)
+                        .filter(|derive| !derive.is_empty())
+                        .map(String::from),
+                ),
+                "attribute" => config.attributes.push(val),
+                "root_type_name" => config.root_type_name = val,
+                "crate_name" => config.crate_name = val,
+                "crate_path" => config.crate_path = Some(val),
+                _ => match arg.strip_prefix("dependency.") {
+                    Some(name) if !name.is_empty() => {
+                        config.dependencies.insert(name.to_owned(), val);
+                    }
+                    _ => return Err(ArgumentError::UnsupportedArgument { argument: arg }),
+                },
+            }
+        }
+
+        if let [(_, first), (argument, second), ..] = description_sources.as_slice() {
+            return Err(ArgumentError::InvalidArgument {
+                value: value_of(second),
+                argument: argument.clone(),
+                message: format!(
+                    "`zksync-error-description` is already taken from `{}`",
+                    value_of(first)
+                ),
+            });
+        }
+        if let Some((_, source)) = description_sources.pop() {
+            config.description_source = source;
+        }
+        if let Some((argument, value)) = description_rev {
+            match &mut config.description_source {
+                DescriptionSource::Git { rev, .. } => *rev = Some(value),
+                _ => {
+                    return Err(ArgumentError::InvalidArgument {
+                        value,
+                        argument,
+                        message: "a revision requires a git source of `zksync-error-description`"
+                            .into(),
+                    });
+                }
             }
         }
         Ok(config)
     }
 }
+fn value_of(source: &DescriptionSource) -> String {
+    match source {
+        DescriptionSource::Path(path) => path.clone(),
+        DescriptionSource::Git { url, .. } => url.clone(),
+        DescriptionSource::Registry { version } => version.clone(),
+    }
+}
+
 impl Config {
+    pub const DEFAULT_ROOT_TYPE_NAME: &str = "ZksyncError";
+    pub const DEFAULT_CRATE_NAME: &str = "zksync_error";
     pub const SHARED_MODEL_CRATE_URL: &str = r"https://github.com/matter-labs/zksync-error";
     pub const PREAMBLE: &'static str =
         "AUTOGENERATED BASED ON A SET OF JSON FILES, DO NOT EDIT MANUALLY";
+
+    pub fn crate_path(&self) -> String {
+        self.crate_path
+            .clone()
+            .unwrap_or_else(|| self.crate_name.replace('-', "_"))
+    }
 }
@@ -1,5 +1,7 @@
 use zksync_error_model::error::ModelValidationError;
 
+use crate::backend::protobuf::schema::InvalidProtobufType;
+
 #[derive(Debug, thiserror::Error)]
 pub enum GenerationError {
     #[error(transparent)]
@@ -8,4 +10,35 @@ pub enum GenerationError {
     ModelSerialization(#[from] serde_json::Error),
     #[error("rustfmt: {0:#?}")]
     FormatError(#[from] rustfmt_wrapper::Error),
+    #[error(
+        "`{expression}` refers to the crate `{crate_name}`; specify it with the backend argument `dependency.{crate_name}`"
+    )]
+    UnknownDependency {
+        crate_name: String,
+        expression: String,
+    },
+    #[error(
+        "Unable to derive `{derive}` for `{item}`: field `{field}` of the error `{error}` has the type `{type}`, which does not implement it. If it does, list the trait in `implements` of the type binding."
+    )]
+    UnsupportedDerive {
+        derive: String,
+        item: String,
+        error: String,
+        field: String,
+        r#type: String,
+    },
+    #[error("Unable to parse `{annotation}`, a derive or attribute of `{item}`")]
+    InvalidAnnotation { annotation: String, item: String },
+    #[error("Unable to parse `{strategy}`, the property-testing strategy of the type `{type}`")]
+    InvalidStrategy { strategy: String, r#type: String },
+    #[error(
+        "Unable to build a value of the type `{type}` in the generated tests: it implements neither `Default` nor has a property-testing strategy. If it implements `Default`, list the trait in `implements` of the type binding."
+    )]
+    MissingSampleValue { r#type: String },
+    #[error("Unable to parse `{expression}` in the Solidity binding of the type `{type}`")]
+    InvalidAbiBinding { expression: String, r#type: String },
+    #[error(transparent)]
+    InvalidProtobufType(#[from] InvalidProtobufType),
+    #[error("Unable to parse `{expression}` in the protobuf binding of the type `{type}`")]
+    InvalidProtobufBinding { expression: String, r#type: String },
 }
@@ -0,0 +1,196 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use zksync_error_model::inner::ComponentDescription;
+
+use crate::backend::Backend as _;
+use crate::backend::File;
+use crate::backend::rust::RustBackend;
+use crate::backend::rust::error::GenerationError;
+use crate::backend::rust::util::codegen::ident;
+use crate::backend::rust::util::codegen::type_ident;
+
+impl RustBackend {
+    /// The strategy declared by the binding of a type, if any.
+    pub(crate) fn binding_strategy(
+        &self,
+        type_name: &str,
+    ) -> Result<Option<TokenStream>, GenerationError> {
+        let binding = self.model.get_type(Self::get_language_name(), type_name)?;
+        binding
+            .strategy
+            .as_ref()
+            .map(|strategy| {
+                strategy
+                    .parse()
+                    .map_err(|_| GenerationError::InvalidStrategy {
+                        strategy: strategy.clone(),
+                        r#type: type_name.to_owned(),
+                    })
+            })
+            .transpose()
+    }
+
+    /// Strategy generating values of a field type. Errors of the model are
+    /// represented by the generic error of a component, so that the
+    /// strategies of recursive errors remain finite.
+    fn field_strategy(&self, type_name: &str) -> Result<TokenStream, GenerationError> {
+        let binding = self.model.get_type(Self::get_language_name(), type_name)?;
+        let rust_type = type_ident(&binding.expression);
+        if let Some(strategy) = self.binding_strategy(type_name)? {
+            return Ok(strategy);
+        }
+        let generic_error = if let Some(domain) = self.model.domains.get(type_name) {
+            let component = &domain
+                .components
+                .values()
+                .next()
+                .expect("Domains without components are rejected by the validator")
+                .meta;
+            let domain_name = Self::domain_ident(&domain.meta);
+            let component_name = Self::component_ident(component);
+            Some(
+                quote! { #domain_name::#component_name(#component_name::GenericError { message }) },
+            )
+        } else {
+            self.model
+                .components()
+                .find(|component| component.meta.identifier.name == type_name)
+                .map(|component| {
+                    let component_name = Self::component_ident(&component.meta);
+                    quote! { #component_name::GenericError { message } }
+                })
+        };
+        Ok(match generic_error {
+            Some(value) => quote! {
+                any::<String>().prop_map(|message| -> #rust_type { #value.into() })
+            },
+            None => quote! { any::<#rust_type>() },
+        })
+    }
+
+    fn component_arbitrary(
+        &self,
+        component: &ComponentDescription,
+    ) -> Result<TokenStream, GenerationError> {
+        let cfg = self.domain_cfg(&component.meta.domain);
+        let component_name = Self::component_ident(&component.meta);
+        let variants = component
+            .errors
+            .iter()
+            .map(|error| -> Result<TokenStream, GenerationError> {
+                let error_name = Self::error_ident(error);
+                let names: Vec<_> = error.fields.iter().map(|field| ident(&field.name)).collect();
+                let strategies = error
+                    .fields
+                    .iter()
+                    .map(|field| self.field_strategy(&field.r#type))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(match names.as_slice() {
+                    [] => quote! { Just(#component_name::#error_name).boxed() },
+                    [name] => quote! {
+                        #( #strategies )*
+                            .prop_map(|#name| #component_name::#error_name { #name })
+                            .boxed()
+                    },
+                    _ => quote! {
+                        ( #( #strategies ),* )
+                            .prop_map(|( #( #names ),* )| #component_name::#error_name { #( #names ),* })
+                            .boxed()
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(quote! {
+            #cfg
+            impl Arbitrary for #component_name {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                    Union::new([ #( #variants ),* ]).boxed()
+                }
+            }
+        })
+    }
+
+    pub fn generate_file_arbitrary(&mut self) -> Result<File, GenerationError> {
+        let root_type = &self.root_type;
+        let components = self
+            .model
+            .components()
+            .map(|component| self.component_arbitrary(component))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let domains = self.model.domains.values().map(|domain| {
+            let cfg = self.domain_cfg(&domain.meta);
+            let domain_name = Self::domain_ident(&domain.meta);
+            let component_names = domain
+                .components
+                .values()
+                .map(|component| Self::component_ident(&component.meta));
+            quote! {
+                #cfg
+                impl Arbitrary for #domain_name {
+                    type Parameters = ();
+                    type Strategy = BoxedStrategy<Self>;
+
+                    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                        Union::new([
+                            #( any::<#component_names>().prop_map(#domain_name::#component_names).boxed() ),*
+                        ])
+                        .boxed()
+                    }
+                }
+            }
+        });
+
+        let all_domains = &self.all_domains;
+        let cfgs = &self.all_domain_cfgs;
+
+        let imports: BTreeSet<_> = self
+            .used_type_bindings()?
+            .into_iter()
+            .flat_map(|binding| binding.imports.iter())
+            .collect();
+        let imports = imports.into_iter().map(|path| type_ident(path));
+
+        let contents = quote! {
+            #![allow(unused)]
+
+            use proptest::prelude::*;
+            use proptest::strategy::BoxedStrategy;
+            use proptest::strategy::Union;
+
+            use crate::error::definitions::*;
+            use crate::error::domains::*;
+            #( use #imports; )*
+
+            #( #components )*
+
+            #( #domains )*
+
+            impl Arbitrary for #root_type {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                    let mut domains: Vec<BoxedStrategy<Self>> = Vec::new();
+                    #(
+                        #cfgs
+                        domains.push(any::<#all_domains>().prop_map(#root_type::#all_domains).boxed());
+                    )*
+                    Union::new(domains).boxed()
+                }
+            }
+        };
+
+        Ok(File {
+            content: Self::format_with_preamble(contents)?,
+            relative_path: PathBuf::from("src/arbitrary.rs"),
+        })
+    }
+}
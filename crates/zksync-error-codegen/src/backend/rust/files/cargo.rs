@@ -1,62 +1,543 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 
 use crate::backend::File;
+use crate::backend::protobuf::schema::PROTOBUF_LANGUAGE_NAME;
+use crate::backend::rust::config::DescriptionSource;
 use crate::backend::rust::error::GenerationError;
 use crate::backend::rust::{RustBackend, RustBackendConfig};
+use crate::backend::solidity::abi::SOLIDITY_LANGUAGE_NAME;
+
+/// Crates that may appear as path roots in type bindings without being
+/// dependencies.
+const BUILTIN_CRATES: &[&str] = &["alloc", "core", "crate", "self", "std", "super", "Self"];
+
+/// Modules and functions of `proptest::prelude` that strategies may use as
+/// path roots.
+const PROPTEST_PRELUDE: &[&str] = &["any", "any_with", "prop"];
+
+#[derive(Default)]
+struct Dependency {
+    /// Inline TOML table without braces, e.g. `version = "1.0"`.
+    specification: String,
+    /// Features enabled in addition to the ones in `specification`.
+    features: BTreeSet<String>,
+    optional: bool,
+    /// Enable the feature `serde` of the package along with `use_serde`.
+    serde_feature: bool,
+}
+
+impl Dependency {
+    fn required(specification: impl Into<String>) -> Self {
+        Self {
+            specification: specification.into(),
+            ..Default::default()
+        }
+    }
+    fn optional(specification: impl Into<String>) -> Self {
+        Self {
+            specification: specification.into(),
+            optional: true,
+            ..Default::default()
+        }
+    }
+    /// Accepts either an inline table, e.g. `{ version = "1.0" }`, or a bare
+    /// version, e.g. `1.0`.
+    fn from_argument(value: &str) -> Self {
+        let value = value.trim();
+        match value
+            .strip_prefix('{')
+            .and_then(|value| value.strip_suffix('}'))
+        {
+            Some(table) => Self::required(table.trim()),
+            None => Self::required(format!(r#"version = "{value}""#)),
+        }
+    }
+}
+
+/// Packages declared by type bindings, and undeclared crates mapped to type
+/// expressions that refer to them.
+type UsedPackages = (BTreeMap<String, Dependency>, BTreeMap<String, String>);
+
+/// Collects the roots of all paths in a Rust type expression, e.g.
+/// `serde_json` and `zksync_basic_types` for
+/// `Vec<(serde_json::Value, ::zksync_basic_types::H256)>`.
+fn referenced_crates(expression: &str) -> BTreeSet<String> {
+    let is_identifier = |c: char| c.is_alphanumeric() || c == '_';
+    let mut tokens = vec![];
+    let mut rest = expression.trim_start();
+    while let Some(c) = rest.chars().next() {
+        let length = if rest.starts_with("::") {
+            2
+        } else if is_identifier(c) {
+            rest.find(|c| !is_identifier(c)).unwrap_or(rest.len())
+        } else {
+            c.len_utf8()
+        };
+        tokens.push(&rest[..length]);
+        rest = rest[length..].trim_start();
+    }
+
+    let mut result = BTreeSet::new();
+    for (index, token) in tokens.iter().enumerate() {
+        let starts_path = tokens.get(index + 1) == Some(&"::");
+        // `a::b::c` refers to the crate `a` only; a leading `::` is allowed.
+        let continues_path =
+            index >= 2 && tokens[index - 1] == "::" && tokens[index - 2].starts_with(is_identifier);
+        if token.starts_with(is_identifier)
+            && starts_path
+            && !continues_path
+            && !BUILTIN_CRATES.contains(token)
+        {
+            result.insert(token.to_string());
+        }
+    }
+    result
+}
 
 impl RustBackend {
+    fn description_dependency(&self) -> Dependency {
+        let source = match &self.config.description_source {
+            DescriptionSource::Path(path) => format!(r#"path = "{path}""#),
+            DescriptionSource::Git {
+                url,
+                rev: Some(rev),
+            } => {
+                format!(r#"git = "{url}", rev = "{rev}""#)
+            }
+            DescriptionSource::Git { url, rev: None } => {
+                format!(r#"git = "{url}", branch = "main""#)
+            }
+            DescriptionSource::Registry { version } => format!(r#"version = "{version}""#),
+        };
+        Dependency::required(format!("{source}, default-features = false"))
+    }
+
+    /// Packages declared by the type bindings in use, and the crates that
+    /// the bindings, derives or attributes refer to without declaring them,
+    /// mapped to the expressions that refer to them.
+    fn packages_used_by_bindings(&self) -> Result<UsedPackages, GenerationError> {
+        let bindings = self.used_type_bindings()?;
+
+        let mut declared: BTreeMap<String, Dependency> = BTreeMap::new();
+        for dependency in bindings
+            .iter()
+            .filter_map(|binding| binding.dependency.as_ref())
+        {
+            let package = declared.entry(dependency.name.clone()).or_insert_with(|| {
+                Dependency::required(format!(r#"version = "{}""#, dependency.version))
+            });
+            package.features.extend(dependency.features.iter().cloned());
+            package.serde_feature |= dependency.use_serde;
+        }
+        let declared_crates: BTreeSet<_> = bindings
+            .iter()
+            .filter_map(|binding| binding.dependency.as_ref())
+            .map(|dependency| dependency.crate_name())
+            .collect();
+
+        let mut undeclared = BTreeMap::new();
+        for binding in bindings {
+            let crates = std::iter::once(&binding.expression)
+                .chain(&binding.imports)
+                .flat_map(|expression| referenced_crates(expression));
+            for crate_name in crates {
+                if !declared_crates.contains(&crate_name) {
+                    undeclared
+                        .entry(crate_name)
+                        .or_insert_with(|| binding.expression.clone());
+                }
+            }
+        }
+        for annotation in self.used_annotations() {
+            for crate_name in referenced_crates(annotation) {
+                if !declared_crates.contains(&crate_name) {
+                    undeclared
+                        .entry(crate_name)
+                        .or_insert_with(|| annotation.clone());
+                }
+            }
+        }
+        Ok((declared, undeclared))
+    }
+
+    /// Cargo features gating conversions from foreign types.
+    fn conversion_features(&self) -> BTreeSet<&str> {
+        self.model
+            .components()
+            .flat_map(|component| &component.conversions)
+            .filter_map(|conversion| conversion.feature.as_deref())
+            .collect()
+    }
+
+    /// Maps each crate referenced by the proptest strategies, Solidity ABI
+    /// conversions or protobuf conversions of the used types to the first
+    /// expression referencing it, skipping paths rooted in types or in the
+    /// `proptest` prelude, e.g. `Box::new` or `prop::collection::vec`.
+    fn crates_used_by_optional_expressions(
+        &self,
+    ) -> Result<BTreeMap<String, String>, GenerationError> {
+        let strategies = self
+            .used_type_bindings()?
+            .into_iter()
+            .filter_map(|binding| binding.strategy.as_ref());
+        let conversions = self
+            .model
+            .errors()
+            .flat_map(|error| &error.fields)
+            .filter_map(|field| {
+                self.model
+                    .get_type(SOLIDITY_LANGUAGE_NAME, &field.r#type)
+                    .ok()
+            })
+            .filter_map(|binding| binding.from_abi.as_ref());
+        let protobuf_conversions = self
+            .model
+            .errors()
+            .flat_map(|error| &error.fields)
+            .filter_map(|field| {
+                self.model
+                    .get_type(PROTOBUF_LANGUAGE_NAME, &field.r#type)
+                    .ok()
+            })
+            .flat_map(|binding| binding.to_proto.iter().chain(&binding.from_proto));
+
+        let mut result = BTreeMap::new();
+        for expression in strategies.chain(conversions).chain(protobuf_conversions) {
+            for crate_name in referenced_crates(expression).into_iter().filter(|name| {
+                name.starts_with(|c: char| c.is_lowercase())
+                    && !PROPTEST_PRELUDE.contains(&name.as_str())
+            }) {
+                result
+                    .entry(crate_name)
+                    .or_insert_with(|| expression.clone());
+            }
+        }
+        Ok(result)
+    }
+
+    fn dependencies(&self) -> Result<BTreeMap<String, Dependency>, GenerationError> {
+        let mut dependencies = BTreeMap::from([
+            (
+                "anyhow".to_owned(),
+                Dependency::optional(r#"version = "1.0", default-features = false"#),
+            ),
+            (
+                "miette".to_owned(),
+                Dependency::optional(r#"version = "7.2.0""#),
+            ),
+            (
+                "proptest".to_owned(),
+                Dependency::optional(r#"version = "1.5""#),
+            ),
+            (
+                "alloy-sol-types".to_owned(),
+                Dependency::optional(r#"version = "1.0", default-features = false"#),
+            ),
+            (
+                "prost".to_owned(),
+                Dependency::optional(
+                    r#"version = "0.14", default-features = false, features = ["derive"]"#,
+                ),
+            ),
+            (
+                "serde".to_owned(),
+                Dependency::optional(
+                    r#"version = "1.0.210", features = [ "derive", "alloc" ], default-features = false"#,
+                ),
+            ),
+            (
+                "serde_json".to_owned(),
+                Dependency::optional(r#"version = "1.0.128""#),
+            ),
+            (
+                "strum".to_owned(),
+                Dependency::required(
+                    r#"version = "0.27.1", default-features = false, features = ["derive"]"#,
+                ),
+            ),
+            (
+                "strum_macros".to_owned(),
+                Dependency::required(r#"version = "0.27.1", default-features = false"#),
+            ),
+            (
+                "zksync-error-description".to_owned(),
+                self.description_dependency(),
+            ),
+        ]);
+
+        let (declared, undeclared) = self.packages_used_by_bindings()?;
+        for (name, dependency) in declared {
+            match self.config.dependencies.get(&name) {
+                Some(specification) => {
+                    let overridden = Dependency::from_argument(specification);
+                    dependencies.insert(
+                        name,
+                        Dependency {
+                            serde_feature: dependency.serde_feature,
+                            ..overridden
+                        },
+                    );
+                }
+                None => {
+                    dependencies.insert(name, dependency);
+                }
+            }
+        }
+        // Strategies and conversions of ABI values and protobuf fields are
+        // compiled only with the features `proptest`, `revert_decoding` and
+        // `protobuf`, so the crates they refer to keep their optionality.
+        let undeclared: Vec<_> = undeclared
+            .into_iter()
+            .chain(
+                self.crates_used_by_optional_expressions()?
+                    .into_iter()
+                    .filter(|(crate_name, _)| !dependencies.contains_key(crate_name)),
+            )
+            .collect();
+        for (crate_name, expression) in undeclared {
+            if let Some(specification) = self.config.dependencies.get(&crate_name) {
+                dependencies.insert(crate_name, Dependency::from_argument(specification));
+            } else if let Some(dependency) = dependencies.get_mut(&crate_name) {
+                dependency.optional = false;
+            } else if self.config.complete_cargo_toml {
+                return Err(GenerationError::UnknownDependency {
+                    crate_name,
+                    expression,
+                });
+            } else {
+                dependencies.insert(crate_name, Dependency::required(r#"version = "*""#));
+            }
+        }
+        for (crate_name, specification) in &self.config.dependencies {
+            dependencies
+                .entry(crate_name.clone())
+                .or_insert_with(|| Dependency::from_argument(specification));
+        }
+        Ok(dependencies)
+    }
+
+    /// Cargo features gating domains, mapped to the features of the other
+    /// domains whose errors they wrap.
+    fn domain_features(&self) -> BTreeMap<String, BTreeSet<String>> {
+        let mut domains_by_type_name = BTreeMap::new();
+        for domain in self.model.domains.values() {
+            domains_by_type_name.insert(&domain.meta.identifier.name, &domain.meta);
+            for component in domain.components.values() {
+                domains_by_type_name.insert(&component.meta.identifier.name, &domain.meta);
+            }
+        }
+
+        self.model
+            .domains
+            .values()
+            .filter_map(|domain| {
+                let feature = self.domain_feature(&domain.meta)?;
+                let enables = domain
+                    .components
+                    .values()
+                    .flat_map(|component| &component.errors)
+                    .flat_map(|error| &error.fields)
+                    .filter_map(|field| domains_by_type_name.get(&field.r#type))
+                    .filter(|other| other.identifier != domain.meta.identifier)
+                    .filter_map(|other| self.domain_feature(other))
+                    .collect();
+                Some((feature, enables))
+            })
+            .collect()
+    }
+
     pub fn generate_file_cargo(&mut self) -> Result<Option<File>, GenerationError> {
-        if !self.config.generate_cargo_toml {
+        if !self.config.generate_cargo_toml && !self.config.complete_cargo_toml {
             return Ok(None);
         }
 
+        let dependencies = self.dependencies()?;
+        let is_optional = |name: &str| dependencies.get(name).is_some_and(|dep| dep.optional);
+
+        let mut use_serde = vec!["dep:serde".to_owned()];
+        use_serde.extend(
+            dependencies
+                .iter()
+                .filter(|(_, dependency)| dependency.serde_feature)
+                .map(|(name, _)| format!("{name}?/serde")),
+        );
+
+        let domain_features = self.domain_features();
+        let mut default = vec!["std", "use_anyhow", "use_serde"];
+        default.extend(domain_features.keys().map(String::as_str));
+
+        let builtin_features = [
+            ("default", default),
+            (
+                "std",
+                vec![
+                    "serde?/std",
+                    "anyhow?/std",
+                    "strum/std",
+                    "alloy-sol-types?/std",
+                    "prost?/std",
+                ],
+            ),
+            ("use_anyhow", vec!["dep:anyhow"]),
+            ("use_serde", use_serde.iter().map(String::as_str).collect()),
+            ("runtime_documentation", vec![]),
+            ("serialized_errors", vec!["dep:serde", "dep:serde_json"]),
+            ("packed_errors", vec!["use_serde"]),
+            ("miette", vec!["std", "dep:miette"]),
+            ("proptest", vec!["std", "dep:proptest"]),
+            ("revert_decoding", vec!["dep:alloy-sol-types"]),
+            ("protobuf", vec!["dep:prost"]),
+        ];
+        let domain_features: Vec<_> = domain_features
+            .iter()
+            .map(|(feature, enables)| {
+                (
+                    feature.as_str(),
+                    enables.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        // Conversions may also be gated behind one of the features above.
+        let conversion_features: Vec<_> = self
+            .conversion_features()
+            .into_iter()
+            .filter(|feature| {
+                !builtin_features
+                    .iter()
+                    .chain(&domain_features)
+                    .any(|(name, _)| name == feature)
+            })
+            .map(|feature| (feature, vec![]))
+            .collect();
+
+        // Features may only enable optional dependencies; required ones are
+        // always present.
+        let features = builtin_features
+            .into_iter()
+            .chain(domain_features)
+            .chain(conversion_features)
+            .map(|(feature, enables)| {
+                let enables: Vec<_> = enables
+                    .into_iter()
+                    .filter_map(|item| match item.strip_prefix("dep:") {
+                        Some(dependency) if !is_optional(dependency) => None,
+                        _ => match item.split_once("?/") {
+                            Some((dependency, feature)) if !is_optional(dependency) => {
+                                Some(format!(r#""{dependency}/{feature}""#))
+                            }
+                            _ => Some(format!(r#""{item}""#)),
+                        },
+                    })
+                    .collect();
+                format!("{feature} = [{}]", enables.join(", "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let dependencies = dependencies
+            .iter()
+            .map(|(name, dependency)| {
+                let optional = if dependency.optional {
+                    ", optional = true"
+                } else {
+                    ""
+                };
+                let features = if dependency.features.is_empty() {
+                    String::new()
+                } else {
+                    let features: Vec<_> = dependency
+                        .features
+                        .iter()
+                        .map(|feature| format!(r#""{feature}""#))
+                        .collect();
+                    format!(", features = [{}]", features.join(", "))
+                };
+                format!(
+                    "{name} = {{ {}{features}{optional} }}",
+                    dependency.specification
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
         let preamble = RustBackendConfig::PREAMBLE;
+        let crate_name = &self.config.crate_name;
+        let (build_dependencies, file_name) = if self.config.complete_cargo_toml {
+            (String::new(), "Cargo.toml")
+        } else {
+            (
+                format!(
+                    r#"
+[build-dependencies]
+zksync-error-codegen = {{ git = "{}", branch = "main", default-features = true }}"#,
+                    RustBackendConfig::SHARED_MODEL_CRATE_URL,
+                ),
+                "Cargo.example.toml",
+            )
+        };
+        // The generated tests check that errors round-trip through JSON.
+        let dev_dependencies = if self.config.generate_tests {
+            "\n[dev-dependencies]\nserde_json = { version = \"1.0.128\" }\n"
+        } else {
+            ""
+        };
         let content = format!(
             r#"######################################
 # {preamble}
 ######################################
 
 [package]
-name = "zksync_error"
+name = "{crate_name}"
 version = "0.1.0"
 edition = "2021"
 
 [features]
-default = ["std", "use_anyhow", "use_serde"]
-std = [ "serde/std", "lazy_static/spin_no_std", "anyhow?/std", "strum/std"]
-use_anyhow = ["dep:anyhow"]
-use_serde = ["dep:serde"]
-runtime_documentation = ["dep:serde", "dep:serde_json"]
-serialized_errors = ["dep:serde", "dep:serde_json"]
-packed_errors = ["use_serde"]
+{features}
 
 [dependencies]
-lazy_static = {{ version = "1.5.0", default-features = false, optional = true }}
-serde = {{ version = "1.0.210", features = [ "derive", "alloc" ], default-features = false, optional = true }}
-serde_json = {{ version = "1.0.128", optional = true }}
-strum = {{ version = "0.26.3", default-features = false, features = ["derive"] }}
-strum_macros = {{ version = "0.26.4", default-features = false }}
-zksync-error-description = {{ git = "{}", branch = "main", default-features = false }}
-
-[dependencies.anyhow]
-version = "1.0"
-optional = true
-default-features = false
+{dependencies}
 
 [lib]
 doctest = false
-
-[build-dependencies]
-zksync-error-codegen = {{ git = "https://github.com/matter-labs/zksync-error", branch = "main", default-features = true }}
-
-
+{dev_dependencies}{build_dependencies}
 "#,
-            RustBackendConfig::SHARED_MODEL_CRATE_URL,
         );
 
         Ok(Some(File {
             content,
-            relative_path: PathBuf::from("Cargo.example.toml"),
+            relative_path: PathBuf::from(file_name),
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crates(expression: &str) -> Vec<String> {
+        referenced_crates(expression).into_iter().collect()
+    }
+
+    #[test]
+    fn test_referenced_crates_of_plain_types() {
+        assert!(crates("u32").is_empty());
+        assert!(crates("Vec<(String,String)>").is_empty());
+        assert!(crates("std::io::Error").is_empty());
+        assert!(crates("Box<core::fmt::Error>").is_empty());
+    }
+
+    #[test]
+    fn test_referenced_crates_of_nested_paths() {
+        assert_eq!(crates("serde_json::Value"), vec!["serde_json"]);
+        assert_eq!(
+            crates("Vec<(serde_json::Value, ::zksync_basic_types::H256)>"),
+            vec!["serde_json", "zksync_basic_types"]
+        );
+        assert_eq!(
+            crates("Box<zksync_basic_types::web3::Bytes>"),
+            vec!["zksync_basic_types"]
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::path::PathBuf;
+use zksync_error_model::inner::ErrorDescription;
+
+use crate::backend::File;
+use crate::backend::mdbook::ERRORS_DIRECTORY;
+use crate::backend::rust::RustBackend;
+use crate::backend::rust::config::DiagnosticSeverity;
+use crate::backend::rust::error::GenerationError;
+use crate::backend::rust::util::codegen::DomainContext;
+use crate::backend::rust::util::codegen::map_domains;
+
+fn option_tokens(value: Option<String>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(Box::new(#value)) },
+        None => quote! { None },
+    }
+}
+
+impl RustBackend {
+    fn error_help(error: &ErrorDescription) -> Option<String> {
+        let fixes: Vec<_> = error
+            .documentation
+            .iter()
+            .flat_map(|doc| doc.likely_causes.iter())
+            .flat_map(|cause| cause.fixes.iter().cloned())
+            .collect();
+        if fixes.is_empty() {
+            None
+        } else {
+            Some(fixes.join("\n"))
+        }
+    }
+
+    fn error_url(&self, error: &ErrorDescription) -> Option<String> {
+        error
+            .documentation
+            .iter()
+            .flat_map(|doc| doc.likely_causes.iter())
+            .flat_map(|cause| cause.references.iter())
+            .next()
+            .cloned()
+            .or_else(|| {
+                self.config
+                    .documentation_url
+                    .as_ref()
+                    .map(|base| format!("{base}/{ERRORS_DIRECTORY}/{}/", error.encoded_code()))
+            })
+    }
+
+    pub fn generate_file_diagnostic(&mut self) -> Result<File, GenerationError> {
+        let root_type = &self.root_type;
+        let severity = match self.config.diagnostic_severity {
+            DiagnosticSeverity::Advice => quote! { Severity::Advice },
+            DiagnosticSeverity::Warning => quote! { Severity::Warning },
+            DiagnosticSeverity::Error => quote! { Severity::Error },
+        };
+
+        let component_impls = self.model.components().map(|component| {
+            let cfg = self.domain_cfg(&component.meta.domain);
+            let component_name = Self::component_ident(&component.meta);
+            let patterns: Vec<_> = component
+                .errors
+                .iter()
+                .map(|error| {
+                    let error_name = Self::error_ident(error);
+                    quote! { #component_name::#error_name { .. } }
+                })
+                .collect();
+            let codes = component
+                .errors
+                .iter()
+                .map(|error| error.get_identifier().to_string());
+            let helps = component
+                .errors
+                .iter()
+                .map(|error| option_tokens(Self::error_help(error)));
+            let urls = component
+                .errors
+                .iter()
+                .map(|error| option_tokens(self.error_url(error)));
+
+            quote! {
+                #cfg
+                impl Diagnostic for #component_name {
+                    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+                        Some(Box::new(match self {
+                            #( #patterns => #codes, )*
+                        }))
+                    }
+
+                    fn severity(&self) -> Option<Severity> {
+                        Some(#severity)
+                    }
+
+                    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+                        match self {
+                            #( #patterns => #helps, )*
+                        }
+                    }
+
+                    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+                        match self {
+                            #( #patterns => #urls, )*
+                        }
+                    }
+                }
+            }
+        });
+
+        let domain_impls = map_domains(
+            self,
+            |DomainContext {
+                 cfg,
+                 domain,
+                 components,
+                 ..
+             }| {
+                quote! {
+                    #cfg
+                    impl Diagnostic for #domain {
+                        fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+                            match self {
+                                #( #domain::#components(error) => error.code(), )*
+                            }
+                        }
+
+                        fn severity(&self) -> Option<Severity> {
+                            match self {
+                                #( #domain::#components(error) => error.severity(), )*
+                            }
+                        }
+
+                        fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+                            match self {
+                                #( #domain::#components(error) => error.help(), )*
+                            }
+                        }
+
+                        fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+                            match self {
+                                #( #domain::#components(error) => error.url(), )*
+                            }
+                        }
+                    }
+                }
+            },
+        );
+
+        let root_branches: Vec<_> = map_domains(self, |DomainContext { cfg, domain, .. }| {
+            quote! { #cfg #root_type::#domain(error) }
+        })
+        .collect();
+
+        let contents = quote! {
+            use core::fmt;
+
+            use miette::Diagnostic;
+            use miette::Severity;
+
+            use crate::error::definitions::*;
+            use crate::error::domains::*;
+
+            #( #component_impls )*
+
+            #( #domain_impls )*
+
+            impl Diagnostic for #root_type {
+                fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+                    match self {
+                        #( #root_branches => error.code(), )*
+                    }
+                }
+
+                fn severity(&self) -> Option<Severity> {
+                    match self {
+                        #( #root_branches => error.severity(), )*
+                    }
+                }
+
+                fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+                    match self {
+                        #( #root_branches => error.help(), )*
+                    }
+                }
+
+                fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+                    match self {
+                        #( #root_branches => error.url(), )*
+                    }
+                }
+            }
+        };
+
+        Ok(File {
+            content: Self::format_with_preamble(contents)?,
+            relative_path: PathBuf::from("src/diagnostic.rs"),
+        })
+    }
+}
@@ -1,24 +1,179 @@
+use proc_macro2::TokenStream;
 use quote::quote;
 use std::path::PathBuf;
+use zksync_error_model::inner::ComponentDescription;
+use zksync_error_model::inner::ErrorDocumentation;
+use zksync_error_model::inner::LikelyCause;
+use zksync_error_model::inner::VersionedOwner;
 
 use crate::backend::File;
+use crate::backend::mdbook::ERRORS_DIRECTORY;
 use crate::backend::rust::RustBackend;
 use crate::backend::rust::error::GenerationError;
+use crate::backend::rust::util::codegen::ident;
+
+fn owner_tokens(owner: &Option<VersionedOwner>) -> TokenStream {
+    match owner {
+        Some(VersionedOwner { name, version }) => quote! {
+            Some(VersionedOwner { name: #name, version: #version })
+        },
+        None => quote! { None },
+    }
+}
+
+fn likely_cause_tokens(likely_cause: &LikelyCause) -> TokenStream {
+    let LikelyCause {
+        cause,
+        fixes,
+        report,
+        owner,
+        references,
+    } = likely_cause;
+    let owner = owner_tokens(owner);
+    quote! {
+        LikelyCause {
+            cause: #cause,
+            fixes: &[ #( #fixes ),* ],
+            report: #report,
+            owner: #owner,
+            references: &[ #( #references ),* ],
+        }
+    }
+}
+
+fn documentation_tokens(documentation: &Option<ErrorDocumentation>) -> TokenStream {
+    match documentation {
+        Some(ErrorDocumentation {
+            description,
+            summary,
+            likely_causes,
+        }) => {
+            let summary = summary.clone().unwrap_or_default();
+            let likely_causes = likely_causes.iter().map(likely_cause_tokens);
+            quote! {
+                Some(&ErrorDocumentation {
+                    description: #description,
+                    summary: #summary,
+                    likely_causes: &[ #( #likely_causes ),* ],
+                })
+            }
+        }
+        None => quote! { None },
+    }
+}
+
+fn table_ident(component: &ComponentDescription) -> TokenStream {
+    ident(
+        &format!(
+            "{}_{}",
+            component.meta.domain.identifier.name, component.meta.identifier.name
+        )
+        .to_uppercase(),
+    )
+}
 
 impl RustBackend {
     pub fn generate_file_documentation(&mut self) -> Result<File, GenerationError> {
+        let tables = self.model.components().map(|component| {
+            let cfg = self.domain_cfg(&component.meta.domain);
+            let table = table_ident(component);
+            let mut errors: Vec<_> = component.errors.iter().collect();
+            errors.sort_by_key(|error| error.code);
+            let entries = errors.iter().map(|error| {
+                let code = error.code;
+                let documentation = documentation_tokens(&error.documentation);
+                quote! { (#code, #documentation) }
+            });
+            quote! {
+                #cfg
+                static #table: &[(u32, Option<&ErrorDocumentation>)] = &[ #( #entries ),* ];
+            }
+        });
+
+        let table_branches = self.model.components().map(|component| {
+            let cfg = self.domain_cfg(&component.meta.domain);
+            let domain = Self::domain_ident(&component.meta.domain);
+            let domain_code = Self::domain_code_ident(&component.meta.domain);
+            let component_ident = Self::component_ident(&component.meta);
+            let table = table_ident(component);
+            quote! {
+                #cfg
+                Kind::#domain(#domain_code::#component_ident) => #table,
+            }
+        });
+
+        let encoded_codes = self.model.components().flat_map(|component| {
+            let cfg = self.domain_cfg(&component.meta.domain);
+            let domain = Self::domain_ident(&component.meta.domain);
+            let domain_code = Self::domain_code_ident(&component.meta.domain);
+            let component_ident = Self::component_ident(&component.meta);
+            component.errors.iter().map(move |error| {
+                let code = error.code;
+                let encoded_code = error.encoded_code();
+                quote! {
+                    #cfg
+                    (Kind::#domain(#domain_code::#component_ident), #code) => #encoded_code,
+                }
+            })
+        });
+
+        let domain_codes = &self.all_domain_codes;
+        let cfgs = &self.all_domain_cfgs;
+        let documentation_url = match &self.config.documentation_url {
+            Some(url) => quote! { Some(#url) },
+            None => quote! { None },
+        };
+        let url_format = format!("{{base}}/{ERRORS_DIRECTORY}/{{}}/");
+
         let contents = quote! {
             use core::fmt;
-            use lazy_static::lazy_static;
-            use zksync_error_description::ErrorHierarchy;
 
-            lazy_static! {
-                pub static ref model : ErrorHierarchy = get_model();
+            #[cfg(not(feature = "std"))]
+            use alloc::{format, string::String};
+
+            use zksync_error_description::static_model::ErrorDocumentation;
+            use zksync_error_description::static_model::LikelyCause;
+            use zksync_error_description::static_model::VersionedOwner;
+
+            use crate::identifier::Identifier;
+            use crate::identifier::Identifying as _;
+            use crate::kind::Kind;
+            #( #cfgs use crate::error::domains::#domain_codes; )*
+
+            #( #tables )*
+
+            /// Documentation of all errors of a component, sorted by error code.
+            fn documentation_table(kind: &Kind) -> &'static [(u32, Option<&'static ErrorDocumentation>)] {
+                match kind {
+                    #( #table_branches )*
+                }
+            }
+
+            /// Finds the documentation of an error; returns `Ok(None)` if the
+            /// error is known but was not documented.
+            pub fn lookup(identifier: &Identifier) -> Result<Option<&'static ErrorDocumentation>, DocumentationError> {
+                let table = documentation_table(&identifier.kind);
+                match table.binary_search_by_key(&identifier.code, |(code, _)| *code) {
+                    Ok(index) => Ok(table[index].1),
+                    Err(_) => Err(DocumentationError::IncompleteModel(format!(
+                        "Can not fetch description for error {}.",
+                        identifier.get_identifier_repr()
+                    ))),
+                }
             }
 
+            /// Base URL of the published documentation of the errors, if any.
+            pub const DOCUMENTATION_URL: Option<&str> = #documentation_url;
 
-            fn get_model() -> ErrorHierarchy {
-                zksync_error_description::ErrorHierarchy::deserialize(include_str!("../resources/error-model-dump.json"))
+            /// URL of the documentation of an error, e.g.
+            /// `https://example.com/errors/11001/`, redirecting to its page, or
+            /// `None` if the error is not in the hierarchy.
+            pub fn url(identifier: &Identifier) -> Option<String> {
+                let encoded_code: u32 = match (&identifier.kind, identifier.code) {
+                    #( #encoded_codes )*
+                    _ => return None,
+                };
+                DOCUMENTATION_URL.map(|base| format!(#url_format, encoded_code))
             }
 
             #[derive(Debug)]
@@ -37,6 +192,8 @@ impl RustBackend {
             pub trait Documented {
                 type Documentation;
                 fn get_documentation(&self) -> Result<Option<Self::Documentation>, DocumentationError>;
+                /// URL of the published documentation of the error, if any.
+                fn get_documentation_url(&self) -> Option<String>;
             }
         };
         Ok(File {
@@ -1,17 +1,22 @@
 use proc_macro2::TokenStream;
 use quote::quote;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 use zksync_error_model::inner::ComponentDescription;
 
+use crate::backend::Backend as _;
 use crate::backend::File;
 use crate::backend::rust::RustBackend;
 use crate::backend::rust::error::GenerationError;
 use crate::backend::rust::util::codegen::doc_tokens;
 use crate::backend::rust::util::codegen::ident;
 use crate::backend::rust::util::codegen::type_ident;
+use zksync_error_model::error::ModelValidationError;
 use zksync_error_model::inner::ErrorDescription;
 use zksync_error_model::inner::ErrorDocumentation;
 use zksync_error_model::inner::FieldDescription;
+use zksync_error_model::inner::FieldInitializer;
+use zksync_error_model::inner::ForeignConversion;
 
 fn error_documentation(description: &ErrorDescription) -> TokenStream {
     if let Some(ErrorDocumentation {
@@ -57,27 +62,74 @@ impl RustBackend {
         }
         let error_name = RustBackend::error_ident(error);
         let doc = error_documentation(error);
+        let annotations = self.error_annotation_tokens(error)?;
         let field_tokens_if_nonempty = if fields.is_empty() {
             quote! {}
         } else {
             quote! { {  #( #field_tokens , )* } }
         };
         Ok(quote! { #doc
+                     #annotations
                      #error_name #field_tokens_if_nonempty = # code
         })
     }
 
+    fn foreign_conversion(
+        &self,
+        component: &ComponentDescription,
+        conversion: &ForeignConversion,
+    ) -> Result<TokenStream, GenerationError> {
+        let Some(source_type) = conversion.bindings.get(Self::get_language_name()) else {
+            return Ok(quote! {});
+        };
+        let source_type = type_ident(&source_type.expression);
+        let component_name = RustBackend::component_ident(&component.meta);
+        let error = component
+            .errors
+            .iter()
+            .find(|error| error.name == conversion.error)
+            .ok_or_else(|| ModelValidationError::UnmappedName(conversion.error.clone()))?;
+        let error_name = RustBackend::error_ident(error);
+        let field_initializers = conversion.fields.iter().map(|(name, initializer)| {
+            let name = ident(name);
+            let value = match initializer {
+                FieldInitializer::Display => quote! { format!("{value}") },
+                FieldInitializer::Debug => quote! { format!("{value:?}") },
+                FieldInitializer::Default => quote! { Default::default() },
+            };
+            quote! { #name : #value }
+        });
+        let cfg = match &conversion.feature {
+            Some(feature) => quote! { #[cfg(feature = #feature)] },
+            None => quote! {},
+        };
+        let domain_cfg = self.domain_cfg(&component.meta.domain);
+        Ok(quote! {
+            #domain_cfg
+            #cfg
+            impl From<#source_type> for #component_name {
+                fn from(value: #source_type) -> Self {
+                    #component_name::#error_name { #( #field_initializers , )* }
+                }
+            }
+        })
+    }
+
     pub fn generate_file_error_definitions(&mut self) -> Result<File, GenerationError> {
-        let definitions = self.model.components().map(|component| -> TokenStream {
+        let root_type = &self.root_type;
+        let definitions = self.model.components().map(|component| -> Result<TokenStream, GenerationError> {
 
 
+            let cfg = self.domain_cfg(&component.meta.domain);
             let component_code = RustBackend::component_code_ident(&component.meta);
-            let error_variants = component.errors.iter().flat_map(|component| self.error_variant(component));
+            let error_variants = component.errors.iter().map(|error| self.error_variant(error)).collect::<Result<Vec<_>, _>>()?;
+            let annotations = self.component_annotation_tokens(&component.meta)?;
             let component_name = RustBackend::component_ident(&component.meta);
 
             let component_doc = component_doc(component);
             let from_anyhow =
                     quote! {
+                        #cfg
                         #[cfg(feature = "use_anyhow")]
                         impl From<anyhow::Error> for #component_name {
                             fn from(value: anyhow::Error) -> Self {
@@ -87,7 +139,9 @@ impl RustBackend {
                         }
                     };
 
-            let impl_custom_error_message = {
+            let from_foreign = component.conversions.iter().flat_map(|conversion| self.foreign_conversion(component, conversion));
+
+            let impl_display = {
 
                 let branch_patterns = component.errors.iter().map(|error| {
                     let error_name = RustBackend::error_ident(error);
@@ -103,18 +157,27 @@ impl RustBackend {
 
                 let messages = component.errors.iter().map(|error| { format!("{} {}", error.get_identifier(), error.message) } );
                 quote! {
-                    impl CustomErrorMessage for #component_name {
-                        fn get_message(&self) -> String {
+                    #cfg
+                    impl fmt::Display for #component_name {
+                        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                             match self {
-                                #( #branch_patterns => { format! ( #messages ) } , )*
+                                #( #branch_patterns => write!(f, #messages ), )*
                             }
                         }
                     }
+
+                    #cfg
+                    impl CustomErrorMessage for #component_name {
+                        fn get_message(&self) -> String {
+                            format!("{self}")
+                        }
+                    }
                 }
 
             };
-            quote! {
+            Ok(quote! {
 
+                #cfg
                 #component_doc
                 #[repr(u32)]
                 #[derive(AsRefStr, Clone, Debug, Eq, EnumDiscriminants, PartialEq)]
@@ -123,53 +186,62 @@ impl RustBackend {
                 #[strum_discriminants(name(#component_code))]
                 #[strum_discriminants(vis(pub))]
                 #[strum_discriminants(derive(AsRefStr, FromRepr))]
+                #annotations
                 #[non_exhaustive]
                 pub enum #component_name {
 
                     #( #error_variants , )*
                 }
 
+                #cfg
                 impl core::error::Error for #component_name {}
 
+                #cfg
                 impl NamedError for #component_name {
                     fn get_error_name(&self) -> String {
                         self.as_ref().to_owned()
                     }
                 }
+                #cfg
                 impl NamedError for #component_code {
                     fn get_error_name(&self) -> String {
                         self.as_ref().to_owned()
                     }
                 }
 
-                impl From<#component_name> for crate::ZksyncError {
+                #cfg
+                impl From<#component_name> for crate::#root_type {
                     fn from(val: #component_name) -> Self {
                         val.to_unified()
                     }
                 }
-                impl fmt::Display for #component_name {
-                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                        f.write_str(&self.get_message())
-                    }
-                }
+                #cfg
                 #[cfg(feature="runtime_documentation")]
                 impl Documented for #component_name {
-                    type Documentation = &'static zksync_error_description::ErrorDocumentation;
+                    type Documentation = &'static zksync_error_description::static_model::ErrorDocumentation;
 
                     fn get_documentation(&self) -> Result<Option<Self::Documentation>, crate::documentation::DocumentationError> {
                         self.to_unified().get_identifier().get_documentation()
                     }
+
+                    fn get_documentation_url(&self) -> Option<String> {
+                        self.to_unified().get_identifier().get_documentation_url()
+                    }
                 }
                 #from_anyhow
 
+                #( #from_foreign )*
+
 
+                #cfg
                 #[cfg(feature="packed_errors")]
-                impl From<#component_name> for crate::packed::PackedError<crate::error::domains::ZksyncError> {
+                impl From<#component_name> for crate::packed::PackedError<crate::error::domains::#root_type> {
                     fn from(value: #component_name) -> Self {
                         crate::packed::pack(value)
                     }
                 }
 
+                #cfg
                 #[cfg(feature="serialized_errors")]
                 impl From<#component_name> for crate::serialized::SerializedError {
                     fn from(value: #component_name) -> Self {
@@ -178,10 +250,17 @@ impl RustBackend {
                     }
                 }
 
-                #impl_custom_error_message
-            }
+                #impl_display
+            })
 
-        });
+        }).collect::<Result<Vec<_>, _>>()?;
+
+        let imports: BTreeSet<_> = self
+            .used_type_bindings()?
+            .into_iter()
+            .flat_map(|binding| binding.imports.iter())
+            .collect();
+        let imports = imports.into_iter().map(|path| type_ident(path));
 
         let contents = quote! {
             #![allow(unused)]
@@ -202,6 +281,7 @@ impl RustBackend {
             use strum_macros::EnumDiscriminants;
             use strum_macros::FromRepr;
             use crate::error::domains::*;
+            #( use #imports; )*
 
             #( #definitions )*
         };
@@ -11,48 +11,74 @@ use crate::backend::rust::util::codegen::map_domains;
 
 impl RustBackend {
     pub fn generate_file_error_domains(&mut self) -> Result<File, GenerationError> {
+        let root_type = &self.root_type;
+        let root_derives = self.root_annotation_tokens();
         let all_domains = &self.all_domains;
-
-        let component_idents = self
-            .model
-            .components()
-            .map(|component| RustBackend::component_ident(&component.meta));
-        let component_code_idents = self
-            .model
-            .components()
-            .map(|component| RustBackend::component_code_ident(&component.meta));
+        let all_domain_cfgs = &self.all_domain_cfgs;
+
+        let component_imports = map_components(
+            self,
+            |ComponentContext {
+                 cfg,
+                 component,
+                 component_code,
+                 ..
+             }| {
+                quote! {
+                    #cfg
+                    use crate::error::definitions:: #component ;
+                    #cfg
+                    use crate::error::definitions:: #component_code ;
+                }
+            },
+        );
 
         let documented = {
             let documentation_branches =
-                map_domains(&self.model, |DomainContext { domain, .. }| {
+                map_domains(self, |DomainContext { cfg, domain, .. }| {
                     quote! {
-                        ZksyncError::#domain ( error ) => error.get_documentation() ,
+                        #cfg
+                        #root_type::#domain ( error ) => error.get_documentation() ,
+                    }
+                });
+            let documentation_url_branches =
+                map_domains(self, |DomainContext { cfg, domain, .. }| {
+                    quote! {
+                        #cfg
+                        #root_type::#domain ( error ) => error.get_documentation_url() ,
                     }
                 });
 
             quote! {
                 #[cfg(feature="runtime_documentation")]
-                impl crate::documentation::Documented for ZksyncError {
-                    type Documentation = &'static zksync_error_description::ErrorDocumentation;
+                impl crate::documentation::Documented for #root_type {
+                    type Documentation = &'static zksync_error_description::static_model::ErrorDocumentation;
 
                     fn get_documentation(&self) -> Result<Option<Self::Documentation>, crate::documentation::DocumentationError> {
                         match self {
                             #( #documentation_branches )*
                         }
                     }
+
+                    fn get_documentation_url(&self) -> Option<String> {
+                        match self {
+                            #( #documentation_url_branches )*
+                        }
+                    }
                 }
             }
         };
 
         let display = {
-            let display_branches = map_domains(&self.model, |DomainContext { domain, .. }| {
+            let display_branches = map_domains(self, |DomainContext { cfg, domain, .. }| {
                 quote! {
-                    ZksyncError::#domain ( domain_error ) => domain_error.fmt(f),
+                    #cfg
+                    #root_type::#domain ( domain_error ) => domain_error.fmt(f),
                 }
             });
 
             quote! {
-            impl fmt::Display for ZksyncError {
+            impl fmt::Display for #root_type {
                 fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                                     match self {
                                         #( #display_branches )*
@@ -64,15 +90,17 @@ impl RustBackend {
         let impl_zksync_error = {
             let get_kind = {
                 let branches = map_components(
-                    &self.model,
+                    self,
                     |ComponentContext {
+                         cfg,
                          domain,
                          domain_code,
                          component,
                          ..
                      }| {
                         quote! {
-                            ZksyncError::#domain( #domain :: #component(_)) => { Kind::#domain (#domain_code :: #component) }
+                            #cfg
+                            #root_type::#domain( #domain :: #component(_)) => { Kind::#domain (#domain_code :: #component) }
                         }
                     },
                 );
@@ -86,15 +114,17 @@ impl RustBackend {
             };
             let get_code = {
                 let branches = map_components(
-                    &self.model,
+                    self,
                     |ComponentContext {
+                         cfg,
                          domain,
                          component,
                          component_code,
                          ..
                      }| {
                         quote! {
-                            ZksyncError:: #domain (#domain :: #component(error)) => { Into::< #component_code >::into(error) as u32 },
+                            #cfg
+                            #root_type:: #domain (#domain :: #component(error)) => { Into::< #component_code >::into(error) as u32 },
                         }
                     },
                 );
@@ -107,7 +137,7 @@ impl RustBackend {
                 }
             };
             quote! {
-                impl ZksyncError {
+                impl #root_type {
 
                     #get_kind
 
@@ -117,17 +147,22 @@ impl RustBackend {
         };
 
         let component_definitions = map_domains(
-            &self.model,
+            self,
             |DomainContext {
+                 cfg,
+                 derives,
                  domain,
                  domain_code,
                  components,
+                 component_values,
                  ..
              }| {
                 quote! {
 
+                    #cfg
                     #[repr(u32)]
                     #[derive(AsRefStr, Clone, Debug, EnumDiscriminants, Eq, PartialEq)]
+                    #derives
                     #[strum_discriminants(derive(FromRepr))]
                     #[cfg_attr(feature = "use_serde", derive(serde::Serialize))]
                     #[cfg_attr(feature = "use_serde", derive(serde::Deserialize))]
@@ -135,21 +170,24 @@ impl RustBackend {
                     #[cfg_attr(feature = "use_serde", strum_discriminants(derive(serde::Serialize, serde::Deserialize)))]
                     #[strum_discriminants(vis(pub))]
                     pub enum #domain {
-                        #( #components( #components ),)*
+                        #( #components( #components ) = #component_values,)*
                     }
 
+                    #cfg
                     impl #domain {
                         pub fn get_name(&self) -> &str {
                             self.as_ref()
                         }
                     }
                     #(
-                        impl ICustomError<ZksyncError, ZksyncError> for #components {
-                            fn to_unified(&self) -> ZksyncError {
+                        #cfg
+                        impl ICustomError<#root_type, #root_type> for #components {
+                            fn to_unified(&self) -> #root_type {
                                  #domain :: #components (self.clone()).to_unified()
                             }
                         }
 
+                        #cfg
                         impl From<#components> for #domain {
                             fn from(val: #components) -> Self {
                                 #domain::#components(val)
@@ -158,21 +196,24 @@ impl RustBackend {
                     )*
 
 
-                    impl ICustomError<ZksyncError, ZksyncError> for #domain {
-                        fn to_unified(&self) -> ZksyncError {
-                            ZksyncError::#domain(self.clone())
+                    #cfg
+                    impl ICustomError<#root_type, #root_type> for #domain {
+                        fn to_unified(&self) -> #root_type {
+                            #root_type::#domain(self.clone())
                         }
                     }
 
-                    impl From<#domain> for ZksyncError {
+                    #cfg
+                    impl From<#domain> for #root_type {
                         fn from(value: #domain) -> Self {
                             value.to_unified()
                         }
                     }
 
+                    #cfg
                     #[cfg(feature="runtime_documentation")]
                     impl crate::documentation::Documented for #domain {
-                        type Documentation = &'static zksync_error_description::ErrorDocumentation;
+                        type Documentation = &'static zksync_error_description::static_model::ErrorDocumentation;
                         fn get_documentation(
                             &self,
                         ) -> Result<Option<Self::Documentation>, crate::documentation::DocumentationError> {
@@ -180,8 +221,14 @@ impl RustBackend {
                                 #( #domain :: #components(error) => error.get_documentation(),) *
                             }
                         }
+                        fn get_documentation_url(&self) -> Option<String> {
+                            match self {
+                                #( #domain :: #components(error) => error.get_documentation_url(),) *
+                            }
+                        }
                     }
 
+                    #cfg
                     impl fmt::Display for #domain {
                         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                             match self {
@@ -189,6 +236,7 @@ impl RustBackend {
                             }
                         }
                     }
+                    #cfg
                     impl core::error::Error for #domain {}
 
                 }
@@ -209,16 +257,14 @@ impl RustBackend {
             use strum_macros::AsRefStr;
             use strum_macros::EnumDiscriminants;
             use strum_macros::FromRepr;
-            #(
-                use crate::error::definitions:: #component_idents ;
-                use crate::error::definitions:: #component_code_idents ;
-            )*
+            #( #component_imports )*
 
             #[repr(u32)]
             #[derive(AsRefStr, Clone, Debug, EnumDiscriminants, Eq, PartialEq)]
+            #root_derives
             #[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
-            pub enum ZksyncError {
-                #( #all_domains( #all_domains ),)*
+            pub enum #root_type {
+                #( #all_domain_cfgs #all_domains( #all_domains ),)*
             }
 
             #documented
@@ -227,8 +273,8 @@ impl RustBackend {
 
             #impl_zksync_error
 
-            impl IUnifiedError<ZksyncError> for ZksyncError {}
-            impl core::error::Error for ZksyncError {}
+            impl IUnifiedError<#root_type> for #root_type {}
+            impl core::error::Error for #root_type {}
 
 
             #( #component_definitions )*
@@ -7,17 +7,21 @@ use crate::backend::rust::error::GenerationError;
 
 impl RustBackend {
     pub fn generate_file_error_mod(&mut self) -> Result<File, GenerationError> {
+        let root_type = &self.root_type;
         let domains = &self.all_domains;
+        let cfgs = &self.all_domain_cfgs;
 
         let impl_ierror_getmessage = {
             let match_tokens =
                 self.model.domains.values().flat_map(|domain_description| {
+                    let cfg = self.domain_cfg(&domain_description.meta);
                     let domain = Self::domain_ident(&domain_description.meta);
 
                     domain_description.components.values().map( move |component_description|  {
                         let component = Self::component_ident(&component_description.meta);
                         quote! {
-                            ZksyncError:: #domain ( #domain :: #component (error)) => error.get_message()
+                            #cfg
+                            #root_type:: #domain ( #domain :: #component (error)) => error.get_message()
                         }
                     }
                     )
@@ -47,9 +51,9 @@ impl RustBackend {
 
             use core::error::Error;
             use crate::identifier::Identifier;
-            use crate::error::domains::ZksyncError;
+            use crate::error::domains::#root_type;
 
-            #( use crate::error::domains:: #domains ; )*
+            #( #cfgs use crate::error::domains:: #domains ; )*
 
             pub trait IError<ContainedType>: Error
             where
@@ -91,7 +95,7 @@ impl RustBackend {
                 fn get_error_name(&self) -> String;
             }
 
-            impl IError<ZksyncError> for ZksyncError {
+            impl IError<#root_type> for #root_type {
                 fn get_identifier(&self) -> Identifier {
                     Identifier {
                         kind: self.get_kind(),
@@ -101,7 +105,7 @@ impl RustBackend {
 
                 #impl_ierror_getmessage
 
-                fn get_data(&self) -> ZksyncError {
+                fn get_data(&self) -> #root_type {
                     self.clone()
                 }
             }
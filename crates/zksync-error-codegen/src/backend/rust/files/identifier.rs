@@ -8,17 +8,16 @@ use crate::backend::rust::error::GenerationError;
 impl RustBackend {
     pub fn generate_file_identifier(&mut self) -> Result<File, GenerationError> {
         let domain_codes = &self.all_domain_codes;
-        let domains = &self.all_domains;
+        let cfgs = &self.all_domain_cfgs;
 
         let imports = quote! {
 
-            #(use crate::error::domains:: #domain_codes ;)*
+            #( #cfgs use crate::error::domains:: #domain_codes ;)*
 
             #[cfg(not(feature = "std"))]
             use alloc::{string::String, format};
 
             use crate::error::NamedError;
-            use crate::kind::DomainCode;
             use crate::kind::Kind;
         };
         let def_structured_error_code = quote! {
@@ -76,16 +75,213 @@ impl RustBackend {
 
                     pub fn decode(code: StructuredErrorCode) -> Option<Self> {
                         let StructuredErrorCode { domain_code, component_code, error_code } = code;
-                        let domain = DomainCode::from_repr(domain_code)?;
-                        let kind: Kind = match domain {
+                        let kind = Kind::from_codes(domain_code, component_code)?;
+                        Some(Identifier { kind, code: error_code })
+                    }
+
+                    /// Same as [`Identifier::decode`], but tells apart unknown
+                    /// components from the domains compiled out of this build.
+                    pub fn try_decode(code: StructuredErrorCode) -> Result<Self, ParseIdentifierError> {
+                        if let Some(feature) = Kind::disabled_domain_feature(code.domain_code) {
+                            return Err(ParseIdentifierError::DisabledDomain { feature });
+                        }
+                        Identifier::decode(code).ok_or(ParseIdentifierError::UnknownKind)
+                    }
+                }
+        };
+
+        let impl_identifier_enumeration = {
+            let mut all_identifiers = Vec::new();
+            let mut names = Vec::new();
+            let mut identifier_cfgs = Vec::new();
+            for component in self.model.components() {
+                let cfg = self.domain_cfg(&component.meta.domain);
+                let domain = Self::domain_ident(&component.meta.domain);
+                let domain_code = Self::domain_code_ident(&component.meta.domain);
+                let component_ident = Self::component_ident(&component.meta);
+                let component_name = &component.meta.identifier.name;
+                for error in &component.errors {
+                    let code = error.code;
+                    let error_name = &error.name;
+                    all_identifiers.push(quote! {
+                        Identifier { kind: Kind::#domain(#domain_code::#component_ident), code: #code }
+                    });
+                    names.push(quote! { #cfg (#component_name, #error_name) });
+                    identifier_cfgs.push(cfg.clone());
+                }
+            }
+
+            let known_branches = self.model.components().map(|component| {
+                let cfg = self.domain_cfg(&component.meta.domain);
+                let domain = Self::domain_ident(&component.meta.domain);
+                let domain_code = Self::domain_code_ident(&component.meta.domain);
+                let component_ident = Self::component_ident(&component.meta);
+                let component_code = Self::component_code_ident(&component.meta);
+                quote! {
+                    #cfg
+                    Kind::#domain(#domain_code::#component_ident) =>
+                        crate::error::definitions::#component_code::from_repr(self.code).is_some()
+                }
+            });
+
+            quote! {
+                impl Identifier {
+                    /// Iterates over identifiers of all errors in the hierarchy.
+                    pub fn all() -> impl Iterator<Item = Identifier> {
+                        [ #( #identifier_cfgs #all_identifiers ),* ].into_iter()
+                    }
+
+                    /// Finds an error by the names of its component and of the
+                    /// error itself in the error description, e.g.
+                    /// `Identifier::from_error_name("Sequencer", "GenericSequencerError")`.
+                    pub fn from_error_name(component_name: &str, error_name: &str) -> Option<Self> {
+                        match (component_name, error_name) {
+                            #( #names => Some(#all_identifiers), )*
+                            _ => None,
+                        }
+                    }
+
+                    /// Returns `true` if the hierarchy contains an error with this identifier.
+                    pub fn is_known(&self) -> bool {
+                        match self.kind {
+                            #( #known_branches, )*
+                        }
+                    }
+                }
+            }
+        };
+
+        let impl_from_str = {
+            let kind_reprs = self.model.components().map(|component| {
+                format!(
+                    "{}-{}",
+                    component.meta.domain.identifier.encoding, component.meta.identifier.encoding
+                )
+            });
+            let kinds = self.model.components().map(|component| {
+                let domain = Self::domain_ident(&component.meta.domain);
+                let domain_code = Self::domain_code_ident(&component.meta.domain);
+                let component_ident = Self::component_ident(&component.meta);
+                quote! { Kind::#domain(#domain_code::#component_ident) }
+            });
+            let kind_cfgs = self
+                .model
+                .components()
+                .map(|component| self.domain_cfg(&component.meta.domain));
 
-                            #(
-                                DomainCode:: #domains => Kind:: #domains ( #domain_codes :: from_repr( component_code )? ) ,
-                            )*
+            quote! {
+                #[derive(Clone, Debug, Eq, PartialEq)]
+                pub enum ParseIdentifierError {
+                    Malformed,
+                    UnknownKind,
+                    UnknownError,
+                    /// The domain exists in the error hierarchy, but was
+                    /// compiled out of this build.
+                    DisabledDomain { feature: &'static str },
+                }
+
+                impl core::fmt::Display for ParseIdentifierError {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        match self {
+                            ParseIdentifierError::Malformed => f.write_str("malformed error identifier"),
+                            ParseIdentifierError::UnknownKind => f.write_str("unknown domain or component"),
+                            ParseIdentifierError::UnknownError => f.write_str("unknown error"),
+                            ParseIdentifierError::DisabledDomain { feature } => write!(
+                                f,
+                                "the domain is disabled, enable the cargo feature `{feature}`"
+                            ),
+                        }
+                    }
+                }
+                impl core::error::Error for ParseIdentifierError {}
+
+                fn strip_brackets(s: &str) -> &str {
+                    let s = s.trim();
+                    s.strip_prefix('[')
+                        .and_then(|s| s.strip_suffix(']'))
+                        .unwrap_or(s)
+                }
+
+                /// Codes are written without leading zeros, so that every code
+                /// has a single representation.
+                fn parse_code(s: &str) -> Result<u32, ParseIdentifierError> {
+                    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) || (s.len() > 1 && s.starts_with('0')) {
+                        return Err(ParseIdentifierError::Malformed);
+                    }
+                    s.parse().map_err(|_| ParseIdentifierError::Malformed)
+                }
+
+                fn kind_from_identifier_repr(repr: &str) -> Result<Kind, ParseIdentifierError> {
+                    Kind::from_identifier_repr(repr).ok_or_else(|| {
+                        let domain = repr.split('-').next().unwrap_or(repr);
+                        match Kind::disabled_domain_feature_by_encoding(domain) {
+                            Some(feature) => ParseIdentifierError::DisabledDomain { feature },
+                            None => ParseIdentifierError::UnknownKind,
+                        }
+                    })
+                }
+
+                impl Kind {
+                    /// Finds a component by its identifier representation, e.g. `core-seq`.
+                    pub fn from_identifier_repr(repr: &str) -> Option<Self> {
+                        match repr {
+                            #( #kind_cfgs #kind_reprs => Some(#kinds), )*
+                            _ => None,
+                        }
+                    }
+                }
+
+                /// Accepts either the identifier representation of a component,
+                /// e.g. `core-seq` or `[core-seq]`, or its numeric code, e.g. `11`.
+                impl core::str::FromStr for Kind {
+                    type Err = ParseIdentifierError;
+
+                    fn from_str(s: &str) -> Result<Self, Self::Err> {
+                        let s = strip_brackets(s);
+                        if s.bytes().all(|b| b.is_ascii_digit()) {
+                            let code = parse_code(s)?;
+                            if let Some(feature) = Kind::disabled_domain_feature(code / 10) {
+                                return Err(ParseIdentifierError::DisabledDomain { feature });
+                            }
+                            Kind::from_codes(code / 10, code % 10)
+                                .filter(|kind| kind.domain_code() * 10 + kind.component_code() == code)
+                                .ok_or(ParseIdentifierError::UnknownKind)
+                        } else {
+                            kind_from_identifier_repr(s)
+                        }
+                    }
+                }
+
+                /// Accepts either the identifier representation of an error,
+                /// e.g. `[core-seq-1]` or `core-seq-1`, or its encoded numeric
+                /// code, e.g. `11001`.
+                impl core::str::FromStr for Identifier {
+                    type Err = ParseIdentifierError;
+
+                    fn from_str(s: &str) -> Result<Self, Self::Err> {
+                        let s = strip_brackets(s);
+                        let identifier = if s.bytes().all(|b| b.is_ascii_digit()) {
+                            let code = parse_code(s)?;
+                            let structured = StructuredErrorCode::decode(code);
+                            // Decoding drops the excess digits of the domain
+                            // and component codes, e.g. of `911001`.
+                            if structured.encode() != code {
+                                return Err(ParseIdentifierError::UnknownKind);
+                            }
+                            Identifier::try_decode(structured)?
+                        } else {
+                            let (kind, code) = s.rsplit_once('-').ok_or(ParseIdentifierError::Malformed)?;
+                            let kind = kind_from_identifier_repr(kind)?;
+                            Identifier::new(kind, parse_code(code)?)
                         };
-                        Some(Identifier { kind, code: error_code })
+                        if identifier.is_known() {
+                            Ok(identifier)
+                        } else {
+                            Err(ParseIdentifierError::UnknownError)
+                        }
                     }
                 }
+            }
         };
 
         let trait_identifying = quote! {
@@ -102,6 +298,7 @@ impl RustBackend {
 
         let impl_identifying_for_kind = {
             let match_tokens = self.model.domains.values().flat_map(|domain_description| {
+                let cfg = self.domain_cfg(&domain_description.meta);
                 let domain_code = Self::domain_code_ident(&domain_description.meta);
                 let domain = Self::domain_ident(&domain_description.meta);
                 let domain_contribution = &domain_description.meta.identifier.encoding;
@@ -115,6 +312,7 @@ impl RustBackend {
                             &component_description.meta.identifier.encoding;
                         let prefix = format!("{domain_contribution}-{component_contribution}");
                         quote! {
+                            #cfg
                             Kind :: #domain ( #domain_code :: #component ) =>
                                 #prefix
                         }
@@ -135,6 +333,7 @@ impl RustBackend {
         let impl_named_error = {
             let match_tokens =
                 self.model.domains.values().flat_map(|domain_description| {
+                    let cfg = self.domain_cfg(&domain_description.meta);
                     let domain_code = Self::domain_code_ident(&domain_description.meta);
                     let domain = Self::domain_ident(&domain_description.meta);
 
@@ -143,6 +342,7 @@ impl RustBackend {
                         let component = Self::component_ident(&component_description.meta);
 
                         quote! {
+                            #cfg
                             Kind :: #domain ( #domain_code :: #component ) =>
                                 crate::error::definitions:: #component_code ::from_repr(self.code).expect("Internal error").get_error_name()
                         }
@@ -164,15 +364,12 @@ impl RustBackend {
         let impl_documented = quote! {
                 #[cfg(feature="runtime_documentation")]
                 impl crate::documentation::Documented for Identifier {
-                    type Documentation = &'static zksync_error_description::ErrorDocumentation;
+                    type Documentation = &'static zksync_error_description::static_model::ErrorDocumentation;
                     fn get_documentation(&self) -> Result<Option<Self::Documentation>, crate::documentation::DocumentationError> {
-                        use crate::documentation::model;
-
-                        let repr = &self.get_identifier_repr();
-                        match model.errors.get(repr) {
-                            Some(metadata) => Ok(metadata.documentation.as_ref()),
-                            None => Err(crate::documentation::DocumentationError::IncompleteModel(format!("Can not fetch description for error {repr}.")))
-                        }
+                        crate::documentation::lookup(self)
+                    }
+                    fn get_documentation_url(&self) -> Option<String> {
+                        crate::documentation::url(self)
                     }
                 }
         };
@@ -188,6 +385,10 @@ impl RustBackend {
 
                 #impl_identifier
 
+                #impl_identifier_enumeration
+
+                #impl_from_str
+
                 #trait_identifying
 
                 #impl_identifying_for_kind
@@ -9,14 +9,55 @@ impl RustBackend {
     pub fn generate_file_kind(&mut self) -> Result<File, GenerationError> {
         let domains = &self.all_domains;
         let domain_codes = &self.all_domain_codes;
-        let codes = self.model.domains.values().map(|d| d.meta.identifier.code);
+        let cfgs = &self.all_domain_cfgs;
+        let codes: Vec<_> = self
+            .model
+            .domains
+            .values()
+            .map(|d| d.meta.identifier.code)
+            .collect();
+        let disabled_domains = self.model.domains.values().filter_map(|domain| {
+            let feature = self.domain_feature(&domain.meta)?;
+            let code = domain.meta.identifier.code;
+            let encoding = &domain.meta.identifier.encoding;
+            Some(quote! {
+                #[cfg(not(feature = #feature))]
+                (#code, #encoding, #feature)
+            })
+        });
+
+        let all_kinds: Vec<_> = self
+            .model
+            .components()
+            .map(|component| {
+                let cfg = self.domain_cfg(&component.meta.domain);
+                let domain = Self::domain_ident(&component.meta.domain);
+                let domain_code = Self::domain_code_ident(&component.meta.domain);
+                let component = Self::component_ident(&component.meta);
+                (cfg, quote! { Kind::#domain(#domain_code::#component) })
+            })
+            .collect();
+        let (kind_cfgs, all_kinds): (Vec<_>, Vec<_>) = all_kinds.into_iter().unzip();
+        let component_codes: Vec<_> = self
+            .model
+            .components()
+            .map(|component| component.meta.identifier.code)
+            .collect();
+        let component_domain_codes = self
+            .model
+            .components()
+            .map(|component| component.meta.domain.identifier.code);
+        let component_names = self
+            .model
+            .components()
+            .map(|component| &component.meta.identifier.name);
 
         let contents = quote! {
 
             use strum_macros::EnumDiscriminants;
             use strum_macros::FromRepr;
 
-            #(use crate::error::domains:: #domain_codes ;)*
+            #( #cfgs use crate::error::domains:: #domain_codes ;)*
 
             #[derive(Clone, Debug, EnumDiscriminants, Eq, PartialEq)]
             #[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
@@ -25,9 +66,13 @@ impl RustBackend {
             #[strum_discriminants(vis(pub))]
             #[repr(u32)]
             pub enum Kind {
-                #( #domains ( #domain_codes ) = #codes ,)*
+                #( #cfgs #domains ( #domain_codes ) = #codes ,)*
             }
 
+            /// Domains compiled out of this build: their codes, identifier
+            /// encodings and the cargo features that enable them.
+            const DISABLED_DOMAINS: &[(u32, &str, &str)] = &[ #( #disabled_domains ),* ];
+
             impl Kind {
                 pub fn domain_code(&self) -> u32 {
                     let domain: DomainCode = self.clone().into();
@@ -35,9 +80,47 @@ impl RustBackend {
                 }
                 pub fn component_code(&self) -> u32 {
                     match self {
-                        #( Kind:: #domains (component) => *component as u32, )*
+                        #( #kind_cfgs #all_kinds => #component_codes, )*
+                    }
+                }
+
+                pub fn from_codes(domain_code: u32, component_code: u32) -> Option<Self> {
+                    match (domain_code, component_code) {
+                        #( #kind_cfgs (#component_domain_codes, #component_codes) => Some(#all_kinds), )*
+                        _ => None,
+                    }
+                }
+
+                /// Iterates over all components of all domains.
+                pub fn all() -> impl Iterator<Item = Kind> {
+                    [ #( #kind_cfgs #all_kinds ),* ].into_iter()
+                }
+
+                /// Finds a component by its name in the error description, e.g. `Sequencer`.
+                pub fn from_component_name(name: &str) -> Option<Self> {
+                    match name {
+                        #( #kind_cfgs #component_names => Some(#all_kinds), )*
+                        _ => None,
                     }
                 }
+
+                /// If the domain with this code exists in the error hierarchy
+                /// but was compiled out, returns the cargo feature enabling it.
+                pub fn disabled_domain_feature(domain_code: u32) -> Option<&'static str> {
+                    DISABLED_DOMAINS
+                        .iter()
+                        .find(|(code, _, _)| *code == domain_code)
+                        .map(|(_, _, feature)| *feature)
+                }
+
+                /// Same as [`Kind::disabled_domain_feature`], but finds the domain
+                /// by its identifier encoding, e.g. `core`.
+                pub fn disabled_domain_feature_by_encoding(encoding: &str) -> Option<&'static str> {
+                    DISABLED_DOMAINS
+                        .iter()
+                        .find(|(_, domain_encoding, _)| *domain_encoding == encoding)
+                        .map(|(_, _, feature)| *feature)
+                }
             }
 
         };
@@ -10,6 +10,26 @@ use crate::backend::rust::util::codegen::ident;
 
 impl RustBackend {
     pub fn generate_file_lib(&mut self) -> Result<File, GenerationError> {
+        let root_type = &self.root_type;
+        let crate_path = self.crate_path();
+        let domain_features: Vec<_> = self
+            .model
+            .domains
+            .values()
+            .filter_map(|domain| self.domain_feature(&domain.meta))
+            .collect();
+        let require_domain = if domain_features.is_empty() {
+            quote! {}
+        } else {
+            let message = format!(
+                "Enable at least one of the cargo features {}.",
+                domain_features.join(", ")
+            );
+            quote! {
+                #[cfg(not(any( #( feature = #domain_features ),* )))]
+                compile_error!(#message);
+            }
+        };
         let imports = quote! {
             #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -19,6 +39,10 @@ impl RustBackend {
             #[cfg(not(feature = "std"))]
             use alloc::format;
 
+            #[cfg(feature="proptest")]
+            mod arbitrary;
+            #[cfg(feature="miette")]
+            mod diagnostic;
             #[cfg(feature="runtime_documentation")]
             pub mod documentation;
             pub(crate) mod error;
@@ -32,18 +56,26 @@ impl RustBackend {
             pub use identifier::StructuredErrorCode;
             pub use identifier::Identifier;
             pub use identifier::Identifying;
+            pub use identifier::ParseIdentifierError;
             pub(crate) mod kind;
             pub use kind::Kind;
+            pub mod matching;
+            pub use matching::ErrorMatching;
 
             #[cfg(feature="packed_errors")]
             pub mod packed;
+            #[cfg(feature="protobuf")]
+            pub mod protobuf;
+            #[cfg(feature="revert_decoding")]
+            pub mod revert;
             #[cfg(feature="serialized_errors")]
             pub mod serialized;
             #[cfg(feature="serialized_errors")]
             pub mod untyped;
 
-            pub use crate::error::domains::ZksyncError;
+            pub use crate::error::domains::#root_type;
 
+            #require_domain
         };
 
         let top_level_doc = {
@@ -68,6 +100,7 @@ impl RustBackend {
         };
 
         let interface_modules = self.model.domains.values().map( |domain| ->TokenStream{
+            let cfg = self.domain_cfg(&domain.meta);
             let outer_module = ident(&domain.meta.identifier.encoding);
 
             let domain_name = RustBackend::domain_ident(&domain.meta);
@@ -107,7 +140,7 @@ impl RustBackend {
                         #[macro_export]
                         macro_rules! #macro_name {
                             ($($arg:tt)*) => {
-                                zksync_error::#outer_module::#inner_module:: #alias_error::GenericError { message: format!($($arg)*) }
+                                #crate_path::#outer_module::#inner_module:: #alias_error::GenericError { message: format!($($arg)*) }
                             };
                         }
                         pub use crate:: #macro_name as generic_error;
@@ -130,6 +163,7 @@ impl RustBackend {
                 format_item(1, &ident(&e.meta.identifier.encoding).to_string())
             });
             quote!{
+                #cfg
                 #[doc = #domain_error_name ]
                 #( #[doc = #components_encoding ] )*
                 pub mod #outer_module {
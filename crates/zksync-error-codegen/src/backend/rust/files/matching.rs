@@ -0,0 +1,163 @@
+use quote::quote;
+use std::path::PathBuf;
+
+use crate::backend::File;
+use crate::backend::rust::RustBackend;
+use crate::backend::rust::error::GenerationError;
+use crate::backend::rust::util::codegen::ident;
+use crate::backend::rust::util::codegen::map_domains;
+use crate::backend::rust::util::snake_case;
+
+impl RustBackend {
+    /// Name of the assertion macro, e.g. `assert_zksync_error` for the root
+    /// type `ZksyncError`.
+    fn assertion_macro_name(&self) -> String {
+        format!("assert_{}", snake_case(&self.config.root_type_name))
+    }
+
+    pub fn generate_file_matching(&mut self) -> Result<File, GenerationError> {
+        let root_type = &self.root_type;
+        let macro_name = ident(&self.assertion_macro_name());
+
+        let impl_components = self.model.components().map(|component| {
+            let cfg = self.domain_cfg(&component.meta.domain);
+            let domain = Self::domain_ident(&component.meta.domain);
+            let domain_code = Self::domain_code_ident(&component.meta.domain);
+            let component_name = Self::component_ident(&component.meta);
+            let component_code = Self::component_code_ident(&component.meta);
+            quote! {
+                #cfg
+                impl From<#component_code> for Identifier {
+                    fn from(code: #component_code) -> Self {
+                        Identifier::new(Kind::#domain(#domain_code::#component_name), code as u32)
+                    }
+                }
+
+                #cfg
+                impl ErrorMatching for #component_name {
+                    fn error_identifier(&self) -> Option<Identifier> {
+                        Some(#component_code::from(self).into())
+                    }
+                }
+            }
+        });
+
+        let impl_domains = map_domains(self, |context| {
+            let cfg = &context.cfg;
+            let domain = &context.domain;
+            let components = &context.components;
+            quote! {
+                #cfg
+                impl ErrorMatching for #domain {
+                    fn error_identifier(&self) -> Option<Identifier> {
+                        match self {
+                            #( #domain::#components(error) => error.error_identifier(), )*
+                        }
+                    }
+                }
+            }
+        });
+
+        let contents = quote! {
+            #![allow(unused)]
+
+            use crate::error::IError as _;
+            use crate::error::definitions::*;
+            use crate::error::domains::*;
+            use crate::identifier::Identifier;
+            use crate::identifier::Identifying as _;
+            use crate::identifier::StructuredErrorCode;
+            use crate::kind::Kind;
+
+            /// Predicates on errors, to match them in tests without spelling
+            /// out the whole hierarchy.
+            pub trait ErrorMatching {
+                /// Identifier of the error; `None` if there is no error or its
+                /// code is unknown to this crate.
+                fn error_identifier(&self) -> Option<Identifier>;
+
+                /// Whether the error belongs to the component, e.g.
+                /// `Kind::Core(CoreCode::Sequencer)`.
+                fn is_kind(&self, kind: Kind) -> bool {
+                    self.error_identifier()
+                        .is_some_and(|identifier| identifier.kind == kind)
+                }
+
+                /// Whether the error has the code, given either as an
+                /// `Identifier` or as a code of a component, e.g.
+                /// `core::seq::ErrorCode::GenericSequencerError`.
+                fn has_code(&self, code: impl Into<Identifier>) -> bool {
+                    self.error_identifier() == Some(code.into())
+                }
+            }
+
+            impl ErrorMatching for #root_type {
+                fn error_identifier(&self) -> Option<Identifier> {
+                    Some(self.get_identifier())
+                }
+            }
+
+            #[cfg(feature = "packed_errors")]
+            impl<T> ErrorMatching for crate::packed::PackedError<T>
+            where
+                T: Clone + core::fmt::Debug,
+            {
+                fn error_identifier(&self) -> Option<Identifier> {
+                    Some(self.identifier.clone())
+                }
+            }
+
+            #[cfg(feature = "serialized_errors")]
+            impl ErrorMatching for crate::serialized::SerializedError {
+                fn error_identifier(&self) -> Option<Identifier> {
+                    Identifier::decode(StructuredErrorCode::decode(self.code))
+                }
+            }
+
+            impl<T, E: ErrorMatching> ErrorMatching for Result<T, E> {
+                fn error_identifier(&self) -> Option<Identifier> {
+                    self.as_ref().err().and_then(E::error_identifier)
+                }
+            }
+
+            #( #impl_components )*
+
+            #( #impl_domains )*
+
+            #[doc(hidden)]
+            #[track_caller]
+            pub fn assert_error(value: &impl ErrorMatching, expected: Identifier) {
+                match value.error_identifier() {
+                    Some(actual) if actual == expected => {}
+                    Some(actual) => panic!(
+                        "expected the error {}, got {}",
+                        expected.get_identifier_repr(),
+                        actual.get_identifier_repr()
+                    ),
+                    None => panic!(
+                        "expected the error {}, got no known error",
+                        expected.get_identifier_repr()
+                    ),
+                }
+            }
+
+            /// Asserts that a value, either an error or a `Result`, is the
+            /// error given by its path, e.g.
+            /// `core::seq::GenericSequencerError`.
+            #[macro_export]
+            macro_rules! #macro_name {
+                ($value:expr, $domain:ident :: $component:ident :: $error:ident $(,)?) => {
+                    $crate::matching::assert_error(
+                        &$value,
+                        $crate::$domain::$component::ErrorCode::$error.into(),
+                    )
+                };
+            }
+        };
+
+        Ok(File {
+            content: Self::format_with_preamble(contents)?,
+            relative_path: PathBuf::from("src/matching.rs"),
+        })
+    }
+}
@@ -1,9 +1,15 @@
+pub mod arbitrary;
 pub mod cargo;
+pub mod diagnostic;
 pub mod documentation;
 pub mod error;
 pub mod identifier;
 pub mod kind;
 pub mod lib;
+pub mod matching;
 pub mod packed;
+pub mod protobuf;
+pub mod revert;
 pub mod serialized;
+pub mod tests;
 pub mod untyped;
@@ -0,0 +1,421 @@
+use proc_macro2::Literal;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use zksync_error_model::inner::ComponentDescription;
+use zksync_error_model::inner::ErrorDescription;
+
+use crate::backend::File;
+use crate::backend::protobuf::schema;
+use crate::backend::protobuf::schema::ProtoMessage;
+use crate::backend::rust::RustBackend;
+use crate::backend::rust::error::GenerationError;
+use crate::backend::rust::util::codegen::ident;
+use crate::backend::rust::util::codegen::type_ident;
+
+fn parse_protobuf_expression(
+    expression: &str,
+    type_name: &str,
+) -> Result<TokenStream, GenerationError> {
+    expression
+        .parse()
+        .map_err(|_| GenerationError::InvalidProtobufBinding {
+            expression: expression.to_owned(),
+            r#type: type_name.to_owned(),
+        })
+}
+
+impl RustBackend {
+    /// `prost` enum of the codes of a component, and its conversions from and
+    /// into the discriminants of the component enum.
+    fn protobuf_enum(&self, component: &ComponentDescription) -> TokenStream {
+        let cfg = self.domain_cfg(&component.meta.domain);
+        let enum_name = ident(&schema::enum_name(&component.meta));
+        let component_code = Self::component_code_ident(&component.meta);
+        let errors = schema::errors_by_code(component);
+        let variants = errors.iter().map(|error| {
+            let variant = Self::error_ident(error);
+            let code = Literal::i32_unsuffixed(error.code as i32);
+            quote! { #variant = #code }
+        });
+        let variants_names: Vec<_> = errors
+            .iter()
+            .map(|error| Self::error_ident(error))
+            .collect();
+        let doc = format!(
+            " Codes of the errors of the component `{}` of the domain `{}`.",
+            component.meta.identifier.name, component.meta.domain.identifier.name
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+            #[repr(i32)]
+            pub enum #enum_name {
+                #( #variants, )*
+            }
+
+            #cfg
+            impl From<definitions::#component_code> for #enum_name {
+                fn from(code: definitions::#component_code) -> Self {
+                    match code {
+                        #( definitions::#component_code::#variants_names => #enum_name::#variants_names, )*
+                    }
+                }
+            }
+
+            #cfg
+            impl From<#enum_name> for definitions::#component_code {
+                fn from(code: #enum_name) -> Self {
+                    match code {
+                        #( #enum_name::#variants_names => definitions::#component_code::#variants_names, )*
+                    }
+                }
+            }
+        }
+    }
+
+    /// `prost` message of the fields of an error.
+    fn protobuf_message(message: &ProtoMessage) -> TokenStream {
+        let name = ident(&message.name);
+        let doc = format!(" Fields of the error {}.", message.error.get_identifier());
+        let fields = message.fields.iter().map(|field| {
+            let kind = ident(field.r#type.scalar);
+            let repeated = field.r#type.repeated.then(|| quote! { repeated, });
+            let tag = field.number.to_string();
+            let name = ident(&field.field.name);
+            let rust_type = type_ident(&field.r#type.rust_type);
+            quote! {
+                #[prost(#kind, #repeated tag = #tag)]
+                pub #name: #rust_type
+            }
+        });
+        quote! {
+            #[doc = #doc]
+            #[derive(Clone, PartialEq, prost::Message)]
+            pub struct #name {
+                #( #fields, )*
+            }
+        }
+    }
+
+    /// Path of the variant of the root type holding an error, e.g.
+    /// `ZksyncError::Core(domains::Core::Sequencer(definitions::Sequencer::NoFields))`,
+    /// with the pattern of its fields.
+    fn protobuf_error_pattern(&self, error: &ErrorDescription, fields: TokenStream) -> TokenStream {
+        let root_type = &self.root_type;
+        let domain = Self::domain_ident(&error.domain);
+        let component = Self::component_ident(&error.component);
+        let variant = Self::error_ident(error);
+        let fields = if error.fields.is_empty() {
+            quote! {}
+        } else {
+            fields
+        };
+        quote! {
+            #root_type::#domain(domains::#domain::#component(definitions::#component::#variant #fields))
+        }
+    }
+
+    /// Match arm converting an error into the fields of the detail message.
+    fn protobuf_to_fields(
+        &self,
+        error: &ErrorDescription,
+        message: Option<&ProtoMessage>,
+    ) -> Result<TokenStream, GenerationError> {
+        let cfg = self.domain_cfg(&error.domain);
+        let Some(message) = message else {
+            let pattern = self.protobuf_error_pattern(error, quote! { { .. } });
+            return Ok(quote! { #cfg #pattern => None, });
+        };
+        let names: Vec<_> = message
+            .fields
+            .iter()
+            .map(|field| ident(&field.field.name))
+            .collect();
+        let pattern = self.protobuf_error_pattern(error, quote! { { #( #names ),* } });
+        let values = message
+            .fields
+            .iter()
+            .map(|field| {
+                let name = ident(&field.field.name);
+                let value = match &field.to_proto {
+                    Some(expression) => {
+                        let expression =
+                            parse_protobuf_expression(expression, &field.field.r#type)?;
+                        quote! { { let value = #name; #expression } }
+                    }
+                    None => quote! { to_proto(#name) },
+                };
+                Ok(quote! { #name: #value })
+            })
+            .collect::<Result<Vec<_>, GenerationError>>()?;
+        let message_name = ident(&message.name);
+        Ok(quote! {
+            #cfg
+            #pattern => Some(error_detail::Fields::#message_name(#message_name { #( #values ),* })),
+        })
+    }
+
+    /// Match arm mapping an error to its encoded code, as in the model.
+    fn protobuf_code(&self, error: &ErrorDescription) -> TokenStream {
+        let cfg = self.domain_cfg(&error.domain);
+        let pattern = self.protobuf_error_pattern(error, quote! { { .. } });
+        let code = error.encoded_code();
+        quote! { #cfg #pattern => #code, }
+    }
+
+    /// Match arm converting the fields of the detail message into an error
+    /// and its encoded code.
+    fn protobuf_from_fields(&self, message: &ProtoMessage) -> Result<TokenStream, GenerationError> {
+        let error = message.error;
+        let cfg = self.domain_cfg(&error.domain);
+        let component = Self::component_ident(&error.component);
+        let variant = Self::error_ident(error);
+        let message_name = ident(&message.name);
+        let error_name = &error.name;
+        let code = error.encoded_code();
+        if message.fields.is_empty() {
+            return Ok(quote! {
+                #cfg
+                error_detail::Fields::#message_name(_) => (definitions::#component::#variant.into(), #code),
+            });
+        }
+        let values = message
+            .fields
+            .iter()
+            .map(|field| {
+                let name = ident(&field.field.name);
+                let field_name = &field.field.name;
+                let value = match &field.from_proto {
+                    Some(expression) => {
+                        let expression = parse_protobuf_expression(expression, &field.field.r#type)?;
+                        quote! { check({ let value = fields.#name; #expression }, #error_name, #field_name)? }
+                    }
+                    None => quote! { convert(fields.#name, #error_name, #field_name)? },
+                };
+                Ok(quote! { #name: #value })
+            })
+            .collect::<Result<Vec<_>, GenerationError>>()?;
+        Ok(quote! {
+            #cfg
+            error_detail::Fields::#message_name(fields) => (definitions::#component::#variant { #( #values ),* }.into(), #code),
+        })
+    }
+
+    pub fn generate_file_protobuf(&mut self) -> Result<File, GenerationError> {
+        let root_type = &self.root_type;
+
+        let mut enums = vec![];
+        let mut messages = vec![];
+        let mut to_fields = vec![];
+        let mut codes = vec![];
+        for component in self.model.components() {
+            enums.push(self.protobuf_enum(component));
+            for (error, message) in component
+                .errors
+                .iter()
+                .zip(schema::proto_messages(&self.model, component)?)
+            {
+                to_fields.push(self.protobuf_to_fields(error, message.as_ref().ok())?);
+                codes.push(self.protobuf_code(error));
+                messages.extend(message.ok());
+            }
+        }
+
+        let message_types = messages.iter().map(Self::protobuf_message);
+        let from_fields = messages
+            .iter()
+            .map(|message| self.protobuf_from_fields(message))
+            .collect::<Result<Vec<_>, _>>()?;
+        let detail_name = ident(schema::DETAIL_MESSAGE);
+
+        let (fields_field, fields_module, fields_value, from_detail) = if messages.is_empty() {
+            (
+                quote! {},
+                quote! {},
+                quote! {},
+                quote! { Err(ProtobufConversionError::MissingFields(detail.code)) },
+            )
+        } else {
+            let tags = messages
+                .iter()
+                .map(|message| message.oneof_field_number().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let variants = messages.iter().map(|message| {
+                let name = ident(&message.name);
+                let tag = message.oneof_field_number().to_string();
+                quote! {
+                    #[prost(message, tag = #tag)]
+                    #name(super::#name)
+                }
+            });
+            (
+                quote! {
+                    /// Fields of the error, if it has a protobuf message.
+                    #[prost(oneof = "error_detail::Fields", tags = #tags)]
+                    pub fields: Option<error_detail::Fields>,
+                },
+                quote! {
+                    pub mod error_detail {
+                        #[derive(Clone, PartialEq, prost::Oneof)]
+                        pub enum Fields {
+                            #( #variants, )*
+                        }
+                    }
+                },
+                quote! {
+                    fields: match error {
+                        #( #to_fields )*
+                    },
+                },
+                quote! {
+                    let Some(fields) = detail.fields else {
+                        return Err(ProtobufConversionError::MissingFields(detail.code));
+                    };
+                    let (error, code): (#root_type, u32) = match fields {
+                        #( #from_fields )*
+                        #[allow(unreachable_patterns)]
+                        _ => return Err(ProtobufConversionError::DisabledDomain(detail.code)),
+                    };
+                    if code != detail.code {
+                        return Err(ProtobufConversionError::MismatchedCode(detail.code));
+                    }
+                    Ok(error)
+                },
+            )
+        };
+
+        let imports: BTreeSet<_> = self
+            .used_type_bindings()?
+            .into_iter()
+            .flat_map(|binding| binding.imports.iter())
+            .collect();
+        let imports = imports.into_iter().map(|path| type_ident(path));
+
+        let contents = quote! {
+            #![allow(unused)]
+
+            #[cfg(not(feature = "std"))]
+            use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
+            use core::fmt;
+
+            use crate::error::IError as _;
+            use crate::error::definitions;
+            use crate::error::domains;
+            use crate::error::domains::#root_type;
+            use crate::identifier::Identifying as _;
+            #( use #imports; )*
+
+            #( #enums )*
+
+            #( #message_types )*
+
+            /// An error, e.g. in the details of a gRPC status.
+            #[derive(Clone, PartialEq, prost::Message)]
+            pub struct #detail_name {
+                /// Encoded code of the error, see `StructuredErrorCode`.
+                #[prost(uint32, tag = "1")]
+                pub code: u32,
+                /// Message of the error, with the values of its fields.
+                #[prost(string, tag = "2")]
+                pub message: String,
+                #fields_field
+            }
+
+            #fields_module
+
+            #[derive(Clone, Debug, Eq, PartialEq)]
+            pub enum ProtobufConversionError {
+                /// The detail carries no fields, e.g. because the error has no
+                /// protobuf message.
+                MissingFields(u32),
+                /// The fields belong to an error of a domain disabled by cargo
+                /// features.
+                DisabledDomain(u32),
+                /// The code does not match the fields.
+                MismatchedCode(u32),
+                /// A field does not convert into the Rust type of the field.
+                InvalidField {
+                    error: &'static str,
+                    field: &'static str,
+                },
+            }
+
+            impl fmt::Display for ProtobufConversionError {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    match self {
+                        ProtobufConversionError::MissingFields(code) => {
+                            write!(f, "The error detail with the code {code} carries no fields")
+                        }
+                        ProtobufConversionError::DisabledDomain(code) => {
+                            write!(f, "The error with the code {code} belongs to a disabled domain")
+                        }
+                        ProtobufConversionError::MismatchedCode(code) => {
+                            write!(f, "The code {code} does not match the fields of the error detail")
+                        }
+                        ProtobufConversionError::InvalidField { error, field } => {
+                            write!(f, "The field `{field}` of the error `{error}` is invalid")
+                        }
+                    }
+                }
+            }
+
+            impl core::error::Error for ProtobufConversionError {}
+
+            fn to_proto<T: Clone + Into<U>, U>(value: &T) -> U {
+                value.clone().into()
+            }
+
+            fn convert<T, U: TryFrom<T>>(
+                value: T,
+                error: &'static str,
+                field: &'static str,
+            ) -> Result<U, ProtobufConversionError> {
+                U::try_from(value).map_err(|_| ProtobufConversionError::InvalidField { error, field })
+            }
+
+            fn check<T, E>(
+                value: Result<T, E>,
+                error: &'static str,
+                field: &'static str,
+            ) -> Result<T, ProtobufConversionError> {
+                value.map_err(|_| ProtobufConversionError::InvalidField { error, field })
+            }
+
+            impl From<&#root_type> for #detail_name {
+                fn from(error: &#root_type) -> Self {
+                    #detail_name {
+                        code: match error {
+                            #( #codes )*
+                        },
+                        message: error.get_message(),
+                        #fields_value
+                    }
+                }
+            }
+
+            impl From<#root_type> for #detail_name {
+                fn from(error: #root_type) -> Self {
+                    Self::from(&error)
+                }
+            }
+
+            impl TryFrom<#detail_name> for #root_type {
+                type Error = ProtobufConversionError;
+
+                fn try_from(detail: #detail_name) -> Result<Self, Self::Error> {
+                    #from_detail
+                }
+            }
+        };
+
+        Ok(File {
+            content: Self::format_with_preamble(contents)?,
+            relative_path: PathBuf::from("src/protobuf.rs"),
+        })
+    }
+}
@@ -0,0 +1,238 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use zksync_error_model::inner::ComponentDescription;
+
+use crate::backend::File;
+use crate::backend::rust::RustBackend;
+use crate::backend::rust::error::GenerationError;
+use crate::backend::rust::util::codegen::ident;
+use crate::backend::rust::util::codegen::type_ident;
+use crate::backend::solidity::abi::AbiError;
+use crate::backend::solidity::abi::abi_errors;
+use crate::backend::solidity::abi::interface_name;
+
+fn parse_abi_expression(expression: &str, type_name: &str) -> Result<TokenStream, GenerationError> {
+    expression
+        .parse()
+        .map_err(|_| GenerationError::InvalidAbiBinding {
+            expression: expression.to_owned(),
+            r#type: type_name.to_owned(),
+        })
+}
+
+impl RustBackend {
+    /// Solidity declaration of an error inside `sol!`.
+    fn sol_error_declaration(error: &AbiError) -> Result<TokenStream, GenerationError> {
+        let name = ident(&error.name);
+        let parameters = error
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let r#type = parse_abi_expression(&parameter.r#type, &parameter.field.r#type)?;
+                let name = ident(&parameter.field.name);
+                Ok(quote! { #r#type #name })
+            })
+            .collect::<Result<Vec<_>, GenerationError>>()?;
+        Ok(quote! { error #name( #( #parameters ),* ); })
+    }
+
+    /// Match arm converting a decoded Solidity error into the Rust variant.
+    fn sol_error_conversion(
+        component: &TokenStream,
+        error: &AbiError,
+    ) -> Result<TokenStream, GenerationError> {
+        let sol_name = ident(&error.name);
+        let variant = Self::error_ident(error.error);
+        let error_name = &error.error.name;
+        let fields = error
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let name = ident(&parameter.field.name);
+                let field_name = &parameter.field.name;
+                let value = match &parameter.from_abi {
+                    Some(expression) => {
+                        let expression = parse_abi_expression(expression, &parameter.field.r#type)?;
+                        quote! { { let value = error.#name; #expression } }
+                    }
+                    None => quote! { convert(error.#name, #error_name, #field_name)? },
+                };
+                Ok(quote! { #name: #value })
+            })
+            .collect::<Result<Vec<_>, GenerationError>>()?;
+        Ok(if fields.is_empty() {
+            quote! { Decoded::#sol_name(_) => #component::#variant }
+        } else {
+            quote! { Decoded::#sol_name(error) => #component::#variant { #( #fields ),* } }
+        })
+    }
+
+    /// Declarations of the Solidity errors of a component, and the
+    /// implementation of `FromRevert` for it, if any of its errors can be
+    /// declared in Solidity.
+    fn component_revert_decoding(
+        &self,
+        component: &ComponentDescription,
+    ) -> Result<Option<(TokenStream, TokenStream)>, GenerationError> {
+        let errors: Vec<_> = abi_errors(&self.model, component)
+            .into_iter()
+            .flatten()
+            .collect();
+        if errors.is_empty() {
+            return Ok(None);
+        }
+        let cfg = self.domain_cfg(&component.meta.domain);
+        let component_name = Self::component_ident(&component.meta);
+        let interface = ident(&interface_name(&component.meta));
+        let decoded = ident(&format!("{}Errors", interface_name(&component.meta)));
+        let declarations = errors
+            .iter()
+            .map(Self::sol_error_declaration)
+            .collect::<Result<Vec<_>, _>>()?;
+        let conversions = errors
+            .iter()
+            .map(|error| Self::sol_error_conversion(&component_name, error))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let sol = quote! {
+            #cfg
+            alloy_sol_types::sol! {
+                interface #interface {
+                    #( #declarations )*
+                }
+            }
+        };
+        let from_revert = quote! {
+            #cfg
+            impl FromRevert for #component_name {
+                fn from_revert(data: &[u8]) -> Result<Self, RevertDecodingError> {
+                    use abi::#interface::#decoded as Decoded;
+                    let decoded = Decoded::abi_decode(data)
+                        .map_err(|error| RevertDecodingError::InvalidData(error.to_string()))?;
+                    Ok(match decoded {
+                        #( #conversions, )*
+                    })
+                }
+            }
+        };
+        Ok(Some((sol, from_revert)))
+    }
+
+    pub fn generate_file_revert(&mut self) -> Result<File, GenerationError> {
+        let root_type = &self.root_type;
+        let mut sol_declarations = vec![];
+        let mut implementations = vec![];
+        let mut decode_arms = vec![];
+        for component in self.model.components() {
+            let cfg = self.domain_cfg(&component.meta.domain);
+            let domain = Self::domain_ident(&component.meta.domain);
+            let domain_code = Self::domain_code_ident(&component.meta.domain);
+            let component_name = Self::component_ident(&component.meta);
+            let kind = quote! { Kind::#domain(#domain_code::#component_name) };
+            match self.component_revert_decoding(component)? {
+                Some((sol, from_revert)) => {
+                    sol_declarations.push(sol);
+                    implementations.push(from_revert);
+                    decode_arms.push(quote! {
+                        #cfg
+                        #kind => #component_name::from_revert(data).map(Into::into),
+                    });
+                }
+                None => decode_arms.push(quote! {
+                    #cfg
+                    #kind => Err(RevertDecodingError::UnsupportedComponent(kind)),
+                }),
+            }
+        }
+
+        let imports: BTreeSet<_> = self
+            .used_type_bindings()?
+            .into_iter()
+            .flat_map(|binding| binding.imports.iter())
+            .collect();
+        let imports = imports.into_iter().map(|path| type_ident(path));
+
+        let contents = quote! {
+            #![allow(unused)]
+
+            #[cfg(not(feature = "std"))]
+            use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
+            use alloy_sol_types::SolInterface as _;
+            use core::fmt;
+
+            use crate::error::definitions::*;
+            use crate::error::domains::*;
+            use crate::kind::Kind;
+            #( use #imports; )*
+
+            /// Solidity declarations of the errors, one interface per component.
+            mod abi {
+                #( #sol_declarations )*
+            }
+
+            #[derive(Clone, Debug, Eq, PartialEq)]
+            pub enum RevertDecodingError {
+                /// No error of the component is declared in Solidity.
+                UnsupportedComponent(Kind),
+                /// The selector matches no error of the component, or the
+                /// ABI-encoded arguments are malformed.
+                InvalidData(String),
+                /// A decoded value does not fit the Rust type of the field.
+                InvalidField {
+                    error: &'static str,
+                    field: &'static str,
+                },
+            }
+
+            impl fmt::Display for RevertDecodingError {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    match self {
+                        RevertDecodingError::UnsupportedComponent(kind) => {
+                            write!(f, "No error of the component {kind:?} is declared in Solidity")
+                        }
+                        RevertDecodingError::InvalidData(message) => {
+                            write!(f, "Invalid revert data: {message}")
+                        }
+                        RevertDecodingError::InvalidField { error, field } => {
+                            write!(f, "The field `{field}` of the error `{error}` is out of range")
+                        }
+                    }
+                }
+            }
+
+            impl core::error::Error for RevertDecodingError {}
+
+            /// Errors decoded from the revert data of a contract call: a 4-byte
+            /// selector followed by the ABI-encoded fields.
+            pub trait FromRevert: Sized {
+                fn from_revert(data: &[u8]) -> Result<Self, RevertDecodingError>;
+            }
+
+            fn convert<T, U: TryFrom<T>>(
+                value: T,
+                error: &'static str,
+                field: &'static str,
+            ) -> Result<U, RevertDecodingError> {
+                U::try_from(value).map_err(|_| RevertDecodingError::InvalidField { error, field })
+            }
+
+            #( #implementations )*
+
+            /// Decodes the revert data of a contract call into an error of the
+            /// component `kind`.
+            pub fn decode(kind: Kind, data: &[u8]) -> Result<#root_type, RevertDecodingError> {
+                match kind {
+                    #( #decode_arms )*
+                }
+            }
+        };
+
+        Ok(File {
+            content: Self::format_with_preamble(contents)?,
+            relative_path: PathBuf::from("src/revert.rs"),
+        })
+    }
+}
@@ -0,0 +1,222 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use zksync_error_model::inner::ComponentMetadata;
+use zksync_error_model::inner::ErrorDescription;
+
+use crate::backend::File;
+use crate::backend::rust::RustBackend;
+use crate::backend::rust::error::GenerationError;
+use crate::backend::rust::util::codegen::ident;
+use crate::backend::rust::util::codegen::type_ident;
+
+impl RustBackend {
+    /// Path to a component enum through the public modules of the crate, e.g.
+    /// `zksync_error::core::seq::SequencerError`.
+    fn public_component_path(&self, component: &ComponentMetadata) -> TokenStream {
+        let crate_path = self.crate_path();
+        let outer_module = ident(&component.domain.identifier.encoding);
+        let inner_module = ident(&component.identifier.encoding);
+        let alias_error = Self::component_error_alias_ident(component);
+        quote! { #crate_path::#outer_module::#inner_module::#alias_error }
+    }
+
+    /// Sample value of a component enum, namely its generic error.
+    fn sample_component_value(&self, component: &ComponentMetadata) -> TokenStream {
+        let component_path = self.public_component_path(component);
+        quote! { #component_path::GenericError { message: "sample".into() } }
+    }
+
+    /// Sample value of a field: errors of the model are represented by a
+    /// generic error, types implementing `Default` by their default value,
+    /// other types by a value of their property-testing strategy. The flag
+    /// tells whether the value comes from a strategy, which is only available
+    /// with the feature `proptest`.
+    fn sample_field_value(&self, type_name: &str) -> Result<(TokenStream, bool), GenerationError> {
+        if let Some(domain) = self.model.domains.get(type_name) {
+            let component = &domain
+                .components
+                .values()
+                .next()
+                .expect("Domains without components are rejected by the validator")
+                .meta;
+            let crate_path = self.crate_path();
+            let outer_module = ident(&domain.meta.identifier.encoding);
+            let domain_error = ident(&format!("{}Error", Self::domain_ident(&domain.meta)));
+            let variant = Self::component_ident(component);
+            let value = self.sample_component_value(component);
+            return Ok((
+                quote! { #crate_path::#outer_module::#domain_error::#variant(#value).into() },
+                false,
+            ));
+        }
+        if let Some(component) = self
+            .model
+            .components()
+            .find(|component| component.meta.identifier.name == type_name)
+        {
+            let value = self.sample_component_value(&component.meta);
+            return Ok((quote! { #value.into() }, false));
+        }
+        if self.type_implements(type_name, "Default")? {
+            return Ok((quote! { Default::default() }, false));
+        }
+        match self.binding_strategy(type_name)? {
+            Some(strategy) => Ok((
+                quote! {
+                    {
+                        use proptest::prelude::*;
+                        use proptest::strategy::ValueTree as _;
+                        let mut runner = proptest::test_runner::TestRunner::deterministic();
+                        (#strategy)
+                            .new_tree(&mut runner)
+                            .expect("The strategy should produce a value")
+                            .current()
+                    }
+                },
+                true,
+            )),
+            None => Err(GenerationError::MissingSampleValue {
+                r#type: type_name.to_owned(),
+            }),
+        }
+    }
+
+    fn error_test(&self, error: &ErrorDescription) -> Result<TokenStream, GenerationError> {
+        let cfg = self.domain_cfg(&error.domain);
+        let root_type = &self.root_type;
+        let crate_path = self.crate_path();
+        let test_name = ident(&format!(
+            "{}_{}_{}",
+            error.domain.identifier.encoding, error.component.identifier.encoding, error.code
+        ));
+        let component_path = self.public_component_path(&error.component);
+        let error_name = Self::error_ident(error);
+        let mut uses_strategies = false;
+        let value = if error.fields.is_empty() {
+            quote! { #component_path::#error_name }
+        } else {
+            let mut fields = vec![];
+            for field in &error.fields {
+                let name = ident(&field.name);
+                let (value, from_strategy) = self.sample_field_value(&field.r#type)?;
+                uses_strategies |= from_strategy;
+                fields.push(quote! { #name: #value });
+            }
+            quote! { #component_path::#error_name { #( #fields ),* } }
+        };
+        let proptest_cfg = if uses_strategies {
+            quote! { #[cfg(feature = "proptest")] }
+        } else {
+            quote! {}
+        };
+        let repr = format!(
+            "[{}-{}-{}]",
+            error.domain.identifier.encoding, error.component.identifier.encoding, error.code
+        );
+        let has_documentation = error.documentation.is_some();
+
+        Ok(quote! {
+            #cfg
+            #proptest_cfg
+            #[test]
+            fn #test_name() {
+                let error: #crate_path::#root_type = #value.into();
+                check(error, #repr, #has_documentation);
+            }
+        })
+    }
+
+    pub fn generate_file_tests(&mut self) -> Result<Option<File>, GenerationError> {
+        if !self.config.generate_tests {
+            return Ok(None);
+        }
+        let root_type = &self.root_type;
+        let crate_path = self.crate_path();
+        let tests = self
+            .model
+            .errors()
+            .map(|error| self.error_test(error))
+            .collect::<Result<Vec<_>, _>>()?;
+        let imports: BTreeSet<_> = self
+            .used_type_bindings()?
+            .into_iter()
+            .flat_map(|binding| binding.imports.iter())
+            .collect();
+        let imports = imports.into_iter().map(|path| type_ident(path));
+
+        let contents = quote! {
+            #![allow(unused)]
+
+            use std::str::FromStr as _;
+
+            use #crate_path::#root_type;
+            use #crate_path::IError as _;
+            use #crate_path::Identifier;
+            use #crate_path::Identifying as _;
+            use #crate_path::StructuredErrorCode;
+            #( use #imports; )*
+
+            /// Checks the properties shared by all errors of the crate.
+            fn check(error: #root_type, repr: &str, has_documentation: bool) {
+                let identifier = error.get_identifier();
+                assert_eq!(identifier.get_identifier_repr(), repr);
+                assert_eq!(Identifier::from_str(repr), Ok(identifier.clone()));
+
+                let encoded = identifier.encode();
+                let structured = StructuredErrorCode::decode(encoded);
+                assert_eq!(structured.encode(), encoded);
+                assert_eq!(Identifier::decode(structured), Some(identifier.clone()));
+
+                assert!(
+                    error.get_message().contains(repr),
+                    "The message of {repr} does not contain its identifier: {}",
+                    error.get_message()
+                );
+
+                #[cfg(feature = "use_serde")]
+                {
+                    let json = serde_json::to_value(&error).expect("Serialization error");
+                    let restored: #root_type =
+                        serde_json::from_value(json).expect("Deserialization error");
+                    assert_eq!(restored, error);
+                }
+
+                #[cfg(all(feature = "packed_errors", feature = "serialized_errors"))]
+                {
+                    let packed = #crate_path::packed::pack_unified(error.clone())
+                        .expect("Serialization error");
+                    let serialized =
+                        #crate_path::serialized::serialize(packed).expect("Serialization error");
+                    assert_eq!(serialized.code, encoded);
+                    assert_eq!(serialized.get_identifier(), identifier);
+                    let json = serde_json::to_string(&serialized).expect("Serialization error");
+                    let restored: #crate_path::serialized::SerializedError =
+                        serde_json::from_str(&json).expect("Deserialization error");
+                    assert_eq!(restored, serialized);
+                    let unpacked: #root_type = #crate_path::serialized::unpack_typed(&restored)
+                        .expect("Deserialization error");
+                    assert_eq!(unpacked, error);
+                }
+
+                #[cfg(feature = "runtime_documentation")]
+                {
+                    use #crate_path::documentation::Documented as _;
+                    let documentation = error
+                        .get_documentation()
+                        .expect("The documentation of the error should resolve");
+                    assert_eq!(documentation.is_some(), has_documentation);
+                }
+            }
+
+            #( #tests )*
+        };
+
+        Ok(Some(File {
+            content: Self::format_with_preamble(contents)?,
+            relative_path: PathBuf::from("tests/generated.rs"),
+        }))
+    }
+}
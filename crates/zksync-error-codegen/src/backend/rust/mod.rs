@@ -1,3 +1,4 @@
+mod annotations;
 pub mod config;
 pub mod error;
 pub mod files;
@@ -6,6 +7,7 @@ pub mod util;
 pub use config::Config as RustBackendConfig;
 use error::GenerationError;
 use proc_macro2::TokenStream;
+use quote::quote;
 use util::codegen::ident;
 use util::codegen::sanitize;
 use util::codegen::type_ident;
@@ -15,6 +17,7 @@ use zksync_error_model::unpacked::UnpackedModel;
 
 use zksync_error_model::error::ModelValidationError;
 use zksync_error_model::inner::ErrorDescription;
+use zksync_error_model::inner::FullyQualifiedTargetLanguageType;
 use zksync_error_model::inner::Model;
 
 use super::Backend;
@@ -23,8 +26,10 @@ use super::File;
 pub struct RustBackend {
     config: RustBackendConfig,
     model: Model,
+    root_type: TokenStream,
     all_domains: Vec<TokenStream>,
     all_domain_codes: Vec<TokenStream>,
+    all_domain_cfgs: Vec<TokenStream>,
 }
 
 impl Backend for RustBackend {
@@ -39,23 +44,33 @@ impl Backend for RustBackend {
     }
 
     fn generate(&mut self) -> Result<Vec<File>, Self::GenerationError> {
+        self.validate_annotations()?;
         Ok([
+            Some(self.generate_file_arbitrary()?),
             Some(self.generate_file_error_definitions()?),
             Some(self.generate_file_error_domains()?),
+            Some(self.generate_file_diagnostic()?),
             Some(self.generate_file_documentation()?),
             Some(self.generate_file_error_mod()?),
             Some(self.generate_file_identifier()?),
             Some(self.generate_file_kind()?),
             Some(self.generate_file_lib()?),
+            Some(self.generate_file_matching()?),
             Some(self.generate_file_packed()?),
+            Some(self.generate_file_protobuf()?),
+            Some(self.generate_file_revert()?),
             Some(self.generate_file_serialized()?),
             Some(self.generate_file_untyped()?),
             self.generate_file_cargo()?,
+            self.generate_file_tests()?,
             Some(File {
                 relative_path: "resources/error-model-dump.json".into(),
                 content: {
-                    let unpacked: UnpackedModel =
+                    let mut unpacked: UnpackedModel =
                         zksync_error_model::unpacked::flatten(&self.model);
+                    if self.config.omit_internal_from_dump {
+                        unpacked = unpacked.without_internal();
+                    }
                     let user_facing_model: zksync_error_description::ErrorHierarchy =
                         unpacked.into();
                     serde_json::to_string_pretty(&user_facing_model.wrap())?
@@ -80,12 +95,22 @@ impl Backend for RustBackend {
             .map(|domain| Self::domain_code_ident(&domain.meta))
             .collect();
 
-        Self {
+        let root_type = type_ident(&config.root_type_name);
+
+        let mut backend = Self {
             config,
             model: model.clone(),
+            root_type,
             all_domains,
             all_domain_codes,
-        }
+            all_domain_cfgs: vec![],
+        };
+        backend.all_domain_cfgs = model
+            .domains
+            .values()
+            .map(|domain| backend.domain_cfg(&domain.meta))
+            .collect();
+        backend
     }
 }
 
@@ -103,11 +128,52 @@ impl RustBackend {
         ))
     }
 
+    /// Name of the cargo feature that enables a domain, if domains are gated
+    /// by cargo features.
+    fn domain_feature(&self, domain: &DomainMetadata) -> Option<String> {
+        self.config
+            .domain_features
+            .then(|| format!("domain_{}", sanitize(&domain.identifier.encoding)))
+    }
+
+    fn domain_cfg(&self, domain: &DomainMetadata) -> TokenStream {
+        match self.domain_feature(domain) {
+            Some(feature) => quote! { #[cfg(feature = #feature)] },
+            None => quote! {},
+        }
+    }
+
+    fn crate_path(&self) -> TokenStream {
+        type_ident(&self.config.crate_path())
+    }
+
     fn get_rust_type(&self, name: &str) -> Result<String, GenerationError> {
         let typ = self.model.get_type(Self::get_language_name(), name)?;
         Ok(typ.expression.clone())
     }
 
+    /// Bindings of the types of all error fields and of the sources of
+    /// conversions from foreign types.
+    fn used_type_bindings(
+        &self,
+    ) -> Result<Vec<&FullyQualifiedTargetLanguageType>, GenerationError> {
+        let mut result = vec![];
+        for component in self.model.components() {
+            for error in &component.errors {
+                for field in &error.fields {
+                    result.push(
+                        self.model
+                            .get_type(Self::get_language_name(), &field.r#type)?,
+                    );
+                }
+            }
+            for conversion in &component.conversions {
+                result.extend(conversion.bindings.get(Self::get_language_name()));
+            }
+        }
+        Ok(result)
+    }
+
     fn component_type_name(component: &ComponentMetadata) -> Result<String, GenerationError> {
         let name = component.bindings.get(Self::get_language_name()).ok_or(
             ModelValidationError::UnmappedName(component.identifier.name.clone()),
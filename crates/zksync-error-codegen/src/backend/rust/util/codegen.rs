@@ -1,16 +1,26 @@
 use crate::backend::rust::RustBackend;
 use proc_macro2::TokenStream;
 use quote::quote;
-use zksync_error_model::inner::Model;
 
 pub struct DomainContext {
+    /// `#[cfg(..)]` attribute gating the domain, if domains are gated by
+    /// cargo features.
+    pub cfg: TokenStream,
+    /// `#[derive(..)]` with the extra derives of the domain enum, if any.
+    pub derives: TokenStream,
     pub domain: TokenStream,
     pub domain_code: TokenStream,
     pub components: Vec<TokenStream>,
     pub component_codes: Vec<TokenStream>,
+    /// Codes of the components in the model, used as the discriminants of
+    /// the domain enum.
+    pub component_values: Vec<u32>,
 }
 
 pub struct ComponentContext {
+    /// `#[cfg(..)]` attribute gating the parent domain, if domains are gated
+    /// by cargo features.
+    pub cfg: TokenStream,
     pub domain: TokenStream,
     pub domain_code: TokenStream,
     pub component: TokenStream,
@@ -47,14 +57,16 @@ pub fn sanitize(s: &str) -> String {
 }
 
 pub fn map_domains<'a, U>(
-    model: &'a Model,
+    backend: &'a RustBackend,
     mapper: impl Fn(&DomainContext) -> U,
 ) -> std::iter::Map<
     impl Iterator<Item = &'a zksync_error_model::inner::DomainDescription>,
     impl FnMut(&'a zksync_error_model::inner::DomainDescription) -> U,
 > {
-    model.domains.values().map(move |domain| {
+    backend.model.domains.values().map(move |domain| {
         let domain_context = DomainContext {
+            cfg: backend.domain_cfg(&domain.meta),
+            derives: backend.domain_annotation_tokens(domain),
             domain: RustBackend::domain_ident(&domain.meta),
             domain_code: RustBackend::domain_code_ident(&domain.meta),
             components: domain
@@ -67,20 +79,26 @@ pub fn map_domains<'a, U>(
                 .values()
                 .map(|component| RustBackend::component_code_ident(&component.meta))
                 .collect(),
+            component_values: domain
+                .components
+                .values()
+                .map(|component| component.meta.identifier.code)
+                .collect(),
         };
         mapper(&domain_context)
     })
 }
 
 pub fn map_components<'a, U>(
-    model: &'a Model,
+    backend: &'a RustBackend,
     mapper: impl Fn(&ComponentContext) -> U,
 ) -> std::iter::Map<
     impl Iterator<Item = &'a zksync_error_model::inner::ComponentDescription>,
     impl FnMut(&'a zksync_error_model::inner::ComponentDescription) -> U,
 > {
-    model.components().map(move |component| {
+    backend.model.components().map(move |component| {
         let component_context = ComponentContext {
+            cfg: backend.domain_cfg(&component.meta.domain),
             domain: RustBackend::domain_ident(&component.meta.domain),
             domain_code: RustBackend::domain_code_ident(&component.meta.domain),
             component: RustBackend::component_ident(&component.meta),
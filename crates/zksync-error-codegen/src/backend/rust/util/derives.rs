@@ -0,0 +1,77 @@
+//!
+//! Knowledge about the traits implemented by common Rust types, used to check
+//! that extra derives of the generated types are applicable.
+//!
+
+/// Derives of every component enum; extra derives need not repeat them.
+pub const BASE_DERIVES: &[&str] = &[
+    "AsRefStr",
+    "Clone",
+    "Debug",
+    "EnumDiscriminants",
+    "Eq",
+    "PartialEq",
+];
+
+const PRIMITIVE_TYPES: &[&str] = &[
+    "bool", "char", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128",
+    "usize",
+];
+
+/// Name of a derived trait without its path, e.g. `JsonSchema` for
+/// `schemars::JsonSchema`.
+pub fn trait_name(derive: &str) -> &str {
+    derive.rsplit("::").next().unwrap_or(derive).trim()
+}
+
+/// Whether a derive or a trait listed in `implements` refers to the trait.
+pub fn same_trait(first: &str, second: &str) -> bool {
+    trait_name(first) == trait_name(second)
+}
+
+/// Whether a type expression made of standard types, e.g. `Vec<String>`, is
+/// known to implement the trait.
+pub fn builtin_implements(expression: &str, derive: &str) -> bool {
+    let expression = expression.trim();
+    let derive = trait_name(derive);
+    if let Some((outer, inner)) = expression
+        .strip_suffix('>')
+        .and_then(|expression| expression.split_once('<'))
+    {
+        return match (trait_name(outer), derive) {
+            ("Option", _) | ("Box" | "Vec", "Hash" | "PartialOrd" | "Ord") => {
+                builtin_implements(inner, derive)
+            }
+            ("Vec", "Default") => true,
+            ("Box", "Default") => builtin_implements(inner, derive),
+            _ => false,
+        };
+    }
+    let typ = trait_name(expression);
+    if PRIMITIVE_TYPES.contains(&typ) {
+        matches!(derive, "Copy" | "Default" | "Hash" | "Ord" | "PartialOrd")
+    } else if typ == "String" {
+        matches!(derive, "Default" | "Hash" | "Ord" | "PartialOrd")
+    } else if matches!(typ, "f32" | "f64") {
+        matches!(derive, "Copy" | "Default" | "PartialOrd")
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::builtin_implements;
+
+    #[test]
+    fn builtin_types() {
+        assert!(builtin_implements("String", "Hash"));
+        assert!(builtin_implements("u64", "std::hash::Hash"));
+        assert!(builtin_implements("Option<u32>", "Copy"));
+        assert!(builtin_implements("Vec<std::string::String>", "Ord"));
+        assert!(!builtin_implements("Vec<u8>", "Copy"));
+        assert!(!builtin_implements("f64", "Hash"));
+        assert!(!builtin_implements("serde_json::Value", "Hash"));
+        assert!(!builtin_implements("String", "schemars::JsonSchema"));
+    }
+}
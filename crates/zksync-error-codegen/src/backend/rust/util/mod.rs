@@ -1,4 +1,7 @@
+use heck::ToSnakeCase as _;
+
 pub mod codegen;
+pub mod derives;
 
 fn replace_non_alphanumeric(input: &str, replacement: char) -> String {
     input
@@ -12,3 +15,22 @@ fn replace_non_alphanumeric(input: &str, replacement: char) -> String {
         })
         .collect()
 }
+
+/// Converts a type name to snake case, treating acronyms as words, e.g.
+/// `ZksyncError` to `zksync_error` and `APIError` to `api_error`.
+pub fn snake_case(name: &str) -> String {
+    name.to_snake_case()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::snake_case;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("ZksyncError"), "zksync_error");
+        assert_eq!(snake_case("AcmeError2"), "acme_error2");
+        assert_eq!(snake_case("APIError"), "api_error");
+        assert_eq!(snake_case("already_snake"), "already_snake");
+    }
+}
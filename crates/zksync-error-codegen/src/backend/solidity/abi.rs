@@ -0,0 +1,99 @@
+//!
+//! Solidity signatures of errors, shared by the Solidity backend and by the
+//! revert data decoder of the Rust backend.
+//!
+
+use zksync_error_model::inner::ComponentDescription;
+use zksync_error_model::inner::ComponentMetadata;
+use zksync_error_model::inner::ErrorDescription;
+use zksync_error_model::inner::FieldDescription;
+use zksync_error_model::inner::Model;
+
+pub const SOLIDITY_LANGUAGE_NAME: &str = "solidity";
+
+pub struct AbiParameter<'a> {
+    pub field: &'a FieldDescription,
+    /// Solidity type of the parameter, e.g. `uint256`.
+    pub r#type: String,
+    /// Rust expression converting the decoded `value` into the type of the
+    /// field; `TryFrom` is used by default.
+    pub from_abi: Option<String>,
+}
+
+pub struct AbiError<'a> {
+    pub error: &'a ErrorDescription,
+    /// Name of the Solidity error, which may differ from the Rust variant.
+    pub name: String,
+    pub parameters: Vec<AbiParameter<'a>>,
+}
+
+impl AbiError<'_> {
+    /// Signature whose hash gives the selector, e.g. `Halted(string,uint32)`.
+    pub fn signature(&self) -> String {
+        let types: Vec<_> = self
+            .parameters
+            .iter()
+            .map(|parameter| parameter.r#type.as_str())
+            .collect();
+        format!("{}({})", self.name, types.join(","))
+    }
+}
+
+/// An error that can not be declared in Solidity, because the type of one
+/// of its fields has no Solidity binding.
+pub struct UnsupportedError<'a> {
+    pub error: &'a ErrorDescription,
+    pub field: &'a FieldDescription,
+}
+
+/// Name of the Solidity interface declaring the errors of a component, e.g.
+/// `ISequencerErrors`.
+pub fn interface_name(component: &ComponentMetadata) -> String {
+    let name = component
+        .bindings
+        .get(SOLIDITY_LANGUAGE_NAME)
+        .unwrap_or(&component.identifier.name);
+    format!("I{name}Errors")
+}
+
+fn abi_error<'a>(
+    model: &Model,
+    error: &'a ErrorDescription,
+) -> Result<AbiError<'a>, UnsupportedError<'a>> {
+    let parameters = error
+        .fields
+        .iter()
+        .map(|field| {
+            model
+                .get_type(SOLIDITY_LANGUAGE_NAME, &field.r#type)
+                .map(|binding| AbiParameter {
+                    field,
+                    r#type: binding.expression.clone(),
+                    from_abi: binding.from_abi.clone(),
+                })
+                .map_err(|_| UnsupportedError { error, field })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let name = error
+        .bindings
+        .get(SOLIDITY_LANGUAGE_NAME)
+        .map_or_else(|| error.name.clone(), |binding| binding.expression.clone());
+    Ok(AbiError {
+        error,
+        name,
+        parameters,
+    })
+}
+
+/// Solidity declarations of the errors of a component, in the order of the
+/// model.
+pub fn abi_errors<'a>(
+    model: &Model,
+    component: &'a ComponentDescription,
+) -> Vec<Result<AbiError<'a>, UnsupportedError<'a>>> {
+    component
+        .errors
+        .iter()
+        .map(|error| abi_error(model, error))
+        .collect()
+}
@@ -0,0 +1,34 @@
+use crate::backend::IBackendConfig;
+use crate::backend::arguments::ArgumentError;
+
+pub struct SolidityBackendConfig {
+    /// SPDX license identifier of the generated files.
+    pub license: String,
+    /// Version constraint of the compiler; custom errors require 0.8.4.
+    pub pragma: String,
+}
+
+impl Default for SolidityBackendConfig {
+    fn default() -> Self {
+        Self {
+            license: "MIT".to_owned(),
+            pragma: "^0.8.4".to_owned(),
+        }
+    }
+}
+
+impl IBackendConfig for SolidityBackendConfig {
+    fn parse_arguments(
+        args: impl Iterator<Item = (String, String)>,
+    ) -> Result<Self, ArgumentError> {
+        let mut config = SolidityBackendConfig::default();
+        for (arg, val) in args {
+            match arg.as_str() {
+                "license" => config.license = val,
+                "pragma" => config.pragma = val,
+                _ => return Err(ArgumentError::UnsupportedArgument { argument: arg }),
+            }
+        }
+        Ok(config)
+    }
+}
@@ -0,0 +1,118 @@
+pub mod abi;
+pub mod config;
+pub mod error;
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use abi::abi_errors;
+use abi::interface_name;
+use config::SolidityBackendConfig;
+use error::GenerationError;
+use zksync_error_model::inner::ComponentDescription;
+use zksync_error_model::inner::Model;
+
+use super::Backend;
+use super::File;
+
+pub struct SolidityBackend {
+    config: SolidityBackendConfig,
+    model: Model,
+}
+
+impl SolidityBackend {
+    const PREAMBLE: &str = "AUTOGENERATED BASED ON A SET OF JSON FILES, DO NOT EDIT MANUALLY";
+
+    fn generate_component(&self, component: &ComponentDescription) -> File {
+        let meta = &component.meta;
+        let interface = interface_name(meta);
+        let SolidityBackendConfig { license, pragma } = &self.config;
+
+        let mut content = format!(
+            "// SPDX-License-Identifier: {license}\n//\n// {}\n//\npragma solidity {pragma};\n\n",
+            Self::PREAMBLE
+        );
+        writeln!(
+            content,
+            "/// @title Errors of the component `{}` of the domain `{}`.",
+            meta.identifier.name, meta.domain.identifier.name
+        )
+        .unwrap();
+        writeln!(content, "interface {interface} {{").unwrap();
+        for (index, error) in abi_errors(&self.model, component).into_iter().enumerate() {
+            if index > 0 {
+                content.push('\n');
+            }
+            match error {
+                Ok(error) => {
+                    let identifier = error.error.get_identifier();
+                    let message: Vec<_> = error.error.message.lines().map(str::trim).collect();
+                    writeln!(
+                        content,
+                        "    /// @notice {identifier} {}",
+                        message.join(" ")
+                    )
+                    .unwrap();
+                    let parameters: Vec<_> = error
+                        .parameters
+                        .iter()
+                        .map(|parameter| format!("{} {}", parameter.r#type, parameter.field.name))
+                        .collect();
+                    writeln!(
+                        content,
+                        "    error {}({});",
+                        error.name,
+                        parameters.join(", ")
+                    )
+                    .unwrap();
+                }
+                Err(unsupported) => {
+                    writeln!(
+                        content,
+                        "    // {} {} is not declared: the type `{}` of its field `{}` has no Solidity binding.",
+                        unsupported.error.get_identifier(),
+                        unsupported.error.name,
+                        unsupported.field.r#type,
+                        unsupported.field.name
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        content.push_str("}\n");
+
+        File {
+            relative_path: PathBuf::from(&meta.domain.identifier.name)
+                .join(format!("{interface}.sol")),
+            content,
+        }
+    }
+}
+
+impl Backend for SolidityBackend {
+    type Config = SolidityBackendConfig;
+    type GenerationError = GenerationError;
+
+    fn get_name() -> &'static str {
+        "solidity"
+    }
+
+    fn get_language_name() -> &'static str {
+        abi::SOLIDITY_LANGUAGE_NAME
+    }
+
+    fn generate(&mut self) -> Result<Vec<File>, Self::GenerationError> {
+        Ok(self
+            .model
+            .components()
+            .map(|component| self.generate_component(component))
+            .collect())
+    }
+
+    fn new(config: Self::Config, model: &Model) -> Self {
+        Self {
+            config,
+            model: model.clone(),
+        }
+    }
+}
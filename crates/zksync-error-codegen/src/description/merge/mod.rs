@@ -72,6 +72,21 @@ impl Mergeable for ArrayMultilineString {
     }
 }
 
+/// Lists merge like strings: they have to be equal unless one of them is
+/// empty.
+fn merge_lists(first: Vec<String>, second: Vec<String>) -> Result<Vec<String>, MergeError> {
+    if first.is_empty() || first == second {
+        Ok(second)
+    } else if second.is_empty() {
+        Ok(first)
+    } else {
+        Err(MergeError::StringsDiffer(
+            first.join(", "),
+            second.join(", "),
+        ))
+    }
+}
+
 impl Mergeable for Root {
     fn merge(self, other: Self) -> Result<Self, MergeError> {
         let Root {
@@ -138,6 +153,7 @@ impl Mergeable for Domain {
                 identifier_encoding: self.identifier_encoding.merge(other.identifier_encoding)?,
                 description: self.description.merge(other.description)?,
                 components,
+                visibility: self.visibility.merge(other.visibility)?,
                 bindings: self.bindings.merge(other.bindings)?,
                 take_from: vec![],
                 origins: [self.origins, other.origins].concat(),
@@ -165,9 +181,11 @@ impl Mergeable for Component {
                 component_code: self.component_code,
                 identifier_encoding: self.identifier_encoding.merge(other.identifier_encoding)?,
                 description: self.description.merge(other.description)?,
+                visibility: self.visibility.merge(other.visibility)?,
                 bindings: self.bindings.merge(other.bindings)?,
                 take_from: vec![],
                 errors: [self.errors, other.errors].concat(),
+                conversions: [self.conversions, other.conversions].concat(),
                 comment: Default::default(),
             })
         } else {
@@ -190,6 +208,7 @@ impl Mergeable for super::Error {
                 code: self.code,
                 message: self.message.merge(other.message)?,
                 fields: self.fields,
+                visibility: self.visibility.merge(other.visibility)?,
                 bindings: self.bindings.merge(other.bindings)?,
                 doc: self.doc.merge(other.doc)?,
                 origins: [self.origins, other.origins].concat(),
@@ -227,6 +246,35 @@ impl Mergeable for super::ErrorType {
     {
         Ok(Self {
             name: self.name.merge(other.name)?,
+            attributes: merge_lists(self.attributes, other.attributes)?,
+        })
+    }
+}
+impl Mergeable for super::NameBinding {
+    fn merge(self, other: Self) -> Result<Self, MergeError>
+    where
+        Self: Sized,
+    {
+        let detailed = |binding| match binding {
+            super::NameBinding::Name(name) => super::DetailedNameBinding {
+                name: Some(name),
+                ..Default::default()
+            },
+            super::NameBinding::Detailed(detailed) => detailed,
+        };
+        let (first, second) = (detailed(self), detailed(other));
+        let merged = super::DetailedNameBinding {
+            name: first.name.merge(second.name)?,
+            derives: merge_lists(first.derives, second.derives)?,
+            attributes: merge_lists(first.attributes, second.attributes)?,
+        };
+        Ok(match merged {
+            super::DetailedNameBinding {
+                name: Some(name),
+                derives,
+                attributes,
+            } if derives.is_empty() && attributes.is_empty() => super::NameBinding::Name(name),
+            merged => super::NameBinding::Detailed(merged),
         })
     }
 }
@@ -249,9 +297,47 @@ impl Mergeable for super::FullyQualifiedType {
     {
         Ok(Self {
             expression: self.expression.merge(other.expression)?,
+            imports: merge_lists(self.imports, other.imports)?,
+            dependency: self.dependency.merge(other.dependency)?,
+            implements: merge_lists(self.implements, other.implements)?,
+            strategy: self.strategy.merge(other.strategy)?,
+            from_abi: self.from_abi.merge(other.from_abi)?,
+            to_proto: self.to_proto.merge(other.to_proto)?,
+            from_proto: self.from_proto.merge(other.from_proto)?,
         })
     }
 }
+impl Mergeable for super::TypeDependency {
+    fn merge(self, other: Self) -> Result<Self, MergeError>
+    where
+        Self: Sized,
+    {
+        if self == other {
+            Ok(self)
+        } else {
+            Err(MergeError::StringsDiffer(
+                format!("{self:?}"),
+                format!("{other:?}"),
+            ))
+        }
+    }
+}
+
+impl Mergeable for zksync_error_model::inner::Visibility {
+    fn merge(self, other: Self) -> Result<Self, MergeError>
+    where
+        Self: Sized,
+    {
+        if self == other {
+            Ok(self)
+        } else {
+            Err(MergeError::StringsDiffer(
+                format!("{self:?}"),
+                format!("{other:?}"),
+            ))
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
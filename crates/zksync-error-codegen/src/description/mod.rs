@@ -17,11 +17,14 @@ use error::FileFormatError;
 use serde::Deserialize;
 use serde::Serialize;
 use strum_macros::EnumDiscriminants;
+use zksync_error_model::inner::FieldInitializer;
+use zksync_error_model::inner::Visibility;
 use zksync_error_model::link::github::GithubLink;
 
 pub type Origins = Vec<String>;
 pub type TypeMappings = BTreeMap<String, FullyQualifiedType>;
 pub type ErrorNameMapping = BTreeMap<String, ErrorType>;
+pub type NameMapping = BTreeMap<String, NameBinding>;
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Root {
@@ -43,11 +46,70 @@ pub struct Type {
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ErrorType {
     pub name: String,
+    /// Extra attributes of the generated variant.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<String>,
+}
+
+///
+/// Name of the type generated for a domain or a component, either alone or
+/// together with extra derives and attributes of the type.
+///
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NameBinding {
+    Name(String),
+    Detailed(DetailedNameBinding),
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DetailedNameBinding {
+    /// Defaults to the name of the domain or component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub derives: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FullyQualifiedType {
     pub expression: String,
+    /// Paths to import for `expression` to resolve.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub imports: Vec<String>,
+    /// Package that provides the type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependency: Option<TypeDependency>,
+    /// Traits implemented by the type, e.g. `Hash`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub implements: Vec<String>,
+    /// Strategy generating arbitrary values of the type in property tests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<String>,
+    /// Conversion of a value decoded from the Solidity ABI into the type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_abi: Option<String>,
+    /// Conversion of a value of the type into its protobuf representation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_proto: Option<String>,
+    /// Fallible conversion of a protobuf field into the type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_proto: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TypeDependency {
+    #[serde(rename = "crate")]
+    pub name: String,
+    pub version: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<String>,
+    /// The type supports serialization only if the package is built with its
+    /// feature `serde`.
+    #[serde(default)]
+    pub use_serde: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -63,12 +125,14 @@ pub struct Domain {
     pub identifier_encoding: Option<String>,
     pub description: Option<ArrayMultilineString>,
     pub components: Vec<Component>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
 
     #[serde(skip_serializing)]
     pub comment: Option<ArrayMultilineString>,
 
     #[serde(default)]
-    pub bindings: BTreeMap<String, String>,
+    pub bindings: NameMapping,
     #[serde(default)]
     pub take_from: Vec<TakeFromLink>,
     #[serde(skip_deserializing)]
@@ -82,21 +146,42 @@ pub struct Component {
 
     pub identifier_encoding: Option<String>,
     pub description: Option<ArrayMultilineString>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
 
     #[serde(skip_serializing)]
     pub comment: Option<ArrayMultilineString>,
 
     #[serde(default)]
-    pub bindings: BTreeMap<String, String>,
+    pub bindings: NameMapping,
     #[serde(default)]
     pub take_from: Vec<TakeFromLink>,
 
     #[serde(default)]
     pub errors: Vec<Error>,
+    #[serde(default, rename = "from")]
+    pub conversions: Vec<Conversion>,
     #[serde(skip_deserializing)]
     pub origins: Origins,
 }
 
+///
+/// Conversion from a foreign type, e.g. `std::io::Error`, to an error of the
+/// enclosing component.
+///
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Conversion {
+    pub bindings: BTreeMap<String, String>,
+    pub error: String,
+    #[serde(default)]
+    pub fields: BTreeMap<String, FieldInitializer>,
+    #[serde(default)]
+    pub feature: Option<String>,
+
+    #[serde(skip_serializing)]
+    pub comment: Option<ArrayMultilineString>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Error {
     pub name: String,
@@ -104,6 +189,8 @@ pub struct Error {
     pub message: String,
     #[serde(default)]
     pub fields: Vec<Field>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
 
     #[serde(skip_serializing)]
     pub comment: Option<ArrayMultilineString>,
@@ -25,4 +25,6 @@ pub enum ProgramError {
     LinkError(#[from] LinkError),
     #[error(transparent)]
     LockError(#[from] LockError),
+    #[error("No error with the code or identifier `{error}` in the model")]
+    UnknownError { error: String },
 }
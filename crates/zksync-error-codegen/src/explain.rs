@@ -0,0 +1,157 @@
+//! Explanation of a single error of the model, like `rustc --explain`.
+
+use zksync_error_description::ErrorDescription;
+use zksync_error_description::ErrorHierarchy;
+use zksync_error_description::Visibility;
+use zksync_error_model::identifier::encode_code;
+
+/// An error of the hierarchy, as serialized in JSON explanations.
+#[derive(Debug, serde::Serialize)]
+pub struct Explanation<'a> {
+    /// Encoded code of the error, the same as `Identifier::encode` of the
+    /// generated Rust crates.
+    pub encoded_code: u32,
+    #[serde(flatten)]
+    pub error: &'a ErrorDescription,
+}
+
+/// Code of an error of the hierarchy encoded with [`encode_code`].
+fn encoded_code(hierarchy: &ErrorHierarchy, error: &ErrorDescription) -> Option<u32> {
+    let domain = hierarchy.domains.get(&error.domain)?;
+    let component = hierarchy.components.get(&error.component)?;
+    Some(encode_code(domain.code, component.code, error.code))
+}
+
+/// Finds an error by its encoded code, e.g. `11001`, or its identifier, e.g.
+/// `core-seq-1` or `[core-seq-1]`, in any case.
+pub fn find<'a>(hierarchy: &'a ErrorHierarchy, query: &str) -> Option<Explanation<'a>> {
+    let query = query.trim();
+    let code: Option<u32> = query.parse().ok();
+    let identifier = query
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_lowercase();
+    hierarchy.errors.values().find_map(|error| {
+        let encoded_code = encoded_code(hierarchy, error)?;
+        let matches = match code {
+            Some(code) => code == encoded_code,
+            None => {
+                error
+                    .identifier
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .to_lowercase()
+                    == identifier
+            }
+        };
+        matches.then_some(Explanation {
+            encoded_code,
+            error,
+        })
+    })
+}
+
+/// Highlighting of the parts of a text explanation.
+struct Style {
+    color: bool,
+}
+
+impl Style {
+    const BOLD: &str = "1";
+    const RED: &str = "1;31";
+    const GREEN: &str = "32";
+    const YELLOW: &str = "33";
+    const CYAN: &str = "1;36";
+    const DIM: &str = "2";
+
+    fn paint(&self, text: &str, code: &str) -> String {
+        if self.color {
+            format!("\x1b[{code}m{text}\x1b[0m")
+        } else {
+            text.to_owned()
+        }
+    }
+
+    fn heading(&self, text: &str) -> String {
+        self.paint(text, Self::CYAN)
+    }
+}
+
+/// Human-readable explanation of an error, highlighted with ANSI escape codes
+/// if `color` is set.
+pub fn render_text(explanation: &Explanation, color: bool) -> String {
+    let style = Style { color };
+    let Explanation {
+        encoded_code,
+        error,
+    } = explanation;
+    let mut lines = vec![
+        format!(
+            "{} {} {}",
+            style.paint(&error.identifier, Style::RED),
+            style.paint(&error.name, Style::BOLD),
+            style.paint(&format!("(code {encoded_code})"), Style::DIM),
+        ),
+        format!("Domain: {}, component: {}", error.domain, error.component),
+        format!("Message: {} {}", error.identifier, error.message),
+    ];
+    if error.visibility == Visibility::Internal {
+        lines.push(style.paint("Internal, not a part of the public API", Style::YELLOW));
+    }
+
+    if let Some(documentation) = &error.documentation {
+        if !documentation.summary.is_empty() {
+            lines.push(String::new());
+            lines.push(style.paint(&documentation.summary, Style::BOLD));
+        }
+        if !documentation.description.is_empty() {
+            lines.push(String::new());
+            lines.push(documentation.description.trim_end().to_owned());
+        }
+    }
+
+    if !error.fields.is_empty() {
+        lines.push(String::new());
+        lines.push(style.heading("Fields"));
+        for field in &error.fields {
+            lines.push(format!("  {}: {}", field.name, field.r#type));
+        }
+    }
+
+    let likely_causes = error
+        .documentation
+        .as_ref()
+        .map(|documentation| documentation.likely_causes.as_slice())
+        .unwrap_or_default();
+    if !likely_causes.is_empty() {
+        lines.push(String::new());
+        lines.push(style.heading("Likely causes"));
+        for cause in likely_causes {
+            lines.push(format!("  - {}", cause.cause));
+            for fix in &cause.fixes {
+                lines.push(format!("    {} {fix}", style.paint("Fix:", Style::GREEN)));
+            }
+            if let Some(owner) = &cause.owner {
+                lines.push(format!(
+                    "    Owner: {} (version {})",
+                    owner.name, owner.version
+                ));
+            }
+            if !cause.report.is_empty() {
+                lines.push(format!("    Report to: {}", cause.report));
+            }
+            for reference in &cause.references {
+                lines.push(format!("    See: {reference}"));
+            }
+        }
+    }
+
+    if !error.origins.is_empty() {
+        lines.push(String::new());
+        lines.push(style.heading("Origins"));
+        for origin in &error.origins {
+            lines.push(format!("  {}", style.paint(origin, Style::DIM)));
+        }
+    }
+    lines.join("\n") + "\n"
+}
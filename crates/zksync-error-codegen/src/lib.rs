@@ -2,38 +2,55 @@ pub mod arguments;
 pub mod backend;
 pub mod description;
 pub mod error;
+pub mod explain;
 pub mod loader;
 pub(crate) mod util;
 
 use arguments::Backend;
+use arguments::ExplanationArguments;
+use arguments::ExplanationFormat;
 use arguments::GenerationArguments;
+use arguments::ModelFilter;
+use arguments::ModelSource;
 use backend::IBackendConfig as _;
 use description::parsers::link;
 use error::ProgramError;
 use loader::builder::build_model;
 use loader::resolution::context::ResolutionContext;
+use zksync_error_description::ErrorHierarchy;
 use zksync_error_model::inner::Model;
 use zksync_error_model::link::Link;
+use zksync_error_model::unpacked::flatten;
 
 use crate::backend::Backend as CodegenBackend;
+use crate::backend::c::CBackend;
+use crate::backend::catalogue::CatalogueBackend;
 use crate::backend::file::File;
+use crate::backend::html::HtmlBackend;
+use crate::backend::markdown::MarkdownBackend;
 use crate::backend::mdbook::MDBookBackend;
+use crate::backend::openrpc::OpenRpcBackend;
+use crate::backend::protobuf::ProtobufBackend;
 use crate::backend::rust::RustBackend;
+use crate::backend::solidity::SolidityBackend;
 
-fn generate<Backend>(
+fn parse_config<Backend>(
     backend_args: impl Iterator<Item = (String, String)>,
-    model: &Model,
-) -> Result<Vec<File>, ProgramError>
+) -> Result<Backend::Config, ProgramError>
 where
     Backend: CodegenBackend,
 {
-    let config = Backend::Config::parse_arguments(backend_args).map_err(|error| {
-        ProgramError::BackendError {
-            backend_name: Backend::get_name().to_string(),
-            inner: Box::new(error),
-        }
-    })?;
-    Backend::new(config, model)
+    Backend::Config::parse_arguments(backend_args).map_err(|error| ProgramError::BackendError {
+        backend_name: Backend::get_name().to_string(),
+        inner: Box::new(error),
+    })
+}
+
+fn run<Backend>(mut backend: Backend) -> Result<Vec<File>, ProgramError>
+where
+    Backend: CodegenBackend,
+{
+    backend
         .generate()
         .map_err(|error| ProgramError::BackendError {
             backend_name: Backend::get_name().to_string(),
@@ -41,12 +58,62 @@ where
         })
 }
 
+fn generate<Backend>(
+    backend_args: impl Iterator<Item = (String, String)>,
+    model: &Model,
+) -> Result<Vec<File>, ProgramError>
+where
+    Backend: CodegenBackend,
+{
+    let config = parse_config::<Backend>(backend_args)?;
+    run(Backend::new(config, model))
+}
+
+fn build_filtered_model(
+    input_links: Vec<Link>,
+    context: &mut ResolutionContext,
+    filter: &ModelFilter,
+    verbose: bool,
+) -> Result<Model, ProgramError> {
+    let model = build_model(input_links, context, verbose)?;
+    Ok(if filter.is_empty() {
+        model
+    } else {
+        zksync_error_model::filter::filter(&model, filter)?
+    })
+}
+
+/// Generates the MDBook documentation, along with the books of the earlier
+/// versions of the model given in the arguments, resolved as the inputs.
+fn generate_mdbook(
+    backend_args: impl Iterator<Item = (String, String)>,
+    model: &Model,
+    context: &mut ResolutionContext,
+    filter: &ModelFilter,
+    verbose: bool,
+) -> Result<Vec<File>, ProgramError> {
+    let config = parse_config::<MDBookBackend>(backend_args)?;
+    let versions = config
+        .versions
+        .iter()
+        .map(|(name, link)| {
+            if verbose {
+                eprintln!("Loading the version {name} of the model from {link}...");
+            }
+            let model = build_filtered_model(vec![link.clone()], context, filter, verbose)?;
+            Ok((name.clone(), model))
+        })
+        .collect::<Result<Vec<_>, ProgramError>>()?;
+    run(MDBookBackend::new(config, model).with_versions(versions))
+}
+
 pub fn load_and_generate(arguments: GenerationArguments) -> Result<(), ProgramError> {
     let GenerationArguments {
         verbose,
         outputs,
         input_links,
         mode,
+        filter,
     } = arguments;
 
     let mut context: ResolutionContext = (&mode).try_into()?;
@@ -56,7 +123,7 @@ pub fn load_and_generate(arguments: GenerationArguments) -> Result<(), ProgramEr
             .iter()
             .map(|repr| link::parse_str(repr))
             .collect();
-        build_model(input_links?, &mut context, verbose)?
+        build_filtered_model(input_links?, &mut context, &filter, verbose)?
     };
 
     for arguments::BackendOutput {
@@ -71,9 +138,30 @@ pub fn load_and_generate(arguments: GenerationArguments) -> Result<(), ProgramEr
 
         let result: Vec<File> = match backend {
             Backend::Rust => generate::<RustBackend>(backend_arguments.iter().cloned(), &model)?,
-            Backend::Mdbook => {
-                generate::<MDBookBackend>(backend_arguments.iter().cloned(), &model)?
+            Backend::Mdbook => generate_mdbook(
+                backend_arguments.iter().cloned(),
+                &model,
+                &mut context,
+                &filter,
+                verbose,
+            )?,
+            Backend::Solidity => {
+                generate::<SolidityBackend>(backend_arguments.iter().cloned(), &model)?
+            }
+            Backend::C => generate::<CBackend>(backend_arguments.iter().cloned(), &model)?,
+            Backend::Protobuf => {
+                generate::<ProtobufBackend>(backend_arguments.iter().cloned(), &model)?
+            }
+            Backend::OpenRpc => {
+                generate::<OpenRpcBackend>(backend_arguments.iter().cloned(), &model)?
+            }
+            Backend::Catalogue => {
+                generate::<CatalogueBackend>(backend_arguments.iter().cloned(), &model)?
+            }
+            Backend::Markdown => {
+                generate::<MarkdownBackend>(backend_arguments.iter().cloned(), &model)?
             }
+            Backend::Html => generate::<HtmlBackend>(backend_arguments.iter().cloned(), &model)?,
         };
 
         if verbose {
@@ -99,3 +187,39 @@ pub fn load_and_generate(arguments: GenerationArguments) -> Result<(), ProgramEr
     }
     Ok(())
 }
+
+/// Explains an error of the model loaded from the description files or from a
+/// dump of the error hierarchy, returning the explanation to print.
+pub fn load_and_explain(arguments: ExplanationArguments) -> Result<String, ProgramError> {
+    let ExplanationArguments {
+        verbose,
+        source,
+        error,
+        format,
+    } = arguments;
+
+    let hierarchy: ErrorHierarchy = match source {
+        ModelSource::Links { input_links, mode } => {
+            let mut context: ResolutionContext = (&mode).try_into()?;
+            let input_links: Result<Vec<Link>, _> = input_links
+                .iter()
+                .map(|repr| link::parse_str(repr))
+                .collect();
+            let model = build_model(input_links?, &mut context, verbose)?;
+            flatten(&model).into()
+        }
+        ModelSource::Dump(path) => {
+            if verbose {
+                eprintln!("Reading the error hierarchy from {}...", path.display());
+            }
+            ErrorHierarchy::try_deserialize(&std::fs::read_to_string(path)?)?
+        }
+    };
+
+    let explanation =
+        explain::find(&hierarchy, &error).ok_or(ProgramError::UnknownError { error })?;
+    Ok(match format {
+        ExplanationFormat::Text { color } => explain::render_text(&explanation, color),
+        ExplanationFormat::Json => serde_json::to_string_pretty(&explanation)? + "\n",
+    })
+}
@@ -17,6 +17,7 @@ use maplit::btreemap;
 use zksync_error_model::inner::domain;
 use zksync_error_model::link::Link;
 
+use zksync_error_model::inner::Annotations;
 use zksync_error_model::inner::ComponentDescription;
 use zksync_error_model::inner::ComponentMetadata;
 use zksync_error_model::inner::DomainDescription;
@@ -25,15 +26,19 @@ use zksync_error_model::inner::ErrorDescription;
 use zksync_error_model::inner::ErrorDocumentation;
 use zksync_error_model::inner::ErrorName;
 use zksync_error_model::inner::FieldDescription;
+use zksync_error_model::inner::ForeignConversion;
 use zksync_error_model::inner::FullyQualifiedTargetLanguageType;
 use zksync_error_model::inner::LikelyCause;
 use zksync_error_model::inner::Model;
 use zksync_error_model::inner::TargetLanguageType;
+use zksync_error_model::inner::TypeDependency;
 use zksync_error_model::inner::TypeDescription;
 use zksync_error_model::inner::TypeMetadata;
 use zksync_error_model::inner::VersionedOwner;
 use zksync_error_model::validator::validate;
 
+use crate::description::DetailedNameBinding;
+use crate::description::NameBinding;
 use crate::description::Root;
 use crate::description::merge::Mergeable as _;
 
@@ -67,16 +72,68 @@ where
     result
 }
 fn translate_and_populate_bindings(
-    bindings: &BTreeMap<String, String>,
+    bindings: &crate::description::NameMapping,
     default: &str,
 ) -> BTreeMap<String, String> {
+    let names = bindings
+        .iter()
+        .map(|(language, binding)| {
+            let name = match binding {
+                NameBinding::Name(name) => name.clone(),
+                NameBinding::Detailed(DetailedNameBinding { name, .. }) => {
+                    name.clone().unwrap_or_else(|| default.to_owned())
+                }
+            };
+            (language.clone(), name)
+        })
+        .collect();
     ensure_existing(
-        bindings.clone(),
+        names,
         default.to_string(),
         ["rust", "typescript"].into_iter(),
     )
 }
 
+fn translate_annotations(
+    bindings: &crate::description::NameMapping,
+) -> BTreeMap<String, Annotations> {
+    bindings
+        .iter()
+        .filter_map(|(language, binding)| match binding {
+            NameBinding::Name(_) => None,
+            NameBinding::Detailed(DetailedNameBinding {
+                derives,
+                attributes,
+                ..
+            }) => Some((
+                language.clone(),
+                Annotations {
+                    derives: derives.clone(),
+                    attributes: attributes.clone(),
+                },
+            )),
+        })
+        .collect()
+}
+
+fn translate_error_annotations(
+    bindings: &crate::description::ErrorNameMapping,
+) -> BTreeMap<String, Annotations> {
+    bindings
+        .iter()
+        .filter(|(_, binding)| !binding.attributes.is_empty())
+        .map(|(language, binding)| {
+            (
+                language.clone(),
+                Annotations {
+                    derives: vec![],
+                    attributes: binding.attributes.clone(),
+                },
+            )
+        })
+        .collect()
+}
+
 fn translate_type_bindings(
     value: &crate::description::ErrorNameMapping,
     error_name: &ErrorName,
@@ -115,12 +172,34 @@ fn translate_type_mappings(
                 language_name.clone(),
                 FullyQualifiedTargetLanguageType {
                     expression: mapping.expression.clone(),
+                    imports: mapping.imports.clone(),
+                    dependency: mapping.dependency.as_ref().map(translate_type_dependency),
+                    implements: mapping.implements.clone(),
+                    strategy: mapping.strategy.clone(),
+                    from_abi: mapping.from_abi.clone(),
+                    to_proto: mapping.to_proto.clone(),
+                    from_proto: mapping.from_proto.clone(),
                 },
             )
         })
         .collect())
 }
 
+fn translate_type_dependency(value: &crate::description::TypeDependency) -> TypeDependency {
+    let crate::description::TypeDependency {
+        name,
+        version,
+        features,
+        use_serde,
+    } = value;
+    TypeDependency {
+        name: name.clone(),
+        version: version.clone(),
+        features: features.clone(),
+        use_serde: *use_serde,
+    }
+}
+
 fn translate_type(
     value: &crate::description::Type,
     _ctx: &TypeTranslationContext,
@@ -259,6 +338,7 @@ fn translate_error(
         bindings,
         fields,
         doc,
+        visibility,
         origins,
         comment: _,
     } = error;
@@ -277,8 +357,10 @@ fn translate_error(
         fields: transformed_fields?,
         documentation,
         bindings: transformed_bindings,
+        annotations: translate_error_annotations(bindings),
         domain: ctx.parent.domain.clone(),
         component: ctx.component.clone(),
+        visibility: visibility.unwrap_or_default(),
         origins: origins.clone(),
     })
 }
@@ -299,6 +381,25 @@ fn translate_errors<'a>(
     }
     Ok(transformed_errors)
 }
+fn translate_conversion(conversion: &crate::description::Conversion) -> ForeignConversion {
+    let crate::description::Conversion {
+        bindings,
+        error,
+        fields,
+        feature,
+        comment: _,
+    } = conversion;
+    ForeignConversion {
+        bindings: bindings
+            .iter()
+            .map(|(language, expression)| (language.clone(), expression.as_str().into()))
+            .collect(),
+        error: error.clone(),
+        fields: fields.clone(),
+        feature: feature.clone(),
+    }
+}
+
 fn translate_component<'a>(
     component: &crate::description::Component,
     ctx: &'a ComponentTranslationContext<'a>,
@@ -310,7 +411,9 @@ fn translate_component<'a>(
         description,
         take_from,
         errors,
+        conversions,
         bindings,
+        visibility,
         origins,
         comment: _,
     } = component;
@@ -318,6 +421,7 @@ fn translate_component<'a>(
     let new_bindings = translate_and_populate_bindings(bindings, component_name);
     let component_meta: Rc<ComponentMetadata> = Rc::new(ComponentMetadata {
         bindings: new_bindings,
+        annotations: translate_annotations(bindings),
         identifier: zksync_error_model::inner::component::Identifier {
             name: component_name.clone(),
             code: *component_code,
@@ -325,6 +429,7 @@ fn translate_component<'a>(
         },
         description: description.clone().unwrap_or_default().into(),
         domain: ctx.domain.clone(),
+        visibility: visibility.unwrap_or_default(),
         origins: origins.clone(),
     });
 
@@ -332,6 +437,7 @@ fn translate_component<'a>(
     let mut result = ComponentDescription {
         meta: component_meta.clone(),
         errors: transformed_errors,
+        conversions: conversions.iter().map(translate_conversion).collect(),
     };
 
     Ok(result)
@@ -349,6 +455,7 @@ fn translate_domain<'a>(
         components,
         bindings,
         take_from,
+        visibility,
         origins,
         comment: _,
     } = value;
@@ -361,6 +468,8 @@ fn translate_domain<'a>(
         },
         description: description.clone().unwrap_or_default().into(),
         bindings: translate_and_populate_bindings(bindings, domain_name),
+        annotations: translate_annotations(bindings),
+        visibility: visibility.unwrap_or_default(),
         origins: origins.clone(),
     });
 
@@ -390,6 +499,16 @@ fn add_default_error(model: &mut Model) {
     for domain in model.domains.values_mut() {
         for component in domain.components.values_mut() {
             if !component.errors.iter().any(|e| e.code == 0) {
+                // Solidity selectors only depend on the names of errors, so
+                // the generic errors of components are named apart.
+                let solidity_name = format!(
+                    "Generic{}Error",
+                    component
+                        .meta
+                        .bindings
+                        .get("solidity")
+                        .unwrap_or(&component.meta.identifier.name)
+                );
                 component.errors.push(ErrorDescription {
                     domain: domain.meta.clone(),
                     component: component.meta.clone(),
@@ -404,7 +523,10 @@ fn add_default_error(model: &mut Model) {
                     bindings: btreemap! {
                         "rust".into() => TargetLanguageType { expression: "GenericError".into()} ,
                         "typescript".into() => TargetLanguageType { expression: "GenericError".into()} ,
+                        "solidity".into() => TargetLanguageType { expression: solidity_name } ,
                     },
+                    annotations: Default::default(),
+                    visibility: Default::default(),
                     origins: vec![],
                 });
             }
@@ -1 +1,2 @@
 mod loader;
+mod model;
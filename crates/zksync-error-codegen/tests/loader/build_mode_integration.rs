@@ -47,6 +47,7 @@ fn test_normal_to_reproducible_workflow() {
 
     // Step 1: Run in Normal mode to create lock file
     let normal_args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Normal {
@@ -66,6 +67,7 @@ fn test_normal_to_reproducible_workflow() {
 
     // Step 2: Run in Reproducible mode using the same lock file
     let reproducible_args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Reproducible {
@@ -170,6 +172,7 @@ fn test_build_mode_with_invalid_lock_file() {
 
     // Test Normal mode with invalid lock file - should handle gracefully
     let normal_args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Normal {
@@ -196,6 +199,7 @@ fn test_build_mode_with_invalid_lock_file() {
 
     // Test Reproducible mode with invalid lock file
     let repro_args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Reproducible {
@@ -400,6 +404,7 @@ fn test_mode_switching_preserves_outputs() {
 
     // Generate with Normal mode
     let normal_result = load_and_generate(GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Normal {
@@ -426,6 +431,7 @@ fn test_mode_switching_preserves_outputs() {
 
     // Generate with Reproducible mode using the same lock
     let repro_result = load_and_generate(GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Reproducible {
@@ -102,6 +102,7 @@ fn test_no_lock_mode_basic() {
     fs::create_dir_all(&output_dir).expect("Failed to create output dir");
 
     let args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::NoLock {
@@ -156,6 +157,7 @@ fn test_no_lock_mode_with_overrides() {
     fs::create_dir_all(&output_dir).expect("Failed to create output dir");
 
     let args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::NoLock {
@@ -190,6 +192,7 @@ fn test_normal_mode_creates_lock_file() {
     fs::create_dir_all(&output_dir).expect("Failed to create output dir");
 
     let args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Normal {
@@ -236,6 +239,7 @@ fn test_normal_mode_updates_existing_lock_file() {
     fs::create_dir_all(&output_dir).expect("Failed to create output dir");
 
     let args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Normal {
@@ -289,6 +293,7 @@ fn test_reproducible_mode_uses_existing_lock() {
     fs::create_dir_all(&output_dir).expect("Failed to create output dir");
 
     let args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Reproducible {
@@ -333,6 +338,7 @@ fn test_reproducible_mode_fails_without_lock_file() {
     fs::create_dir_all(&output_dir).expect("Failed to create output dir");
 
     let args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Reproducible {
@@ -370,6 +376,7 @@ fn test_reproducible_mode_fails_with_missing_dependency() {
     fs::create_dir_all(&output_dir).expect("Failed to create output dir");
 
     let args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Reproducible {
@@ -402,6 +409,7 @@ fn test_mode_with_multiple_outputs() {
     fs::create_dir_all(&docs_output_dir).expect("Failed to create docs output dir");
 
     let args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::NoLock {
@@ -512,6 +520,7 @@ fn test_build_mode_error_propagation() {
         },
     ] {
         let args = GenerationArguments {
+            filter: Default::default(),
             verbose: false,
             input_links: vec![input_file.to_string_lossy().to_string()],
             mode,
@@ -400,6 +400,7 @@ fn test_end_to_end_override_behavior() {
 
     // Test with Normal mode (should use overrides and ignore lock for overridden dependency)
     let args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Normal {
@@ -461,6 +462,7 @@ fn test_reproducible_mode_no_overrides() {
 
     // Try Reproducible mode (should work without overrides)
     let args = GenerationArguments {
+        filter: Default::default(),
         verbose: false,
         input_links: vec![input_file.to_string_lossy().to_string()],
         mode: ResolutionMode::Reproducible {
@@ -0,0 +1,59 @@
+use zksync_error_codegen::backend::file::File;
+use zksync_error_codegen::backend::rust::error::GenerationError;
+
+use super::common::Description;
+use super::common::file;
+use super::common::generate_rust;
+
+fn generate(blob_bindings: &str, arguments: &[(&str, &str)]) -> Result<Vec<File>, GenerationError> {
+    let model = Description::default()
+        .with_type(&format!(
+            r#"{{ "name": "blob", "description": "", "bindings": {{ "rust": {blob_bindings} }} }}"#
+        ))
+        .with_domain_property(r#""bindings": { "rust": { "derives": ["PartialOrd"] } }"#)
+        .with_component_property(
+            r#""bindings": { "rust": { "name": "Sequencer", "attributes": ["serde(tag = \"kind\")"] } }"#,
+        )
+        .with_error(
+            r#"{
+                "name": "Halted",
+                "code": 1,
+                "message": "Halted",
+                "fields": [ { "name": "payload", "type": "blob" } ],
+                "bindings": { "rust": { "name": "Halted", "attributes": ["doc(alias = \"stopped\")"] } }
+            }"#,
+        )
+        .model();
+    generate_rust(&model, arguments)
+}
+
+#[test]
+fn test_annotations_are_emitted() {
+    let files = generate(
+        r#"{ "expression": "Vec<u8>", "implements": ["Hash"] }"#,
+        &[("derive", "Hash")],
+    )
+    .expect("Generation should succeed");
+
+    let definitions = file(&files, "src/error/definitions.rs");
+    assert!(definitions.contains("#[derive(Hash, PartialOrd)]"));
+    assert!(definitions.contains(r#"#[serde(tag = "kind")]"#));
+    assert!(definitions.contains(r#"#[doc(alias = "stopped")]"#));
+
+    // Derives shared by all components propagate to the enclosing enums.
+    let domains = file(&files, "src/error/domains.rs");
+    assert_eq!(domains.matches("PartialEq, Hash, PartialOrd)]").count(), 2);
+}
+
+#[test]
+fn test_unsupported_derive() {
+    let result = generate(
+        r#"{ "expression": "serde_json::Value" }"#,
+        &[("derive", "Hash")],
+    );
+    assert!(matches!(
+        result,
+        Err(GenerationError::UnsupportedDerive { ref derive, ref r#type, .. })
+            if derive == "Hash" && r#type == "blob"
+    ));
+}
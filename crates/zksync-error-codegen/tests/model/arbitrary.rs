@@ -0,0 +1,59 @@
+use zksync_error_codegen::backend::file::File;
+use zksync_error_codegen::backend::rust::error::GenerationError;
+
+use super::common::Description;
+use super::common::file;
+use super::common::generate_rust;
+
+fn generate(blob_strategy: &str) -> Result<Vec<File>, GenerationError> {
+    let model = Description::default()
+        .with_type(&format!(
+            r#"{{ "name": "blob", "description": "", "bindings": {{ "rust": {{
+                "expression": "Box<blobs::Blob>",
+                "strategy": {blob_strategy}
+            }} }} }}"#
+        ))
+        .with_error(
+            r#"{
+                "name": "Halted",
+                "code": 1,
+                "message": "Halted",
+                "fields": [
+                    { "name": "payload", "type": "blob" },
+                    { "name": "reason", "type": "string" }
+                ]
+            }"#,
+        )
+        .model();
+    generate_rust(&model, &[("generate_cargo_toml", "true")])
+}
+
+#[test]
+fn test_strategies_are_used() {
+    let files = generate(
+        r#""prop::collection::vec(any::<u8>(), 0..32).prop_map(|bytes| Box::new(blobs::Blob::new(bytes)))""#,
+    )
+    .expect("Generation should succeed");
+
+    let arbitrary = file(&files, "src/arbitrary.rs");
+    assert!(arbitrary.contains("impl Arbitrary for Sequencer"));
+    assert!(arbitrary.contains("impl Arbitrary for Core"));
+    assert!(arbitrary.contains("impl Arbitrary for ZksyncError"));
+    assert!(arbitrary.contains("blobs::Blob::new(bytes)"));
+    assert!(arbitrary.contains("any::<String>()"));
+
+    let manifest = file(&files, "Cargo.example.toml");
+    assert!(manifest.contains(r#"proptest = ["std", "dep:proptest"]"#));
+    assert!(manifest.contains(r#"blobs = { version = "*" }"#));
+    assert!(!manifest.contains("prop ="));
+    assert!(!manifest.contains("Box ="));
+}
+
+#[test]
+fn test_invalid_strategy() {
+    let result = generate(r#""any::<u8>(""#);
+    assert!(matches!(
+        result,
+        Err(GenerationError::InvalidStrategy { ref r#type, .. }) if r#type == "blob"
+    ));
+}
@@ -0,0 +1,141 @@
+use std::path::Path;
+use std::process::Command;
+use zksync_error_codegen::backend::Backend as _;
+use zksync_error_codegen::backend::IBackendConfig as _;
+use zksync_error_codegen::backend::c::CBackend;
+use zksync_error_codegen::backend::c::config::CBackendConfig;
+
+use super::common::build_model_from_str;
+use super::common::file;
+
+const DESCRIPTION: &str = r#"{
+    "types": [
+        { "name": "string", "description": "", "bindings": { "rust": { "expression": "String" } } }
+    ],
+    "domains": [
+        {
+            "domain_name": "ExecutionPlatform",
+            "domain_code": 3,
+            "identifier_encoding": "ep",
+            "components": [
+                {
+                    "component_name": "EraVM",
+                    "component_code": 2,
+                    "identifier_encoding": "eravm",
+                    "errors": [
+                        {
+                            "name": "StackOverflow",
+                            "code": 17,
+                            "message": "Stack overflow: \"{reason}\"",
+                            "fields": [ { "name": "reason", "type": "string" } ],
+                            "doc": { "description": "The stack is exhausted.", "summary": "Stack exhausted" }
+                        }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+#[test]
+fn test_c_header_and_shim() {
+    let model = build_model_from_str(DESCRIPTION).expect("Model should be valid");
+    let config =
+        CBackendConfig::parse_arguments([("prefix".to_owned(), "acme".to_owned())].into_iter())
+            .expect("Arguments should be valid");
+    let files = CBackend::new(config, &model)
+        .generate()
+        .expect("Generation should succeed");
+
+    let header = file(&files, "include/acme.h");
+    assert!(header.contains("#ifndef ACME_H"));
+    assert!(header.contains("ACME_DOMAIN_EP = 3,"));
+    assert!(header.contains("enum acme_ep_component {"));
+    assert!(header.contains("ACME_COMPONENT_EP_ERAVM = 2,"));
+    assert!(header.contains("#define ACME_EP_ERAVM_STACK_OVERFLOW 32017u /* [ep-eravm-17] */"));
+    assert!(header.contains("#define ACME_EP_ERAVM_GENERIC_ERROR 32000u"));
+    assert!(header.contains("const char *acme_summary(uint32_t code);"));
+
+    assert!(file(&files, "shim/Cargo.toml").contains("name = \"acme_c\""));
+    let shim = file(&files, "shim/src/lib.rs");
+    assert!(shim.contains("pub extern \"C\" fn acme_message(code: u32)"));
+    assert!(shim.contains(r#"message: b"Stack overflow: \"{reason}\"\0","#));
+    assert!(shim.contains(r#"summary: Some(b"Stack exhausted\0"),"#));
+}
+
+#[test]
+fn test_c_prefix_validation() {
+    for prefix in ["", "1abc", "my-lib"] {
+        assert!(
+            CBackendConfig::parse_arguments([("prefix".to_owned(), prefix.to_owned())].into_iter())
+                .is_err(),
+            "Prefix {prefix:?} should be rejected"
+        );
+    }
+}
+
+const LOOKUP_TEST: &str = r#"
+#include <stdio.h>
+#include <string.h>
+
+#include "acme.h"
+
+int main(void) {
+    const char *name = acme_name(ACME_EP_ERAVM_STACK_OVERFLOW);
+    if (name == NULL || strcmp(name, "StackOverflow") != 0) return 1;
+    if (strcmp(acme_identifier(32017u), "[ep-eravm-17]") != 0) return 2;
+    if (acme_summary(ACME_EP_ERAVM_GENERIC_ERROR) != NULL) return 3;
+    /* Codes that do not round-trip through the encoding match no error. */
+    if (acme_name(932017u) != NULL || acme_message(332017u) != NULL) return 4;
+    if (acme_name(ACME_ENCODE(3, 2, 18)) != NULL) return 5;
+    puts("ok");
+    return 0;
+}
+"#;
+
+fn run(command: &mut Command) {
+    let output = command.output().expect("Failed to run command");
+    assert!(
+        output.status.success(),
+        "Command {command:?} failed:\n{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
+
+#[test]
+fn test_c_shim_links() {
+    let model = build_model_from_str(DESCRIPTION).expect("Model should be valid");
+    let config =
+        CBackendConfig::parse_arguments([("prefix".to_owned(), "acme".to_owned())].into_iter())
+            .expect("Arguments should be valid");
+    let files = CBackend::new(config, &model)
+        .generate()
+        .expect("Generation should succeed");
+
+    let directory = tempfile::tempdir().expect("Failed to create temp dir");
+    for file in &files {
+        let path = directory.path().join(&file.relative_path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, &file.content).unwrap();
+    }
+    std::fs::write(directory.path().join("main.c"), LOOKUP_TEST).unwrap();
+
+    let target_directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("c");
+    run(Command::new(env!("CARGO"))
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(directory.path().join("shim/Cargo.toml"))
+        .env("CARGO_TARGET_DIR", &target_directory));
+
+    let executable = directory.path().join("main");
+    run(Command::new("cc")
+        .args(["-std=c99", "-Wall", "-Wextra", "-pedantic", "-Werror"])
+        .arg("-I")
+        .arg(directory.path().join("include"))
+        .arg(directory.path().join("main.c"))
+        .arg(target_directory.join("debug/libacme_c.a"))
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&executable));
+    run(&mut Command::new(executable));
+}
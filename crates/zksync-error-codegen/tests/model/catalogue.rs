@@ -0,0 +1,156 @@
+use serde_json::Value;
+use serde_json::json;
+use zksync_error_codegen::backend::Backend as _;
+use zksync_error_codegen::backend::IBackendConfig as _;
+use zksync_error_codegen::backend::arguments::ArgumentError;
+use zksync_error_codegen::backend::catalogue::CatalogueBackend;
+use zksync_error_codegen::backend::catalogue::config::CatalogueBackendConfig;
+use zksync_error_codegen::backend::file::File;
+
+use super::common::build_model_from_str;
+use super::common::file;
+
+const DESCRIPTION: &str = r#"{
+    "types": [
+        { "name": "string", "description": "", "bindings": { "rust": { "expression": "String" } } }
+    ],
+    "domains": [
+        {
+            "domain_name": "ExecutionPlatform",
+            "domain_code": 1,
+            "identifier_encoding": "ep",
+            "components": [
+                {
+                    "component_name": "EraVM",
+                    "component_code": 2,
+                    "identifier_encoding": "eravm",
+                    "errors": [
+                        {
+                            "name": "Secret",
+                            "code": 4,
+                            "message": "Secret",
+                            "visibility": "internal"
+                        },
+                        {
+                            "name": "Reverted",
+                            "code": 3,
+                            "message": "Reverted: {reason}",
+                            "fields": [ { "name": "reason", "type": "string" } ],
+                            "doc": {
+                                "description": "The call reverted.",
+                                "summary": "Call reverted, \"badly\"",
+                                "likely_causes": [
+                                    {
+                                        "cause": "Out of gas",
+                                        "fixes": [ "Raise the gas limit", "Simplify the call" ],
+                                        "owner": { "name": "vm", "version": "1.0.0" }
+                                    },
+                                    {
+                                        "cause": "Explicit revert",
+                                        "fixes": [ "Check the inputs" ],
+                                        "owner": { "name": "vm", "version": "1.0.0" }
+                                    }
+                                ]
+                            }
+                        }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+fn parse(arguments: &[(&str, &str)]) -> Result<CatalogueBackendConfig, ArgumentError> {
+    CatalogueBackendConfig::parse_arguments(
+        arguments
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    )
+}
+
+fn generate(arguments: &[(&str, &str)]) -> Vec<File> {
+    let model = build_model_from_str(DESCRIPTION).expect("Model should be valid");
+    let config = parse(arguments).expect("Arguments should be valid");
+    CatalogueBackend::new(config, &model)
+        .generate()
+        .expect("Generation should succeed")
+}
+
+#[test]
+fn test_catalogue_default_columns() {
+    let files = generate(&[("hide_internal", "true")]);
+    assert_eq!(files.len(), 2);
+
+    let csv: Vec<_> = file(&files, "catalogue.csv").lines().collect();
+    assert_eq!(
+        csv[0],
+        "identifier,code,domain,component,name,message,summary,likely_causes,owners,origins"
+    );
+    assert!(
+        csv[1].starts_with(
+            "[ep-eravm-0],12000,ExecutionPlatform,EraVM,GenericError,Generic error: {message},"
+        ),
+        "{csv:?}"
+    );
+    assert!(!csv.iter().any(|line| line.contains("Secret")));
+
+    let jsonl: Vec<Value> = file(&files, "catalogue.jsonl")
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Each line should be valid JSON"))
+        .collect();
+    assert_eq!(jsonl.len(), 2);
+    assert_eq!(jsonl[1]["code"], json!(12003));
+    assert_eq!(jsonl[1]["summary"], json!("Call reverted, \"badly\""));
+    assert_eq!(
+        jsonl[1]["likely_causes"],
+        json!(["Out of gas", "Explicit revert"])
+    );
+    assert_eq!(jsonl[1]["owners"], json!(["vm@1.0.0"]));
+    assert_eq!(jsonl[0]["summary"], Value::Null);
+}
+
+#[test]
+fn test_catalogue_custom_columns() {
+    let files = generate(&[("columns", "name,summary,fixes,code"), ("formats", "csv")]);
+    assert_eq!(files.len(), 1);
+    let csv = file(&files, "catalogue.csv");
+    assert_eq!(
+        csv,
+        "name,summary,fixes,code
+GenericError,,,12000
+Reverted,\"Call reverted, \"\"badly\"\"\",\"Raise the gas limit
+Simplify the call
+Check the inputs\",12003
+Secret,,,12004
+"
+    );
+
+    let files = generate(&[("columns", "code, visibility"), ("formats", "jsonl")]);
+    let keys: Vec<_> = file(&files, "catalogue.jsonl")
+        .lines()
+        .map(|line| line.split(':').next().unwrap().to_owned())
+        .collect();
+    assert!(keys.iter().all(|key| key == "{\"code\""), "{keys:?}");
+    assert!(file(&files, "catalogue.jsonl").contains("\"visibility\":\"internal\""));
+}
+
+#[test]
+fn test_catalogue_invalid_arguments() {
+    for (argument, value) in [
+        ("columns", "name,bogus"),
+        ("columns", "name,code,name"),
+        ("formats", "xml"),
+    ] {
+        assert!(
+            matches!(
+                parse(&[(argument, value)]),
+                Err(ArgumentError::InvalidArgument { .. })
+            ),
+            "{argument}={value} should be rejected"
+        );
+    }
+    assert!(matches!(
+        parse(&[("rows", "all")]),
+        Err(ArgumentError::UnsupportedArgument { .. })
+    ));
+}
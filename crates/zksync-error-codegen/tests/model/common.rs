@@ -0,0 +1,261 @@
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use tempfile::NamedTempFile;
+use zksync_error_codegen::backend::Backend as _;
+use zksync_error_codegen::backend::IBackendConfig as _;
+use zksync_error_codegen::backend::file::File;
+use zksync_error_codegen::backend::rust::RustBackend;
+use zksync_error_codegen::backend::rust::RustBackendConfig;
+use zksync_error_codegen::backend::rust::error::GenerationError;
+use zksync_error_codegen::loader::builder::build_model;
+use zksync_error_codegen::loader::builder::error::ModelBuildingError;
+use zksync_error_codegen::loader::resolution::{context::ResolutionContext, overrides::Remapping};
+use zksync_error_model::inner::Model;
+use zksync_error_model::link::Link;
+
+/// Builds a model from a single description file with the given contents.
+pub fn build_model_from_str(contents: &str) -> Result<Model, ModelBuildingError> {
+    let mut file = NamedTempFile::new().expect("Failed to create temp file");
+    write!(file, "{contents}").expect("Failed to write to temp file");
+    let link = Link::FileLink {
+        path: file.path().to_string_lossy().to_string(),
+    };
+    let mut context = ResolutionContext::NoLock {
+        overrides: Remapping {
+            map: Default::default(),
+        },
+    };
+    build_model(vec![link], &mut context, false)
+}
+
+/// An error of the component `Sequencer` with a single `string` field.
+pub const HALTED: &str = r#"{
+    "name": "Halted",
+    "code": 1,
+    "message": "Halted: {reason}",
+    "fields": [ { "name": "reason", "type": "string" } ]
+}"#;
+
+/// Description of the domain `Core` (`core`, code 1) holding the component
+/// `Sequencer` (`seq`, code 1), with the type `string` bound to `String` and
+/// to the Solidity `string`.
+/// Tests add the errors and types they need.
+#[derive(Clone, Default)]
+pub struct Description {
+    types: Vec<String>,
+    errors: Vec<String>,
+    conversions: Vec<String>,
+    domain_properties: Vec<String>,
+    component_properties: Vec<String>,
+}
+
+impl Description {
+    /// Adds a type besides `string`.
+    pub fn with_type(mut self, r#type: &str) -> Self {
+        self.types.push(r#type.to_owned());
+        self
+    }
+
+    /// Adds an error to the component `Sequencer`.
+    pub fn with_error(mut self, error: &str) -> Self {
+        self.errors.push(error.to_owned());
+        self
+    }
+
+    /// Adds a conversion from a foreign type to the component `Sequencer`.
+    pub fn with_conversion(mut self, conversion: &str) -> Self {
+        self.conversions.push(conversion.to_owned());
+        self
+    }
+
+    /// Adds a property, e.g. `"bindings": { ... }`, to the domain `Core`.
+    pub fn with_domain_property(mut self, property: &str) -> Self {
+        self.domain_properties.push(property.to_owned());
+        self
+    }
+
+    /// Adds a property, e.g. `"bindings": { ... }`, to the component
+    /// `Sequencer`.
+    pub fn with_component_property(mut self, property: &str) -> Self {
+        self.component_properties.push(property.to_owned());
+        self
+    }
+
+    pub fn model(&self) -> Model {
+        build_model_from_str(&self.to_string()).expect("Model should be valid")
+    }
+}
+
+impl fmt::Display for Description {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let properties = |properties: &[String]| -> String {
+            properties
+                .iter()
+                .map(|property| format!(" {property},"))
+                .collect()
+        };
+        let string = r#"{ "name": "string", "description": "", "bindings": { "rust": { "expression": "String" }, "solidity": { "expression": "string" } } }"#;
+        let types: Vec<_> = std::iter::once(string)
+            .chain(self.types.iter().map(String::as_str))
+            .collect();
+        write!(
+            f,
+            r#"{{
+    "types": [ {} ],
+    "domains": [
+        {{
+            "domain_name": "Core",
+            "domain_code": 1,
+            "identifier_encoding": "core",{}
+            "components": [
+                {{
+                    "component_name": "Sequencer",
+                    "component_code": 1,
+                    "identifier_encoding": "seq",{}
+                    "errors": [ {} ],
+                    "from": [ {} ]
+                }}
+            ]
+        }}
+    ]
+}}"#,
+            types.join(", "),
+            properties(&self.domain_properties),
+            properties(&self.component_properties),
+            self.errors.join(", "),
+            self.conversions.join(", "),
+        )
+    }
+}
+
+/// Contents of the generated file at the given relative path.
+pub fn file<'a>(files: &'a [File], path: &str) -> &'a str {
+    &files
+        .iter()
+        .find(|file| file.relative_path.to_str() == Some(path))
+        .unwrap_or_else(|| panic!("Missing file {path}"))
+        .content
+}
+
+/// Runs the Rust backend on the model with the given backend arguments.
+pub fn generate_rust(
+    model: &Model,
+    arguments: &[(&str, &str)],
+) -> Result<Vec<File>, GenerationError> {
+    let config = RustBackendConfig::parse_arguments(
+        arguments
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    )
+    .expect("Arguments should be valid");
+    RustBackend::new(config, model).generate()
+}
+
+/// The crate generated by the Rust backend for a model, tested by running
+/// `cargo test` on it with the given test files.
+pub struct GeneratedCrate<'a> {
+    model: &'a Model,
+    arguments: Vec<(&'a str, &'a str)>,
+    tests: Vec<(String, &'a str)>,
+    cargo_arguments: Vec<&'a str>,
+}
+
+impl<'a> GeneratedCrate<'a> {
+    pub fn new(model: &'a Model) -> Self {
+        Self {
+            model,
+            arguments: vec![],
+            tests: vec![],
+            cargo_arguments: vec![],
+        }
+    }
+
+    /// Adds arguments of the Rust backend.
+    pub fn arguments(mut self, arguments: &[(&'a str, &'a str)]) -> Self {
+        self.arguments.extend(arguments);
+        self
+    }
+
+    /// Adds the test file `tests/<name>.rs`.
+    pub fn test(mut self, name: &str, source: &'a str) -> Self {
+        self.tests.push((format!("tests/{name}.rs"), source));
+        self
+    }
+
+    /// Adds arguments of `cargo test`, e.g. `["--features", "miette"]`.
+    pub fn cargo_arguments(mut self, arguments: &[&'a str]) -> Self {
+        self.cargo_arguments.extend(arguments);
+        self
+    }
+
+    /// Generates the crate and runs `cargo test` on it.
+    pub fn run(&self) {
+        // Build against the local description crate rather than the one on
+        // GitHub.
+        let description_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../zksync-error-description")
+            .canonicalize()
+            .expect("Description crate should exist");
+        let arguments = [
+            &[
+                ("complete_cargo_toml", "true"),
+                ("description_path", description_path.to_str().unwrap()),
+            ],
+            self.arguments.as_slice(),
+        ]
+        .concat();
+        let files = generate_rust(self.model, &arguments).expect("Generation should succeed");
+
+        let directory = tempfile::tempdir().expect("Failed to create temp dir");
+        let generated = files
+            .iter()
+            .map(|file| (file.relative_path.to_str().unwrap(), file.content.as_str()));
+        let tests = self
+            .tests
+            .iter()
+            .map(|(path, source)| (path.as_str(), *source));
+        for (path, content) in generated.chain(tests) {
+            let path = directory.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+
+        // Keep the generated crate out of any enclosing workspace.
+        let manifest_path = directory.path().join("Cargo.toml");
+        let manifest = file(&files, "Cargo.toml");
+        std::fs::write(&manifest_path, format!("{manifest}\n[workspace]\n")).unwrap();
+
+        // All generated crates share a target directory and a lockfile, so that
+        // cargo does not query the registry index for each of them.
+        let target_directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("generated");
+        let lockfile = target_directory.join("Cargo.lock");
+        if lockfile.exists() {
+            std::fs::copy(&lockfile, directory.path().join("Cargo.lock")).unwrap();
+        }
+
+        let output = Command::new(env!("CARGO"))
+            .arg("test")
+            .arg("--manifest-path")
+            .arg(&manifest_path)
+            .args(&self.cargo_arguments)
+            .env("CARGO_TARGET_DIR", &target_directory)
+            // Pick dependency versions supported by the local toolchain.
+            .env("CARGO_RESOLVER_INCOMPATIBLE_RUST_VERSIONS", "fallback")
+            .output()
+            .expect("Failed to run cargo");
+        if output.status.success() {
+            // Other tests may be reading the lockfile, so replace it atomically.
+            let staged = NamedTempFile::new_in(&target_directory).unwrap();
+            std::fs::copy(directory.path().join("Cargo.lock"), staged.path()).unwrap();
+            staged.persist(&lockfile).unwrap();
+        }
+        assert!(
+            output.status.success(),
+            "Tests of the generated crate failed:\n{}\n{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr),
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use zksync_error_codegen::loader::builder::error::ModelBuildingError;
+use zksync_error_model::error::ModelValidationError;
+use zksync_error_model::inner::FieldInitializer;
+use zksync_error_model::inner::Model;
+
+use super::common::Description;
+use super::common::GeneratedCrate;
+use super::common::HALTED;
+use super::common::build_model_from_str;
+use super::common::file;
+use super::common::generate_rust;
+
+fn description_with_conversion(conversion: &str) -> String {
+    Description::default()
+        .with_type(r#"{ "name": "uint", "description": "", "bindings": { "rust": { "expression": "u64" } } }"#)
+        .with_error(
+            r#"{
+                "name": "IOFailure",
+                "code": 1,
+                "message": "I/O failure: {message}",
+                "fields": [ { "name": "message", "type": "string" } ]
+            }"#,
+        )
+        .with_error(
+            r#"{
+                "name": "Failed",
+                "code": 2,
+                "message": "Failed with code {code}",
+                "fields": [ { "name": "code", "type": "uint" } ]
+            }"#,
+        )
+        .with_conversion(conversion)
+        .to_string()
+}
+
+#[test]
+fn test_conversion_is_translated() {
+    let model = build_model_from_str(&description_with_conversion(
+        r#"{ "bindings": { "rust": "std::io::Error" }, "error": "IOFailure", "fields": { "message": "display" }, "feature": "std" }"#,
+    ))
+    .expect("Model should be valid");
+
+    let component = model.components().next().unwrap();
+    assert_eq!(component.conversions.len(), 1);
+    let conversion = &component.conversions[0];
+    assert_eq!(conversion.error, "IOFailure");
+    assert_eq!(conversion.bindings["rust"].expression, "std::io::Error");
+    assert_eq!(conversion.fields["message"], FieldInitializer::Display);
+    assert_eq!(conversion.feature.as_deref(), Some("std"));
+}
+
+#[test]
+fn test_conversion_to_unknown_error() {
+    let result = build_model_from_str(&description_with_conversion(
+        r#"{ "bindings": { "rust": "std::io::Error" }, "error": "Missing" }"#,
+    ));
+    assert!(matches!(
+        result,
+        Err(ModelBuildingError::ModelValidationError(
+            ModelValidationError::InvalidConversion { .. }
+        ))
+    ));
+}
+
+#[test]
+fn test_conversion_with_uninitialized_field() {
+    let result = build_model_from_str(&description_with_conversion(
+        r#"{ "bindings": { "rust": "std::io::Error" }, "error": "IOFailure" }"#,
+    ));
+    assert!(matches!(
+        result,
+        Err(ModelBuildingError::ModelValidationError(
+            ModelValidationError::InvalidConversion { .. }
+        ))
+    ));
+}
+
+#[test]
+fn test_conversion_with_unknown_field() {
+    let result = build_model_from_str(&description_with_conversion(
+        r#"{ "bindings": { "rust": "std::io::Error" }, "error": "IOFailure", "fields": { "message": "display", "extra": "debug" } }"#,
+    ));
+    assert!(matches!(
+        result,
+        Err(ModelBuildingError::ModelValidationError(
+            ModelValidationError::InvalidConversion { .. }
+        ))
+    ));
+}
+
+#[test]
+fn test_conversion_formats_into_non_string_field() {
+    for initializer in ["display", "debug"] {
+        let result = build_model_from_str(&description_with_conversion(&format!(
+            r#"{{ "bindings": {{ "rust": "std::io::Error" }}, "error": "Failed", "fields": {{ "code": "{initializer}" }} }}"#,
+        )));
+        assert!(matches!(
+            result,
+            Err(ModelBuildingError::ModelValidationError(
+                ModelValidationError::InvalidConversion { ref reason, .. }
+            )) if reason.contains("`code`")
+        ));
+    }
+
+    build_model_from_str(&description_with_conversion(
+        r#"{ "bindings": { "rust": "std::io::Error" }, "error": "Failed", "fields": { "code": "default" } }"#,
+    ))
+    .expect("Default initializers are valid for any type");
+}
+
+fn model_with_conversions() -> Model {
+    let description = Description::default()
+        .with_error(HALTED)
+        .with_error(
+            r#"{
+                "name": "Stalled",
+                "code": 2,
+                "message": "Stalled: {reason}",
+                "fields": [ { "name": "reason", "type": "string" } ]
+            }"#,
+        )
+        .with_conversion(
+            r#"{ "bindings": { "rust": "std::io::Error" }, "error": "Halted", "fields": { "reason": "display" }, "feature": "io" }"#,
+        )
+        .with_conversion(
+            r#"{ "bindings": { "rust": "std::num::ParseIntError" }, "error": "Stalled", "fields": { "reason": "debug" } }"#,
+        );
+    description.model()
+}
+
+#[test]
+fn test_conversion_features_are_declared() {
+    let files = generate_rust(
+        &model_with_conversions(),
+        &[("generate_cargo_toml", "true")],
+    )
+    .expect("Generation should succeed");
+
+    let manifest = file(&files, "Cargo.example.toml");
+    assert!(manifest.contains("\nio = []\n"), "{manifest}");
+}
+
+const CONVERSIONS_TEST: &str = r#"
+use zksync_error::core::seq::SequencerError;
+
+#[test]
+fn converts_foreign_errors() {
+    let error = std::io::Error::other("disk full");
+    assert_eq!(
+        SequencerError::from(error),
+        SequencerError::Halted { reason: "disk full".into() }
+    );
+
+    let error = "x".parse::<u32>().unwrap_err();
+    let expected = format!("{error:?}");
+    assert_eq!(
+        SequencerError::from(error),
+        SequencerError::Stalled { reason: expected }
+    );
+}
+"#;
+
+#[test]
+fn test_conversions_compile() {
+    GeneratedCrate::new(&model_with_conversions())
+        .test("conversions", CONVERSIONS_TEST)
+        .cargo_arguments(&["--features", "io"])
+        .run();
+}
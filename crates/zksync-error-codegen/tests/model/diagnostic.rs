@@ -0,0 +1,115 @@
+use zksync_error_codegen::backend::IBackendConfig as _;
+use zksync_error_codegen::backend::file::File;
+use zksync_error_codegen::backend::rust::RustBackendConfig;
+use zksync_error_model::inner::Model;
+
+use super::common::Description;
+use super::common::GeneratedCrate;
+use super::common::file;
+use super::common::generate_rust;
+
+fn model() -> Model {
+    Description::default()
+        .with_error(
+            r#"{
+                "name": "Halted",
+                "code": 3,
+                "message": "Halted: {reason}",
+                "fields": [ { "name": "reason", "type": "string" } ],
+                "doc": {
+                    "description": "The sequencer stopped.",
+                    "summary": "Sequencer halted",
+                    "likely_causes": [
+                        {
+                            "cause": "Out of disk space",
+                            "fixes": [ "Free some space", "Mount a larger disk" ],
+                            "references": [ "https://docs.example.com/halted" ]
+                        }
+                    ]
+                }
+            }"#,
+        )
+        .with_error(r#"{ "name": "Stalled", "code": 1, "message": "Stalled" }"#)
+        .model()
+}
+
+fn generate(arguments: &[(&str, &str)]) -> Vec<File> {
+    generate_rust(&model(), arguments).expect("Generation should succeed")
+}
+
+#[test]
+fn test_diagnostic_impls() {
+    let files = generate(&[("documentation_url", "https://example.com/")]);
+    let diagnostic = file(&files, "src/diagnostic.rs");
+    for expected in [
+        "impl Diagnostic for Sequencer {",
+        "impl Diagnostic for Core {",
+        "impl Diagnostic for ZksyncError {",
+        r#"Sequencer::Halted { .. } => "[core-seq-3]","#,
+        "Some(Severity::Error)",
+        r#"Sequencer::Stalled { .. } => None,"#,
+        // References take precedence over the published documentation.
+        r#"Some(Box::new("https://docs.example.com/halted"))"#,
+        r#"Some(Box::new("https://example.com/errors/11001/"))"#,
+    ] {
+        assert!(diagnostic.contains(expected), "{expected} in {diagnostic}");
+    }
+    assert!(file(&files, "src/lib.rs").contains(r#"#[cfg(feature = "miette")]"#));
+
+    let files = generate(&[("diagnostic_severity", "advice")]);
+    let diagnostic = file(&files, "src/diagnostic.rs");
+    assert!(
+        diagnostic.contains("Some(Severity::Advice)"),
+        "{diagnostic}"
+    );
+    assert!(!diagnostic.contains("https://example.com"), "{diagnostic}");
+
+    assert!(
+        RustBackendConfig::parse_arguments(
+            [("diagnostic_severity".to_owned(), "fatal".to_owned())].into_iter()
+        )
+        .is_err()
+    );
+}
+
+const DIAGNOSTIC_TEST: &str = r#"
+use miette::Diagnostic;
+use miette::Severity;
+use zksync_error::ZksyncError;
+
+fn text(value: Option<Box<dyn std::fmt::Display + '_>>) -> Option<String> {
+    value.map(|value| value.to_string())
+}
+
+#[test]
+fn diagnostics() {
+    let error: ZksyncError = zksync_error::core::seq::Halted { reason: "full".into() }.into();
+    assert_eq!(text(error.code()).as_deref(), Some("[core-seq-3]"));
+    assert_eq!(error.severity(), Some(Severity::Warning));
+    assert_eq!(
+        text(error.help()).as_deref(),
+        Some("Free some space\nMount a larger disk")
+    );
+    assert_eq!(text(error.url()).as_deref(), Some("https://docs.example.com/halted"));
+
+    let error: ZksyncError = zksync_error::core::seq::Stalled.into();
+    assert_eq!(text(error.code()).as_deref(), Some("[core-seq-1]"));
+    assert_eq!(text(error.help()), None);
+    assert_eq!(text(error.url()).as_deref(), Some("https://example.com/errors/11001/"));
+
+    let report = miette::Report::new(error);
+    assert_eq!(report.to_string(), "[core-seq-1] Stalled");
+}
+"#;
+
+#[test]
+fn test_diagnostics() {
+    GeneratedCrate::new(&model())
+        .arguments(&[
+            ("documentation_url", "https://example.com"),
+            ("diagnostic_severity", "warning"),
+        ])
+        .test("diagnostic", DIAGNOSTIC_TEST)
+        .cargo_arguments(&["--features", "miette"])
+        .run();
+}
@@ -0,0 +1,72 @@
+use zksync_error_model::inner::Model;
+
+use super::common::Description;
+use super::common::GeneratedCrate;
+use super::common::HALTED;
+use super::common::file;
+use super::common::generate_rust;
+
+fn model() -> Model {
+    Description::default()
+        .with_type(
+            r#"{ "name": "strings", "description": "", "bindings": { "rust": { "expression": "Vec<String>" } } }"#,
+        )
+        .with_error(HALTED)
+        .with_error(
+            r#"{
+                "name": "Rejected",
+                "code": 2,
+                "message": "Rejected {entries:?}",
+                "fields": [ { "name": "entries", "type": "strings" } ]
+            }"#,
+        )
+        .model()
+}
+
+#[test]
+fn test_display_writes_into_formatter() {
+    let files = generate_rust(&model(), &[]).expect("Generation should succeed");
+
+    let definitions = file(&files, "src/error/definitions.rs");
+    assert!(
+        definitions.contains(r#"write!(f, "[core-seq-1] Halted: {reason}")"#),
+        "{definitions}"
+    );
+    assert!(
+        definitions.contains(r#"write!(f, "[core-seq-2] Rejected {entries:?}")"#),
+        "{definitions}"
+    );
+    assert!(
+        definitions.contains(r#"format!("{self}")"#),
+        "{definitions}"
+    );
+    assert!(!definitions.contains("self.get_message()"), "{definitions}");
+
+    let domains = file(&files, "src/error/domains.rs");
+    assert!(domains.contains("=> component.fmt(f),"), "{domains}");
+    assert!(domains.contains("=> domain_error.fmt(f),"), "{domains}");
+}
+
+const DISPLAY_TEST: &str = r##"
+use zksync_error::IError as _;
+use zksync_error::ZksyncError;
+
+#[test]
+fn display_matches_messages() {
+    let error: ZksyncError = zksync_error::core::seq::Halted { reason: "out of gas".into() }.into();
+    assert_eq!(error.to_string(), "[core-seq-1] Halted: out of gas");
+    assert_eq!(error.get_message(), error.to_string());
+
+    let error: ZksyncError =
+        zksync_error::core::seq::Rejected { entries: vec!["a".into(), "b".into()] }.into();
+    assert_eq!(error.to_string(), r#"[core-seq-2] Rejected ["a", "b"]"#);
+    assert_eq!(error.get_message(), error.to_string());
+}
+"##;
+
+#[test]
+fn test_display_matches_messages() {
+    GeneratedCrate::new(&model())
+        .test("display", DISPLAY_TEST)
+        .run();
+}
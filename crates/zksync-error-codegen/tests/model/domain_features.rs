@@ -0,0 +1,123 @@
+use zksync_error_codegen::backend::file::File;
+use zksync_error_model::inner::Model;
+
+use super::common::GeneratedCrate;
+use super::common::build_model_from_str;
+use super::common::file;
+use super::common::generate_rust;
+
+const DESCRIPTION: &str = r#"{
+    "types": [
+        { "name": "string", "description": "", "bindings": { "rust": { "expression": "String" } } }
+    ],
+    "domains": [
+        {
+            "domain_name": "Core",
+            "domain_code": 1,
+            "identifier_encoding": "core",
+            "components": [
+                {
+                    "component_name": "Sequencer",
+                    "component_code": 1,
+                    "identifier_encoding": "seq",
+                    "errors": [ { "name": "Halted", "code": 1, "message": "Halted" } ]
+                }
+            ]
+        },
+        {
+            "domain_name": "Compiler",
+            "domain_code": 2,
+            "identifier_encoding": "compiler",
+            "components": [
+                {
+                    "component_name": "Solc",
+                    "component_code": 1,
+                    "identifier_encoding": "solc",
+                    "errors": [
+                        { "name": "Wrapped", "code": 1, "message": "Sequencer failed", "fields": [ { "name": "inner", "type": "Sequencer" } ] }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+fn model() -> Model {
+    build_model_from_str(DESCRIPTION).expect("Model should be valid")
+}
+
+fn generate(arguments: &[(&str, &str)]) -> Vec<File> {
+    generate_rust(&model(), arguments).expect("Generation should succeed")
+}
+
+#[test]
+fn test_domain_features() {
+    let files = generate(&[("domain_features", "true"), ("generate_cargo_toml", "true")]);
+    let manifest = file(&files, "Cargo.example.toml");
+    for expected in [
+        r#"default = ["std", "use_anyhow", "use_serde", "domain_compiler", "domain_core"]"#,
+        // Compiler errors wrap sequencer errors.
+        r#"domain_compiler = ["domain_core"]"#,
+        "domain_core = []",
+    ] {
+        assert!(manifest.contains(expected), "{expected} in {manifest}");
+    }
+    let kind = file(&files, "src/kind.rs");
+    assert!(
+        kind.contains(r#"#[cfg(feature = "domain_compiler")]"#),
+        "{kind}"
+    );
+    assert!(
+        kind.contains(r#"(2u32, "compiler", "domain_compiler")"#),
+        "{kind}"
+    );
+
+    let files = generate(&[("generate_cargo_toml", "true")]);
+    for file in &files {
+        assert!(
+            !file.content.contains("domain_core"),
+            "{} is gated by default",
+            file.relative_path.display()
+        );
+    }
+}
+
+const DOMAIN_FEATURES_TEST: &str = r#"
+use std::str::FromStr;
+use zksync_error::Identifier;
+use zksync_error::Kind;
+use zksync_error::ParseIdentifierError;
+
+#[cfg(not(feature = "domain_compiler"))]
+#[test]
+fn disabled_domain() {
+    let disabled = ParseIdentifierError::DisabledDomain { feature: "domain_compiler" };
+    assert_eq!(Identifier::from_str("compiler-solc-1"), Err(disabled.clone()));
+    assert_eq!(Identifier::from_str("21001"), Err(disabled.clone()));
+    assert_eq!(Kind::from_str("compiler-solc"), Err(disabled.clone()));
+    assert_eq!(Kind::from_str("21"), Err(disabled));
+    assert_eq!(Kind::disabled_domain_feature(2), Some("domain_compiler"));
+    assert_eq!(Identifier::from_str("core-seq-9"), Err(ParseIdentifierError::UnknownError));
+    assert!(Identifier::all().all(|identifier| identifier.kind.domain_code() == 1));
+    assert_eq!(Identifier::from_str("11001").unwrap().encode(), 11001);
+}
+
+#[cfg(feature = "domain_compiler")]
+#[test]
+fn dependent_domain() {
+    assert_eq!(Kind::disabled_domain_feature(1), None);
+    assert_eq!(Identifier::from_str("core-seq-1").unwrap().encode(), 11001);
+    assert_eq!(Identifier::from_str("21001").unwrap().encode(), 21001);
+}
+"#;
+
+#[test]
+fn test_domain_features_build() {
+    for features in ["std,domain_core", "std,domain_compiler"] {
+        GeneratedCrate::new(&model())
+            .arguments(&[("domain_features", "true")])
+            .test("domains", DOMAIN_FEATURES_TEST)
+            .cargo_arguments(&["--no-default-features", "--features", features])
+            .run();
+    }
+}
@@ -0,0 +1,106 @@
+use zksync_error_codegen::explain;
+use zksync_error_description::ErrorHierarchy;
+use zksync_error_model::unpacked::flatten;
+
+use super::common::build_model_from_str;
+
+const DESCRIPTION: &str = r#"{
+    "types": [
+        { "name": "string", "description": "", "bindings": { "rust": { "expression": "String" } } }
+    ],
+    "domains": [
+        {
+            "domain_name": "ExecutionPlatform",
+            "domain_code": 1,
+            "identifier_encoding": "ep",
+            "components": [
+                {
+                    "component_name": "EraVM",
+                    "component_code": 2,
+                    "identifier_encoding": "eravm",
+                    "errors": [
+                        {
+                            "name": "Reverted",
+                            "code": 3,
+                            "message": "Reverted: {reason}",
+                            "fields": [ { "name": "reason", "type": "string" } ],
+                            "doc": {
+                                "description": "The call reverted.",
+                                "summary": "Call reverted",
+                                "likely_causes": [
+                                    {
+                                        "cause": "Out of gas",
+                                        "fixes": [ "Raise the gas limit" ],
+                                        "owner": { "name": "vm", "version": "1.0.0" }
+                                    }
+                                ]
+                            }
+                        }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+fn hierarchy() -> ErrorHierarchy {
+    let model = build_model_from_str(DESCRIPTION).expect("Model should be valid");
+    flatten(&model).into()
+}
+
+#[test]
+fn test_explain_find() {
+    let hierarchy = hierarchy();
+    for query in ["12003", "ep-eravm-3", "[EP-EraVM-3]", " 12003 "] {
+        let explanation =
+            explain::find(&hierarchy, query).unwrap_or_else(|| panic!("{query} should be found"));
+        assert_eq!(explanation.error.name, "Reverted");
+        assert_eq!(explanation.encoded_code, 12003);
+    }
+    for query in ["3", "ep-eravm", "Reverted", "ep-eravm-4"] {
+        assert!(
+            explain::find(&hierarchy, query).is_none(),
+            "{query} should not be found"
+        );
+    }
+}
+
+#[test]
+fn test_explain_render() {
+    let hierarchy = hierarchy();
+    let explanation = explain::find(&hierarchy, "12003").expect("Error should be found");
+    let text = explain::render_text(&explanation, false);
+    assert!(
+        text.starts_with("[ep-eravm-3] Reverted (code 12003)\n"),
+        "{text}"
+    );
+    for line in [
+        "Message: [ep-eravm-3] Reverted: {reason}",
+        "Call reverted",
+        "The call reverted.",
+        "  reason: string",
+        "  - Out of gas",
+        "    Fix: Raise the gas limit",
+        "    Owner: vm (version 1.0.0)",
+    ] {
+        assert!(text.contains(&format!("\n{line}\n")), "{line} in {text}");
+    }
+    assert!(!text.contains('\x1b'), "{text}");
+    assert!(explain::render_text(&explanation, true).contains("\x1b[1;31m[ep-eravm-3]\x1b[0m"));
+
+    let json = serde_json::to_value(&explanation).expect("Explanation should serialize");
+    assert_eq!(json["encoded_code"], 12003);
+    assert_eq!(json["identifier"], "[ep-eravm-3]");
+    assert_eq!(
+        json["documentation"]["likely_causes"][0]["fixes"][0],
+        "Raise the gas limit"
+    );
+}
+
+#[test]
+fn test_explain_from_dump() {
+    let dump = serde_json::to_string(&hierarchy().wrap()).expect("Dump should serialize");
+    let hierarchy = ErrorHierarchy::try_deserialize(&dump).expect("Dump should parse");
+    assert!(explain::find(&hierarchy, "ep-eravm-3").is_some());
+    assert!(ErrorHierarchy::try_deserialize("{}").is_err());
+}
@@ -0,0 +1,167 @@
+use zksync_error_model::error::ModelValidationError;
+use zksync_error_model::filter::{ModelFilter, filter};
+use zksync_error_model::inner::Model;
+
+use super::common::GeneratedCrate;
+use super::common::build_model_from_str;
+
+const DESCRIPTION: &str = r#"{
+    "types": [
+        { "name": "string", "description": "", "bindings": { "rust": { "expression": "String" } } }
+    ],
+    "domains": [
+        {
+            "domain_name": "Core",
+            "domain_code": 1,
+            "identifier_encoding": "core",
+            "components": [
+                {
+                    "component_name": "Sequencer",
+                    "component_code": 1,
+                    "identifier_encoding": "seq",
+                    "errors": [
+                        { "name": "Halted", "code": 1, "message": "Halted: {reason}", "fields": [ { "name": "reason", "type": "string" } ] }
+                    ]
+                },
+                {
+                    "component_name": "API",
+                    "component_code": 2,
+                    "identifier_encoding": "api",
+                    "errors": [
+                        { "name": "BadRequest", "code": 3, "message": "Bad request" }
+                    ]
+                }
+            ]
+        },
+        {
+            "domain_name": "Compiler",
+            "domain_code": 2,
+            "identifier_encoding": "compiler",
+            "components": [
+                {
+                    "component_name": "Solc",
+                    "component_code": 1,
+                    "identifier_encoding": "solc",
+                    "errors": [
+                        { "name": "Wrapped", "code": 5, "message": "Sequencer failed", "fields": [ { "name": "inner", "type": "Sequencer" } ] }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+fn model() -> Model {
+    build_model_from_str(DESCRIPTION).expect("Model should be valid")
+}
+
+fn component_names(model: &Model) -> Vec<String> {
+    model
+        .components()
+        .map(|component| component.meta.identifier.name.clone())
+        .collect()
+}
+
+#[test]
+fn test_include_domain_keeps_codes() {
+    let filtered = filter(
+        &model(),
+        &ModelFilter {
+            include_domains: vec!["core".into()],
+            exclude_components: vec!["API".into()],
+            ..Default::default()
+        },
+    )
+    .expect("Filter should be valid");
+
+    assert_eq!(component_names(&filtered), vec!["Sequencer"]);
+    assert!(!filtered.types.contains_key("Compiler"));
+    assert!(!filtered.types.contains_key("API"));
+    let component = filtered.components().next().unwrap();
+    assert_eq!(component.meta.domain.identifier.code, 1);
+    assert_eq!(component.meta.identifier.code, 1);
+}
+
+#[test]
+fn test_exclude_component_by_encoding() {
+    let filtered = filter(
+        &model(),
+        &ModelFilter {
+            exclude_components: vec!["core-api".into()],
+            ..Default::default()
+        },
+    )
+    .expect("Filter should be valid");
+
+    assert_eq!(component_names(&filtered), vec!["Solc", "Sequencer"]);
+    assert_eq!(
+        filtered.components().next().unwrap().errors[0].code,
+        5,
+        "Error codes must not change"
+    );
+}
+
+const STABLE_CODES_TEST: &str = r#"
+use std::str::FromStr;
+use zksync_error::Identifier;
+use zksync_error::Kind;
+
+#[test]
+fn stable_codes() {
+    let halted = Identifier::from_str("core-seq-1").unwrap();
+    assert_eq!(halted.encode(), 11001);
+    let Kind::Core(component) = halted.kind else { panic!("Unexpected kind") };
+    assert_eq!(component as u32, 1);
+    assert_eq!(Identifier::from_str("compiler-solc-5").unwrap().encode(), 21005);
+}
+"#;
+
+#[test]
+fn test_filter_keeps_encoded_codes() {
+    let filtered = filter(
+        &model(),
+        &ModelFilter {
+            exclude_components: vec!["core-api".into()],
+            ..Default::default()
+        },
+    )
+    .expect("Filter should be valid");
+    for model in [model(), filtered] {
+        GeneratedCrate::new(&model)
+            .test("codes", STABLE_CODES_TEST)
+            .run();
+    }
+}
+
+#[test]
+fn test_dropped_type_referenced() {
+    let result = filter(
+        &model(),
+        &ModelFilter {
+            exclude_components: vec!["Sequencer".into()],
+            ..Default::default()
+        },
+    );
+
+    assert!(matches!(
+        result,
+        Err(ModelValidationError::DroppedTypeReferenced { ref error, ref r#type, .. })
+            if error == "Wrapped" && r#type == "Sequencer"
+    ));
+}
+
+#[test]
+fn test_unknown_filter_entry() {
+    let result = filter(
+        &model(),
+        &ModelFilter {
+            include_domains: vec!["Prover".into()],
+            ..Default::default()
+        },
+    );
+
+    assert!(matches!(
+        result,
+        Err(ModelValidationError::UnknownFilterEntry(ref entry)) if entry == "Prover"
+    ));
+}
@@ -0,0 +1,135 @@
+use zksync_error_codegen::backend::file::File;
+use zksync_error_codegen::backend::rust::error::GenerationError;
+use zksync_error_model::inner::Model;
+
+use super::common::Description;
+use super::common::GeneratedCrate;
+use super::common::HALTED;
+use super::common::file;
+use super::common::generate_rust;
+
+pub(super) fn model() -> Model {
+    Description::default()
+        .with_type(
+            r#"{ "name": "Sequencer", "description": "", "bindings": { "rust": { "expression": "Box<Sequencer>" } } }"#,
+        )
+        .with_error(HALTED)
+        .with_error(
+            r#"{
+                "name": "Nested",
+                "code": 2,
+                "message": "Nested {inner} {details}",
+                "fields": [
+                    { "name": "inner", "type": "Sequencer" },
+                    { "name": "details", "type": "string" }
+                ]
+            }"#,
+        )
+        .model()
+}
+
+pub(super) fn generate(arguments: &[(&str, &str)]) -> Vec<File> {
+    generate_rust(&model(), arguments).expect("Generation should succeed")
+}
+
+fn tests_file(files: &[File]) -> Option<&str> {
+    files
+        .iter()
+        .find(|file| file.relative_path.to_str() == Some("tests/generated.rs"))
+        .map(|file| file.content.as_str())
+}
+
+#[test]
+fn test_tests_are_generated_on_demand() {
+    assert!(tests_file(&generate(&[])).is_none());
+
+    let files = generate(&[("generate_tests", "true")]);
+    let content = tests_file(&files).expect("Tests should be generated");
+    assert!(content.contains("fn core_seq_1()"));
+    assert!(content.contains("fn core_seq_2()"));
+    assert!(content.contains(r#"check(error, "[core-seq-2]", false)"#));
+    assert!(content.contains("zksync_error::core::seq::SequencerError::GenericError"));
+}
+
+#[test]
+fn test_generated_tests_build_and_pass() {
+    let files = generate(&[("generate_tests", "true"), ("complete_cargo_toml", "true")]);
+    assert!(
+        file(&files, "Cargo.toml").contains("[dev-dependencies]\nserde_json = "),
+        "The generated tests use serde_json"
+    );
+    assert!(
+        !file(&generate(&[("complete_cargo_toml", "true")]), "Cargo.toml")
+            .contains("[dev-dependencies]")
+    );
+
+    let model = model();
+    GeneratedCrate::new(&model)
+        .arguments(&[("generate_tests", "true")])
+        .run();
+    GeneratedCrate::new(&model)
+        .arguments(&[("generate_tests", "true")])
+        .cargo_arguments(&[
+            "--features",
+            "packed_errors,serialized_errors,runtime_documentation",
+        ])
+        .run();
+}
+
+fn model_with_field_type(binding: &str) -> Model {
+    Description::default()
+        .with_type(&format!(
+            r#"{{ "name": "duration", "description": "", "bindings": {{ "rust": {binding} }} }}"#
+        ))
+        .with_error(
+            r#"{
+                "name": "TimedOut",
+                "code": 1,
+                "message": "Timed out after {elapsed:?}",
+                "fields": [ { "name": "elapsed", "type": "duration" } ]
+            }"#,
+        )
+        .model()
+}
+
+#[test]
+fn test_sample_values() {
+    let generate = |binding: &str| {
+        generate_rust(
+            &model_with_field_type(binding),
+            &[("generate_tests", "true")],
+        )
+    };
+
+    let files = generate(r#"{ "expression": "std::time::Duration", "implements": ["Default"] }"#)
+        .expect("Generation should succeed");
+    let tests = file(&files, "tests/generated.rs");
+    assert!(tests.contains("elapsed: Default::default()"), "{tests}");
+    assert!(!tests.contains("proptest"), "{tests}");
+
+    let files = generate(
+        r#"{ "expression": "std::time::Duration", "strategy": "(0u64..10).prop_map(std::time::Duration::from_secs)" }"#,
+    )
+    .expect("Generation should succeed");
+    let tests = file(&files, "tests/generated.rs");
+    assert!(tests.contains(r#"#[cfg(feature = "proptest")]"#), "{tests}");
+    assert!(tests.contains("TestRunner::deterministic()"), "{tests}");
+    assert!(!tests.contains("elapsed: Default::default()"), "{tests}");
+
+    let result = generate(r#"{ "expression": "std::time::Duration" }"#);
+    assert!(matches!(
+        result,
+        Err(GenerationError::MissingSampleValue { ref r#type }) if r#type == "duration"
+    ));
+}
+
+#[test]
+fn test_sample_values_from_strategies_build() {
+    let model = model_with_field_type(
+        r#"{ "expression": "std::time::Duration", "strategy": "(0u64..10).prop_map(std::time::Duration::from_secs)" }"#,
+    );
+    GeneratedCrate::new(&model)
+        .arguments(&[("generate_tests", "true")])
+        .cargo_arguments(&["--features", "proptest"])
+        .run();
+}
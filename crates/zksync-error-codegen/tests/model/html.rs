@@ -0,0 +1,61 @@
+use zksync_error_codegen::backend::Backend as _;
+use zksync_error_codegen::backend::IBackendConfig as _;
+use zksync_error_codegen::backend::file::File;
+use zksync_error_codegen::backend::html::HtmlBackend;
+use zksync_error_codegen::backend::html::config::HtmlBackendConfig;
+use zksync_error_codegen::backend::html::error::GenerationError;
+
+use super::common::build_model_from_str;
+use super::markdown::DESCRIPTION;
+
+fn generate(arguments: &[(&str, &str)]) -> Result<Vec<File>, GenerationError> {
+    let config = HtmlBackendConfig::parse_arguments(
+        arguments
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    )
+    .expect("Arguments should be valid");
+    let model = build_model_from_str(DESCRIPTION).expect("Model should be valid");
+    HtmlBackend::new(config, &model).generate()
+}
+
+#[test]
+fn test_html_page() {
+    let files = generate(&[("title", "Errors <of> ZKsync"), ("hide_internal", "true")])
+        .expect("Generation should succeed");
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].relative_path.to_str(), Some("errors.html"));
+    let page = &files[0].content;
+    assert!(
+        page.contains("<title>Errors &lt;of&gt; ZKsync</title>"),
+        "{page}"
+    );
+    for element in [
+        "<a href=\"#ep-eravm-3\"><code>[ep-eravm-3] Reverted</code></a>",
+        "<a id=\"ep-eravm-3\"></a>",
+        "<h4>[ep-eravm-3] Reverted</h4>",
+        "<h5>Likely Causes</h5>",
+        "<strong>Possible fix</strong>: Raise the gas limit",
+        "<td style=\"text-align: center\">rust</td><td style=\"text-align: center\">Reverted</td>",
+        "<pre><code>revert(reason)\n",
+    ] {
+        assert!(page.contains(element), "{element} in {page}");
+    }
+    assert!(!page.contains("Secret"), "{page}");
+}
+
+#[test]
+fn test_html_stylesheet() {
+    let directory = tempfile::tempdir().expect("Failed to create temp dir");
+    let stylesheet = directory.path().join("custom.css");
+    std::fs::write(&stylesheet, "body { color: teal; }").unwrap();
+    let files = generate(&[("stylesheet", stylesheet.to_str().unwrap())])
+        .expect("Generation should succeed");
+    assert!(files[0].content.contains("body { color: teal; }"));
+
+    let missing = directory.path().join("missing.css");
+    assert!(matches!(
+        generate(&[("stylesheet", missing.to_str().unwrap())]),
+        Err(GenerationError::Stylesheet { .. })
+    ));
+}
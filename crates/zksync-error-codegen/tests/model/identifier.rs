@@ -0,0 +1,104 @@
+use super::common::GeneratedCrate;
+use super::common::build_model_from_str;
+
+/// Component codes deliberately differ from the positions of the components
+/// in their domains.
+const DESCRIPTION: &str = r#"{
+    "types": [
+        { "name": "string", "description": "", "bindings": { "rust": { "expression": "String" } } }
+    ],
+    "domains": [
+        {
+            "domain_name": "Core",
+            "domain_code": 1,
+            "identifier_encoding": "core",
+            "bindings": { "rust": "Core" },
+            "components": [
+                {
+                    "component_name": "API",
+                    "component_code": 5,
+                    "identifier_encoding": "api",
+                    "bindings": { "rust": "API" },
+                    "errors": [ { "name": "Unavailable", "code": 3, "message": "Unavailable" } ]
+                },
+                {
+                    "component_name": "Sequencer",
+                    "component_code": 2,
+                    "identifier_encoding": "seq",
+                    "bindings": { "rust": "Sequencer" },
+                    "errors": [
+                        { "name": "Halted", "code": 1, "message": "Halted: {reason}", "fields": [ { "name": "reason", "type": "string" } ] }
+                    ]
+                }
+            ]
+        },
+        {
+            "domain_name": "Compiler",
+            "domain_code": 3,
+            "identifier_encoding": "compiler",
+            "bindings": { "rust": "Compiler" },
+            "components": [
+                {
+                    "component_name": "Solc",
+                    "component_code": 4,
+                    "identifier_encoding": "solc",
+                    "bindings": { "rust": "Solc" },
+                    "errors": [ { "name": "Failed", "code": 7, "message": "Failed" } ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+const NUMERIC_CODES_TEST: &str = r#"
+use std::str::FromStr;
+use zksync_error::Identifier;
+use zksync_error::Kind;
+use zksync_error::ParseIdentifierError;
+use zksync_error::StructuredErrorCode;
+
+#[test]
+fn numeric_codes() {
+    let halted = Identifier::from_str("core-seq-1").unwrap();
+    assert_eq!(halted.kind.component_code(), 2);
+    assert_eq!(halted.encode(), 12001);
+    assert_eq!(Identifier::from_str("12001").unwrap(), halted);
+    assert_eq!(Identifier::from_str("[compiler-solc-7]").unwrap().encode(), 34007);
+    assert_eq!(Kind::from_str("12").unwrap(), halted.kind);
+    assert_eq!(Kind::from_str("15").unwrap(), Kind::from_str("core-api").unwrap());
+    assert_eq!(Kind::from_codes(3, 4), Some(Kind::from_str("compiler-solc").unwrap()));
+    assert!(Kind::from_str("10").is_err());
+    assert!(Kind::from_str("11").is_err());
+    assert!(Identifier::from_str("11001").is_err());
+
+    for identifier in Identifier::all() {
+        let code = identifier.encode();
+        assert_eq!(Identifier::decode(StructuredErrorCode::decode(code)), Some(identifier.clone()));
+        assert_eq!(Identifier::from_str(&code.to_string()).unwrap(), identifier);
+        assert_eq!(Kind::from_codes(identifier.kind.domain_code(), identifier.kind.component_code()), Some(identifier.kind));
+    }
+}
+
+#[test]
+fn non_canonical_codes() {
+    for code in ["912001", "1012001", "12012001"] {
+        assert_eq!(Identifier::from_str(code), Err(ParseIdentifierError::UnknownKind), "{code}");
+    }
+    for code in ["012001", "0012001", "core-seq-01", "[core-seq-001]"] {
+        assert_eq!(Identifier::from_str(code), Err(ParseIdentifierError::Malformed), "{code}");
+    }
+    assert_eq!(Kind::from_str("912"), Err(ParseIdentifierError::UnknownKind));
+    for code in ["012", "0012"] {
+        assert_eq!(Kind::from_str(code), Err(ParseIdentifierError::Malformed), "{code}");
+    }
+    assert!(Identifier::from_str("core-seq-0").is_ok());
+}
+"#;
+
+#[test]
+fn test_numeric_codes_round_trip() {
+    let model = build_model_from_str(DESCRIPTION).expect("Model should be valid");
+    GeneratedCrate::new(&model)
+        .test("codes", NUMERIC_CODES_TEST)
+        .run();
+}
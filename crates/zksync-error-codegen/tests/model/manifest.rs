@@ -0,0 +1,161 @@
+use zksync_error_codegen::backend::IBackendConfig as _;
+use zksync_error_codegen::backend::file::File;
+use zksync_error_codegen::backend::rust::RustBackendConfig;
+use zksync_error_codegen::backend::rust::error::GenerationError;
+use zksync_error_model::inner::Model;
+
+use super::common::Description;
+use super::common::GeneratedCrate;
+use super::common::file;
+use super::common::generate_rust;
+
+fn model(hash_binding: &str) -> Model {
+    Description::default()
+        .with_type(
+            r#"{ "name": "map", "description": "", "bindings": { "rust": { "expression": "Vec<(serde_json::Value, String)>" } } }"#,
+        )
+        .with_type(&format!(
+            r#"{{ "name": "hash", "description": "", "bindings": {{ "rust": {hash_binding} }} }}"#
+        ))
+        .with_error(
+            r#"{
+                "name": "Rejected",
+                "code": 1,
+                "message": "Rejected {entries:?} at {hash:?}",
+                "fields": [
+                    { "name": "entries", "type": "map" },
+                    { "name": "hash", "type": "hash" }
+                ]
+            }"#,
+        )
+        .model()
+}
+
+const BYTES_HASH: &str = r#"{ "expression": "[u8; 32]" }"#;
+
+const DECLARED_HASH: &str = r#"{
+    "expression": "H256",
+    "imports": [ "zksync_basic_types::H256" ],
+    "dependency": { "crate": "zksync_basic_types", "version": "0.1", "features": [ "serde" ], "use_serde": true }
+}"#;
+
+const UNDECLARED_HASH: &str = r#"{ "expression": "hashes::H256" }"#;
+
+fn generate(hash_binding: &str, arguments: &[(&str, &str)]) -> Result<Vec<File>, GenerationError> {
+    generate_rust(&model(hash_binding), arguments)
+}
+
+fn parse_arguments(arguments: &[(&str, &str)]) -> bool {
+    RustBackendConfig::parse_arguments(
+        arguments
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    )
+    .is_ok()
+}
+
+#[test]
+fn test_manifest_description_source() {
+    let files = generate(BYTES_HASH, &[("generate_cargo_toml", "true")])
+        .expect("Generation should succeed");
+    assert!(
+        !files
+            .iter()
+            .any(|file| file.relative_path.to_str() == Some("Cargo.toml"))
+    );
+    let manifest = file(&files, "Cargo.example.toml");
+    assert!(manifest.contains(
+        r#"zksync-error-description = { git = "https://github.com/matter-labs/zksync-error", branch = "main", default-features = false }"#
+    ));
+    assert!(manifest.contains("[build-dependencies]"));
+
+    let files = generate(
+        BYTES_HASH,
+        &[
+            ("complete_cargo_toml", "true"),
+            ("description_git", "https://example.com/errors"),
+            ("description_rev", "abc123"),
+        ],
+    )
+    .expect("Generation should succeed");
+    let manifest = file(&files, "Cargo.toml");
+    assert!(manifest.contains(
+        r#"zksync-error-description = { git = "https://example.com/errors", rev = "abc123", default-features = false }"#
+    ));
+    assert!(!manifest.contains("[build-dependencies]"), "{manifest}");
+
+    let files = generate(
+        BYTES_HASH,
+        &[
+            ("complete_cargo_toml", "true"),
+            ("description_version", "0.3"),
+        ],
+    )
+    .expect("Generation should succeed");
+    assert!(
+        file(&files, "Cargo.toml").contains(
+            r#"zksync-error-description = { version = "0.3", default-features = false }"#
+        )
+    );
+
+    assert!(!parse_arguments(&[
+        ("description_path", "../description"),
+        ("description_version", "0.3"),
+    ]));
+    assert!(!parse_arguments(&[
+        ("description_path", "../description"),
+        ("description_rev", "abc123"),
+    ]));
+}
+
+#[test]
+fn test_manifest_dependencies() {
+    let files = generate(DECLARED_HASH, &[("complete_cargo_toml", "true")])
+        .expect("Generation should succeed");
+    let manifest = file(&files, "Cargo.toml");
+    for expected in [
+        // `serde_json` is required by the binding of `map`, not only by
+        // `serialized_errors`.
+        "\nserde_json = { version = \"1.0.128\" }\n",
+        "\nzksync_basic_types = { version = \"0.1\", features = [\"serde\"] }\n",
+        r#"use_serde = ["dep:serde", "zksync_basic_types/serde"]"#,
+        r#"serialized_errors = ["dep:serde"]"#,
+    ] {
+        assert!(manifest.contains(expected), "{expected} in {manifest}");
+    }
+
+    let result = generate(UNDECLARED_HASH, &[("complete_cargo_toml", "true")]);
+    assert!(matches!(
+        result,
+        Err(GenerationError::UnknownDependency { ref crate_name, .. }) if crate_name == "hashes"
+    ));
+
+    let files = generate(
+        UNDECLARED_HASH,
+        &[
+            ("complete_cargo_toml", "true"),
+            ("dependency.hashes", r#"{ path = "../hashes" }"#),
+            ("dependency.zksync_basic_types", "0.2"),
+        ],
+    )
+    .expect("Generation should succeed");
+    let manifest = file(&files, "Cargo.toml");
+    assert!(
+        manifest.contains("\nhashes = { path = \"../hashes\" }\n"),
+        "{manifest}"
+    );
+    assert!(
+        manifest.contains("\nzksync_basic_types = { version = \"0.2\" }\n"),
+        "{manifest}"
+    );
+
+    // Without a complete manifest, undeclared crates are left to the user.
+    let files = generate(UNDECLARED_HASH, &[("generate_cargo_toml", "true")])
+        .expect("Generation should succeed");
+    assert!(file(&files, "Cargo.example.toml").contains("\nhashes = { version = \"*\" }\n"));
+}
+
+#[test]
+fn test_manifest_builds() {
+    GeneratedCrate::new(&model(BYTES_HASH)).run();
+}
@@ -0,0 +1,123 @@
+use zksync_error_codegen::backend::Backend as _;
+use zksync_error_codegen::backend::IBackendConfig as _;
+use zksync_error_codegen::backend::markdown::MarkdownBackend;
+use zksync_error_codegen::backend::markdown::config::MarkdownBackendConfig;
+use zksync_error_model::inner::Model;
+
+use super::common::build_model_from_str;
+
+pub(super) const DESCRIPTION: &str = r#"{
+    "types": [
+        { "name": "string", "description": "", "bindings": { "rust": { "expression": "String" } } }
+    ],
+    "domains": [
+        {
+            "domain_name": "ExecutionPlatform",
+            "domain_code": 1,
+            "identifier_encoding": "ep",
+            "description": "Execution of transactions.",
+            "components": [
+                {
+                    "component_name": "EraVM",
+                    "component_code": 2,
+                    "identifier_encoding": "eravm",
+                    "errors": [
+                        {
+                            "name": "Secret",
+                            "code": 4,
+                            "message": "Secret",
+                            "visibility": "internal"
+                        },
+                        {
+                            "name": "Reverted",
+                            "code": 3,
+                            "message": "Reverted: {reason}",
+                            "fields": [ { "name": "reason", "type": "string" } ],
+                            "doc": {
+                                "description": "The call reverted.\n\n```\nrevert(reason)\n\n\n```",
+                                "summary": "Call reverted",
+                                "likely_causes": [
+                                    {
+                                        "cause": "Out of gas",
+                                        "fixes": [ "Raise the gas limit" ],
+                                        "owner": { "name": "vm", "version": "1.0.0" }
+                                    }
+                                ]
+                            }
+                        }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+fn model() -> Model {
+    build_model_from_str(DESCRIPTION).expect("Model should be valid")
+}
+
+fn generate(arguments: &[(&str, &str)]) -> String {
+    let config = MarkdownBackendConfig::parse_arguments(
+        arguments
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    )
+    .expect("Arguments should be valid");
+    let files = MarkdownBackend::new(config, &model())
+        .generate()
+        .expect("Generation should succeed");
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].relative_path.to_str(), Some("errors.md"));
+    files[0].content.clone()
+}
+
+#[test]
+fn test_markdown_document() {
+    let document = generate(&[("title", "Errors")]);
+    assert!(document.starts_with("# Errors\n"), "{document}");
+    assert!(
+        document.contains("    - [`[ep-eravm-3] Reverted`](#ep-eravm-3)\n"),
+        "{document}"
+    );
+    assert!(
+        document.contains("<a id=\"ep-eravm-3\"></a>\n#### [ep-eravm-3] Reverted\n"),
+        "{document}"
+    );
+    for section in [
+        "##### Short description\nCall reverted",
+        "- `reason : string`",
+        "##### Likely Causes",
+        "   - **Owner**: vm (Version 1.0.0)",
+        "   - **Possible fix**: Raise the gas limit",
+        "| rust | Reverted |",
+    ] {
+        assert!(document.contains(section), "{section} in {document}");
+    }
+    // Blank lines are collapsed, except in code blocks.
+    assert!(
+        document.contains("#### [ep-eravm-3] Reverted\n\n- **Domain**"),
+        "{document}"
+    );
+    assert!(
+        document.contains("```\nrevert(reason)\n\n\n```"),
+        "{document}"
+    );
+}
+
+#[test]
+fn test_markdown_arguments() {
+    let document = generate(&[("hide_internal", "true"), ("table_of_contents", "false")]);
+    assert!(!document.contains("Secret"), "{document}");
+    assert!(!document.contains("](#ep-eravm-3)"), "{document}");
+    assert!(
+        document.contains("#### [ep-eravm-3] Reverted"),
+        "{document}"
+    );
+
+    assert!(
+        MarkdownBackendConfig::parse_arguments(
+            [("grouping".to_owned(), "domain".to_owned())].into_iter()
+        )
+        .is_err()
+    );
+}
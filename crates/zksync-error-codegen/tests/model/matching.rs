@@ -0,0 +1,82 @@
+use super::common::GeneratedCrate;
+use super::generated_tests::generate;
+use super::generated_tests::model;
+
+fn matching_file(arguments: &[(&str, &str)]) -> String {
+    generate(arguments)
+        .into_iter()
+        .find(|file| file.relative_path.to_str() == Some("src/matching.rs"))
+        .expect("Matching helpers should be generated")
+        .content
+}
+
+#[test]
+fn test_matching_helpers() {
+    let content = matching_file(&[]);
+    assert!(content.contains("macro_rules! assert_zksync_error"));
+    assert!(content.contains("impl From<SequencerCode> for Identifier"));
+    assert!(content.contains("impl ErrorMatching for Sequencer"));
+    assert!(content.contains("impl ErrorMatching for Core"));
+    assert!(content.contains("impl ErrorMatching for ZksyncError"));
+}
+
+#[test]
+fn test_assertion_macro_follows_root_type() {
+    let content = matching_file(&[("root_type_name", "AcmeError")]);
+    assert!(content.contains("macro_rules! assert_acme_error"));
+    assert!(content.contains("impl ErrorMatching for AcmeError"));
+
+    let content = matching_file(&[("root_type_name", "APIError")]);
+    assert!(
+        content.contains("macro_rules! assert_api_error"),
+        "{content}"
+    );
+}
+
+const MATCHING_TEST: &str = r#"
+use zksync_error::Kind;
+use zksync_error::ZksyncError;
+use zksync_error::core::CoreCode;
+use zksync_error::core::seq::ErrorCode;
+use zksync_error::matching::ErrorMatching as _;
+
+fn halted() -> ZksyncError {
+    zksync_error::core::seq::Halted { reason: "full".into() }.into()
+}
+
+#[test]
+fn matching() {
+    let error = halted();
+    zksync_error::assert_zksync_error!(error, core::seq::Halted);
+    assert!(error.has_code(ErrorCode::Halted));
+    assert!(!error.has_code(ErrorCode::Nested));
+    assert!(error.is_kind(Kind::Core(CoreCode::Sequencer)));
+
+    let result: Result<(), ZksyncError> = Err(error);
+    zksync_error::assert_zksync_error!(result, core::seq::Halted);
+    assert!(result.has_code(ErrorCode::Halted));
+    assert!(!Ok::<(), ZksyncError>(()).has_code(ErrorCode::Halted));
+
+    let halted = zksync_error::core::seq::Halted { reason: "full".into() };
+    assert!(halted.has_code(ErrorCode::Halted));
+}
+
+#[test]
+#[should_panic(expected = "expected the error [core-seq-2], got [core-seq-1]")]
+fn mismatch() {
+    zksync_error::assert_zksync_error!(halted(), core::seq::Nested);
+}
+
+#[test]
+#[should_panic(expected = "expected the error [core-seq-1], got no known error")]
+fn no_error() {
+    zksync_error::assert_zksync_error!(Ok::<(), ZksyncError>(()), core::seq::Halted);
+}
+"#;
+
+#[test]
+fn test_matching_in_generated_crate() {
+    GeneratedCrate::new(&model())
+        .test("matching", MATCHING_TEST)
+        .run();
+}
@@ -0,0 +1,299 @@
+use zksync_error_codegen::backend::Backend as _;
+use zksync_error_codegen::backend::IBackendConfig as _;
+use zksync_error_codegen::backend::file::File;
+use zksync_error_codegen::backend::mdbook::MDBookBackend;
+use zksync_error_codegen::backend::mdbook::config::MDBookBackendConfig;
+use zksync_error_codegen::backend::mdbook::error::GenerationError;
+use zksync_error_model::inner::Model;
+
+use super::common::GeneratedCrate;
+use super::common::build_model_from_str;
+use super::common::file;
+
+const DESCRIPTION: &str = r#"{
+    "types": [
+        { "name": "string", "description": "", "bindings": { "rust": { "expression": "String" } } }
+    ],
+    "domains": [
+        {
+            "domain_name": "ExecutionPlatform",
+            "domain_code": 1,
+            "identifier_encoding": "ep",
+            "components": [
+                {
+                    "component_name": "EraVM",
+                    "component_code": 2,
+                    "identifier_encoding": "eravm",
+                    "errors": [
+                        {
+                            "name": "Reverted",
+                            "code": 3,
+                            "message": "Reverted: {reason}",
+                            "fields": [ { "name": "reason", "type": "string" } ],
+                            "doc": { "description": "The call reverted.", "summary": "Call reverted" }
+                        }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+fn model() -> Model {
+    build_model_from_str(DESCRIPTION).expect("Model should be valid")
+}
+
+fn generate(model: &Model, arguments: &[(&str, &str)]) -> Result<Vec<File>, GenerationError> {
+    let config = MDBookBackendConfig::parse_arguments(
+        arguments
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    )
+    .expect("Arguments should be valid");
+    MDBookBackend::new(config, model).generate()
+}
+
+fn paths(files: &[File]) -> Vec<&str> {
+    files
+        .iter()
+        .filter_map(|file| file.relative_path.to_str())
+        .collect()
+}
+
+fn error_origins(model: &mut Model, origin: &str) {
+    for domain in model.domains.values_mut() {
+        for component in domain.components.values_mut() {
+            for error in &mut component.errors {
+                error.origins = vec![origin.to_owned()];
+            }
+        }
+    }
+}
+
+#[test]
+fn test_mdbook_book_toml() {
+    let files = generate(
+        &model(),
+        &[
+            ("title", "Errors \"of\" ZKsync"),
+            ("authors", "Alice, Bob"),
+            ("site_url", "/errors/"),
+            ("version_box", "false"),
+        ],
+    )
+    .expect("Generation should succeed");
+    let book = file(&files, "book.toml");
+    assert!(book.contains(r#"title = "Errors \"of\" ZKsync""#), "{book}");
+    assert!(book.contains("\"Alice\",\n    \"Bob\","), "{book}");
+    assert!(book.contains(r#"site-url = "/errors/""#), "{book}");
+    assert!(!book.contains("version-box"), "{book}");
+    assert!(!paths(&files).contains(&"js/version-box.js"));
+}
+
+#[test]
+fn test_mdbook_grouping() {
+    let model = model();
+    let by_error = generate(&model, &[]).expect("Generation should succeed");
+    assert!(paths(&by_error).contains(&"src/domains/ExecutionPlatform/EraVM/Reverted.md"));
+
+    let by_component =
+        generate(&model, &[("grouping", "component")]).expect("Generation should succeed");
+    assert!(!paths(&by_component).contains(&"src/domains/ExecutionPlatform/EraVM/Reverted.md"));
+    let component = file(
+        &by_component,
+        "src/domains/ExecutionPlatform/EraVM/README.md",
+    );
+    assert!(
+        component.contains("<a id=\"ep-eravm-3\"></a>"),
+        "{component}"
+    );
+    assert!(component.contains("### Description"), "{component}");
+
+    let by_domain = generate(&model, &[("grouping", "domain"), ("lookup_table", "true")])
+        .expect("Generation should succeed");
+    assert!(!paths(&by_domain).contains(&"src/domains/ExecutionPlatform/EraVM/README.md"));
+    let domain = file(&by_domain, "src/domains/ExecutionPlatform/README.md");
+    assert!(domain.contains("#### [ep-eravm-3] Reverted"), "{domain}");
+    let lookup = file(&by_domain, "src/lookup.md");
+    assert!(
+        lookup.contains(
+            "| [`[ep-eravm-3]`](domains/ExecutionPlatform/README.md#ep-eravm-3) | 12003 |"
+        ),
+        "{lookup}"
+    );
+    assert!(file(&by_domain, "src/SUMMARY.md").contains("[Error lookup](lookup.md)"));
+}
+
+#[test]
+fn test_mdbook_edit_links() {
+    let mut model = model();
+    error_origins(
+        &mut model,
+        "https://raw.githubusercontent.com/matter-labs/zksync-error/refs/heads/main/zksync-root.json",
+    );
+    let files = generate(&model, &[("edit_links", "true")]).expect("Generation should succeed");
+    let page = file(&files, "src/domains/ExecutionPlatform/EraVM/Reverted.md");
+    assert!(
+        page.contains("[Edit this error](https://github.com/matter-labs/zksync-error/edit/main/zksync-root.json)"),
+        "{page}"
+    );
+
+    error_origins(&mut model, "descriptions/vm.json");
+    let files = generate(
+        &model,
+        &[
+            ("edit_links", "true"),
+            ("repository", "acme/errors"),
+            ("branch", "dev"),
+        ],
+    )
+    .expect("Generation should succeed");
+    let page = file(&files, "src/domains/ExecutionPlatform/EraVM/Reverted.md");
+    assert!(
+        page.contains("(https://github.com/acme/errors/edit/dev/descriptions/vm.json)"),
+        "{page}"
+    );
+    assert!(
+        file(&files, "book.toml")
+            .contains("git-repository-url = \"https://github.com/acme/errors\"")
+    );
+}
+
+#[test]
+fn test_mdbook_template_overrides() {
+    let directory = tempfile::tempdir().expect("Failed to create temp dir");
+    std::fs::write(
+        directory.path().join("error.md"),
+        "Custom page of {{ error.name }}",
+    )
+    .unwrap();
+    let templates = directory.path().to_str().unwrap();
+    let files = generate(&model(), &[("templates", templates)]).expect("Generation should succeed");
+    assert_eq!(
+        file(&files, "src/domains/ExecutionPlatform/EraVM/Reverted.md"),
+        "Custom page of Reverted"
+    );
+
+    std::fs::write(directory.path().join("bogus.md"), "").unwrap();
+    assert!(matches!(
+        generate(&model(), &[("templates", templates)]),
+        Err(GenerationError::UnknownTemplate { .. })
+    ));
+}
+
+#[test]
+fn test_mdbook_versions() {
+    let old = build_model_from_str(&DESCRIPTION.replace("Reverted: {reason}", "Reverted"))
+        .expect("Model should be valid");
+    let config = MDBookBackendConfig::parse_arguments(
+        [
+            ("version", "v2"),
+            ("version.v1", "v1/zksync-root.json"),
+            ("redirects", "true"),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string())),
+    )
+    .expect("Arguments should be valid");
+    let files = MDBookBackend::new(config, &model())
+        .with_versions(vec![("v1".into(), old)])
+        .generate()
+        .expect("Generation should succeed");
+
+    assert_eq!(
+        file(&files, "book/versions.json"),
+        "{\n  \"v2\": \"v2\",\n  \"v1\": \"v1\"\n}\n"
+    );
+    assert!(file(&files, "v1/book.toml").contains("build-dir = \"../book/v1\""));
+    let old_page = file(&files, "v1/src/domains/ExecutionPlatform/EraVM/Reverted.md");
+    assert!(old_page.contains("- **Since**: v1\n"), "{old_page}");
+    let page = file(&files, "v2/src/domains/ExecutionPlatform/EraVM/Reverted.md");
+    assert!(page.contains("- **Since**: v1, changed in v2\n"), "{page}");
+    let generic = file(
+        &files,
+        "v2/src/domains/ExecutionPlatform/EraVM/GenericError.md",
+    );
+    assert!(generic.contains("- **Since**: v1\n"), "{generic}");
+    let redirect = file(&files, "book/errors/12003/index.html");
+    assert!(
+        redirect.contains("URL=../../v2/errors/12003/\""),
+        "{redirect}"
+    );
+}
+
+#[test]
+fn test_mdbook_version_names() {
+    for arguments in [
+        [("version", "v1"), ("version.v1", "v1.json")],
+        [("version", "latest"), ("version.../up", "v1.json")],
+    ] {
+        assert!(
+            MDBookBackendConfig::parse_arguments(
+                arguments
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string())),
+            )
+            .is_err(),
+            "{arguments:?} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_mdbook_error_index_and_redirects() {
+    let files = generate(&model(), &[("error_index", "true"), ("redirects", "true")])
+        .expect("Generation should succeed");
+    let book = file(&files, "book.toml");
+    assert!(
+        book.contains(
+            r#""/errors/12003/index.html" = "../../domains/ExecutionPlatform/EraVM/Reverted.html""#
+        ),
+        "{book}"
+    );
+    assert!(
+        book.contains(
+            r#""/errors/ep-eravm-3/index.html" = "../../domains/ExecutionPlatform/EraVM/Reverted.html""#
+        ),
+        "{book}"
+    );
+    let index = file(&files, "src/errors/README.md");
+    assert!(
+        index.contains(
+            r#"<a href="../domains/ExecutionPlatform/EraVM/Reverted.html"><code>[ep-eravm-3]</code></a></td><td>12003</td>"#
+        ),
+        "{index}"
+    );
+    assert!(file(&files, "src/SUMMARY.md").contains("(errors/README.md)"));
+
+    // Both are opt-in, so that existing books keep their pages.
+    let files = generate(&model(), &[]).expect("Generation should succeed");
+    assert!(!paths(&files).contains(&"src/errors/README.md"));
+    assert!(!file(&files, "book.toml").contains("/errors/12003"));
+    assert!(!file(&files, "src/SUMMARY.md").contains("(errors/README.md)"));
+}
+
+const DOCUMENTATION_URL_TEST: &str = r#"
+use std::str::FromStr;
+use zksync_error::Identifier;
+use zksync_error::documentation::Documented;
+
+#[test]
+fn documentation_url() {
+    let identifier = Identifier::from_str("ep-eravm-3").unwrap();
+    assert_eq!(
+        identifier.get_documentation_url().as_deref(),
+        Some("https://example.com/errors/12003/")
+    );
+    assert_eq!(Identifier::new(identifier.kind, 4).get_documentation_url(), None);
+}
+"#;
+
+#[test]
+fn test_runtime_documentation_url_matches_redirects() {
+    let model = model();
+    GeneratedCrate::new(&model)
+        .arguments(&[("documentation_url", "https://example.com")])
+        .test("documentation", DOCUMENTATION_URL_TEST)
+        .cargo_arguments(&["--features", "runtime_documentation"])
+        .run();
+}
@@ -0,0 +1,25 @@
+mod annotations;
+mod arbitrary;
+mod c;
+mod catalogue;
+mod common;
+mod conversions;
+mod diagnostic;
+mod display;
+mod domain_features;
+mod explain;
+mod filter;
+mod generated_tests;
+mod html;
+mod identifier;
+mod manifest;
+mod markdown;
+mod matching;
+mod mdbook;
+mod naming;
+mod openrpc;
+mod protobuf;
+mod runtime_documentation;
+mod solidity;
+mod type_dependencies;
+mod visibility;
@@ -0,0 +1,75 @@
+use zksync_error_codegen::backend::file::File;
+use zksync_error_model::inner::Model;
+
+use super::common::Description;
+use super::common::GeneratedCrate;
+use super::common::HALTED;
+use super::common::file;
+use super::common::generate_rust;
+
+fn model() -> Model {
+    Description::default().with_error(HALTED).model()
+}
+
+fn generate(arguments: &[(&str, &str)]) -> Vec<File> {
+    generate_rust(&model(), arguments).expect("Generation should succeed")
+}
+
+const ARGUMENTS: [(&str, &str); 3] = [
+    ("root_type_name", "AcmeError"),
+    ("crate_name", "acme-errors"),
+    ("generate_tests", "true"),
+];
+
+#[test]
+fn test_custom_names() {
+    let files = generate(&[ARGUMENTS.as_slice(), &[("generate_cargo_toml", "true")]].concat());
+    for file in &files {
+        assert!(
+            !file.content.contains("ZksyncError") && !file.content.contains("zksync_error ::"),
+            "{} refers to the default names:\n{}",
+            file.relative_path.display(),
+            file.content
+        );
+    }
+    assert!(file(&files, "Cargo.example.toml").contains("name = \"acme-errors\""));
+    assert!(file(&files, "src/error/domains.rs").contains("pub enum AcmeError {"));
+    let lib = file(&files, "src/lib.rs");
+    assert!(
+        lib.contains("acme_errors :: core :: seq :: SequencerError :: GenericError"),
+        "{lib}"
+    );
+    assert!(file(&files, "src/matching.rs").contains("macro_rules! assert_acme_error"));
+    assert!(file(&files, "tests/generated.rs").contains("use acme_errors::AcmeError;"));
+
+    let files = generate(&[
+        ("root_type_name", "AcmeError"),
+        ("crate_path", "::acme::errors"),
+    ]);
+    let lib = file(&files, "src/lib.rs");
+    assert!(
+        lib.contains(":: acme :: errors :: core :: seq :: SequencerError :: GenericError"),
+        "{lib}"
+    );
+}
+
+const NAMING_TEST: &str = r#"
+use acme_errors::AcmeError;
+use acme_errors::IError as _;
+
+#[test]
+fn custom_names() {
+    let error: AcmeError = acme_errors::core_seq_generic_error!("code {}", 7).into();
+    assert_eq!(error.get_message(), "[core-seq-0] Generic error: code 7");
+    let error: AcmeError = acme_errors::core::seq::Halted { reason: "full".into() }.into();
+    acme_errors::assert_acme_error!(error, core::seq::Halted);
+}
+"#;
+
+#[test]
+fn test_custom_names_build() {
+    GeneratedCrate::new(&model())
+        .arguments(&ARGUMENTS)
+        .test("naming", NAMING_TEST)
+        .run();
+}
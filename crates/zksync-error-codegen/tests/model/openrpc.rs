@@ -0,0 +1,107 @@
+use serde_json::Value;
+use serde_json::json;
+use zksync_error_codegen::backend::Backend as _;
+use zksync_error_codegen::backend::IBackendConfig as _;
+use zksync_error_codegen::backend::openrpc::OpenRpcBackend;
+use zksync_error_codegen::backend::openrpc::config::OpenRpcBackendConfig;
+
+use super::common::build_model_from_str;
+
+const DESCRIPTION: &str = r#"{
+    "types": [
+        { "name": "string", "description": "", "bindings": {
+            "rust": { "expression": "String" },
+            "json_schema": { "expression": "string" }
+        } },
+        { "name": "hash", "description": "256-bit hash", "bindings": {
+            "rust": { "expression": "String" },
+            "json_schema": { "expression": "{\"type\": \"string\", \"pattern\": \"^0x[0-9a-f]{64}$\"}" }
+        } },
+        { "name": "opaque", "description": "Anything", "bindings": { "rust": { "expression": "String" } } }
+    ],
+    "domains": [
+        {
+            "domain_name": "ExecutionPlatform",
+            "domain_code": 1,
+            "identifier_encoding": "ep",
+            "components": [
+                {
+                    "component_name": "EraVM",
+                    "component_code": 2,
+                    "identifier_encoding": "eravm",
+                    "errors": [
+                        {
+                            "name": "Reverted",
+                            "code": 3,
+                            "message": "Reverted at {hash}: {reason}",
+                            "fields": [
+                                { "name": "hash", "type": "hash" },
+                                { "name": "reason", "type": "opaque" }
+                            ],
+                            "doc": { "description": "The call reverted.", "summary": "Call reverted" }
+                        },
+                        {
+                            "name": "Secret",
+                            "code": 4,
+                            "message": "Secret",
+                            "visibility": "internal"
+                        }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+fn generate(arguments: &[(&str, &str)]) -> Value {
+    let model = build_model_from_str(DESCRIPTION).expect("Model should be valid");
+    let config = OpenRpcBackendConfig::parse_arguments(
+        arguments
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    )
+    .expect("Arguments should be valid");
+    let files = OpenRpcBackend::new(config, &model)
+        .generate()
+        .expect("Generation should succeed");
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].relative_path.to_str(), Some("openrpc-errors.json"));
+    serde_json::from_str(&files[0].content).expect("The export should be valid JSON")
+}
+
+#[test]
+fn test_openrpc_error_objects() {
+    let document = generate(&[]);
+    let errors = &document["components"]["errors"];
+    assert_eq!(
+        errors["ExecutionPlatform.EraVM.Reverted"],
+        json!({
+            "code": 12003,
+            "message": "Reverted at {hash}: {reason}",
+            "x-identifier": "[ep-eravm-3]",
+            "x-summary": "Call reverted",
+            "x-data-schema": {
+                "type": "object",
+                "properties": {
+                    "hash": { "type": "string", "pattern": "^0x[0-9a-f]{64}$" },
+                    "reason": { "description": "Anything" }
+                },
+                "required": ["hash", "reason"],
+                "additionalProperties": false
+            }
+        })
+    );
+    assert_eq!(
+        errors["ExecutionPlatform.EraVM.GenericError"]["x-data-schema"]["properties"]["message"],
+        json!({ "type": "string" })
+    );
+    assert!(errors.get("ExecutionPlatform.EraVM.Secret").is_some());
+}
+
+#[test]
+fn test_openrpc_hide_internal() {
+    let document = generate(&[("hide_internal", "true")]);
+    let errors = &document["components"]["errors"];
+    assert!(errors.get("ExecutionPlatform.EraVM.Reverted").is_some());
+    assert!(errors.get("ExecutionPlatform.EraVM.Secret").is_none());
+}
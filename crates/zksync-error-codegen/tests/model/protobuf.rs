@@ -0,0 +1,130 @@
+use zksync_error_codegen::backend::Backend as _;
+use zksync_error_codegen::backend::IBackendConfig as _;
+use zksync_error_codegen::backend::protobuf::ProtobufBackend;
+use zksync_error_codegen::backend::protobuf::config::ProtobufBackendConfig;
+
+use super::common::build_model_from_str;
+use super::common::file;
+use super::common::generate_rust;
+
+const DESCRIPTION: &str = r#"{
+    "types": [
+        { "name": "string", "description": "", "bindings": {
+            "rust": { "expression": "String" },
+            "protobuf": { "expression": "string" }
+        } },
+        { "name": "hash", "description": "", "bindings": {
+            "rust": { "expression": "Box<hashes::H256>" },
+            "protobuf": {
+                "expression": "bytes",
+                "to_proto": "value.as_bytes().to_vec()",
+                "from_proto": "<[u8; 32]>::try_from(value.as_slice()).map(|bytes| Box::new(hashes::H256::from(bytes)))"
+            }
+        } },
+        { "name": "map", "description": "", "bindings": { "rust": { "expression": "Vec<(String, String)>" } } }
+    ],
+    "domains": [
+        {
+            "domain_name": "ExecutionPlatform",
+            "domain_code": 1,
+            "identifier_encoding": "ep",
+            "components": [
+                {
+                    "component_name": "EraVM",
+                    "component_code": 1,
+                    "identifier_encoding": "eravm",
+                    "bindings": { "protobuf": "EraVm" },
+                    "errors": [
+                        {
+                            "name": "Reverted",
+                            "code": 1,
+                            "message": "Reverted at {hash}: {reason}",
+                            "fields": [
+                                { "name": "hash", "type": "hash" },
+                                { "name": "reason", "type": "string" }
+                            ]
+                        },
+                        {
+                            "name": "Mapped",
+                            "code": 2,
+                            "message": "Mapped {entries:?}",
+                            "fields": [ { "name": "entries", "type": "map" } ]
+                        }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+#[test]
+fn test_protobuf_schema() {
+    let model = build_model_from_str(DESCRIPTION).expect("Model should be valid");
+    let config = ProtobufBackendConfig::parse_arguments(
+        [("package".to_owned(), "acme.errors.v1".to_owned())].into_iter(),
+    )
+    .expect("Arguments should be valid");
+    let files = ProtobufBackend::new(config, &model)
+        .generate()
+        .expect("Generation should succeed");
+
+    let domain = file(&files, "acme/errors/v1/ep.proto");
+    assert!(domain.contains("package acme.errors.v1;"));
+    assert!(domain.contains(
+        "enum EraVmErrorCode {
+  // [ep-eravm-0] Generic error: {message}
+  ERA_VM_ERROR_CODE_GENERIC_ERROR = 0;"
+    ));
+    assert!(domain.contains("  ERA_VM_ERROR_CODE_MAPPED = 2;"));
+    assert!(domain.contains(
+        "message EraVmReverted {
+  bytes hash = 1;
+  string reason = 2;
+}"
+    ));
+    assert!(domain.contains(
+        "// [ep-eravm-2] Mapped has no message: the type `map` of its field `entries` has no protobuf binding."
+    ));
+
+    let detail = file(&files, "acme/errors/v1/detail.proto");
+    assert!(detail.contains("import \"acme/errors/v1/ep.proto\";"));
+    assert!(detail.contains("    EraVmReverted era_vm_reverted = 111001;"));
+    assert!(detail.contains("    EraVmGenericError era_vm_generic_error = 111000;"));
+    assert!(!detail.contains("Mapped"));
+}
+
+#[test]
+fn test_protobuf_rejects_non_scalar_types() {
+    let description = DESCRIPTION.replace(
+        r#""protobuf": { "expression": "string" }"#,
+        r#""protobuf": { "expression": "google.protobuf.Any" }"#,
+    );
+    let model = build_model_from_str(&description).expect("Model should be valid");
+    let result = ProtobufBackend::new(ProtobufBackendConfig::default(), &model).generate();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_protobuf_conversions() {
+    let model = build_model_from_str(DESCRIPTION).expect("Model should be valid");
+    let files = generate_rust(&model, &[("generate_cargo_toml", "true")])
+        .expect("Generation should succeed");
+
+    let protobuf = file(&files, "src/protobuf.rs");
+    assert!(protobuf.contains("pub enum EraVmErrorCode {"));
+    assert!(protobuf.contains("#[prost(bytes, tag = \"1\")]"));
+    assert!(protobuf.contains("EraVmReverted(super::EraVmReverted)"));
+    assert!(protobuf.contains("value.as_bytes().to_vec()"));
+    assert!(protobuf.contains("reason: convert(fields.reason, \"Reverted\", \"reason\")?"));
+    assert!(!protobuf.contains("EraVmMapped"));
+    // Codes are the encoded codes of the model, not derived from positions.
+    assert!(protobuf.contains("=> 11002u32,"), "{protobuf}");
+    assert!(
+        protobuf.contains(".into(),\n                11001u32,\n"),
+        "{protobuf}"
+    );
+
+    let manifest = file(&files, "Cargo.example.toml");
+    assert!(manifest.contains(r#"protobuf = ["dep:prost"]"#));
+    assert!(manifest.contains(r#"hashes = { version = "*" }"#));
+}
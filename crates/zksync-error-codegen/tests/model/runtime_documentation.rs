@@ -0,0 +1,100 @@
+use zksync_error_model::inner::Model;
+
+use super::common::Description;
+use super::common::GeneratedCrate;
+use super::common::file;
+use super::common::generate_rust;
+
+fn model() -> Model {
+    Description::default()
+        .with_error(
+            r#"{
+                "name": "Halted",
+                "code": 3,
+                "message": "Halted: {reason}",
+                "fields": [ { "name": "reason", "type": "string" } ],
+                "doc": {
+                    "description": "The sequencer stopped.",
+                    "summary": "Sequencer halted",
+                    "likely_causes": [
+                        {
+                            "cause": "Out of disk space",
+                            "fixes": [ "Free some space" ],
+                            "owner": { "name": "seq", "version": "1.0.0" }
+                        }
+                    ]
+                }
+            }"#,
+        )
+        .with_error(r#"{ "name": "Stalled", "code": 1, "message": "Stalled" }"#)
+        .model()
+}
+
+#[test]
+fn test_documentation_tables() {
+    let files = generate_rust(&model(), &[]).expect("Generation should succeed");
+
+    let documentation = file(&files, "src/documentation.rs");
+    assert!(
+        documentation.contains("static CORE_SEQUENCER: &[(u32, Option<&ErrorDocumentation>)]"),
+        "{documentation}"
+    );
+    // Tables are sorted by error code for binary search.
+    let positions: Vec<_> = ["(0u32, None)", "(1u32, None)", "3u32,"]
+        .iter()
+        .map(|entry| {
+            documentation
+                .find(entry)
+                .expect("Entry should be generated")
+        })
+        .collect();
+    assert!(positions.is_sorted(), "{documentation}");
+    assert!(documentation.contains(r#"summary: "Sequencer halted""#));
+    assert!(documentation.contains(r#"fixes: &["Free some space"]"#));
+    assert!(!documentation.contains("serde_json"), "{documentation}");
+    assert!(!documentation.contains("lazy_static"), "{documentation}");
+}
+
+const DOCUMENTATION_TEST: &str = r#"
+use zksync_error::IError as _;
+use zksync_error::Identifier;
+use zksync_error::ZksyncError;
+use zksync_error::documentation::Documented as _;
+
+#[test]
+fn documentation_lookup() {
+    let error: ZksyncError = zksync_error::core::seq::Halted { reason: "full".into() }.into();
+    let documentation = error.get_documentation().unwrap().expect("Halted is documented");
+    assert_eq!(documentation.summary, "Sequencer halted");
+    assert_eq!(documentation.description, "The sequencer stopped.");
+    let cause = &documentation.likely_causes[0];
+    assert_eq!(cause.cause, "Out of disk space");
+    assert_eq!(cause.fixes, &["Free some space"]);
+    assert_eq!(cause.owner.as_ref().map(|owner| owner.name), Some("seq"));
+
+    let stalled: ZksyncError = zksync_error::core::seq::Stalled.into();
+    assert!(stalled.get_documentation().unwrap().is_none());
+
+    let unknown = Identifier::new(error.get_identifier().kind, 2);
+    assert!(zksync_error::documentation::lookup(&unknown).is_err());
+}
+"#;
+
+#[test]
+fn test_documentation_lookup() {
+    let model = model();
+    for cargo_arguments in [
+        &["--features", "runtime_documentation"][..],
+        // The tables need neither `std` nor `serde_json`.
+        &[
+            "--no-default-features",
+            "--features",
+            "runtime_documentation",
+        ],
+    ] {
+        GeneratedCrate::new(&model)
+            .test("documentation", DOCUMENTATION_TEST)
+            .cargo_arguments(cargo_arguments)
+            .run();
+    }
+}
@@ -0,0 +1,149 @@
+use zksync_error_codegen::backend::Backend as _;
+use zksync_error_codegen::backend::IBackendConfig as _;
+use zksync_error_codegen::backend::solidity::SolidityBackend;
+use zksync_error_codegen::backend::solidity::config::SolidityBackendConfig;
+
+use super::common::Description;
+use super::common::GeneratedCrate;
+use super::common::HALTED;
+use super::common::build_model_from_str;
+use super::common::file;
+use super::common::generate_rust;
+
+const DESCRIPTION: &str = r#"{
+    "types": [
+        { "name": "string", "description": "", "bindings": {
+            "rust": { "expression": "String" },
+            "solidity": { "expression": "string" }
+        } },
+        { "name": "hash", "description": "", "bindings": {
+            "rust": { "expression": "Box<hashes::H256>" },
+            "solidity": { "expression": "bytes32", "from_abi": "Box::new(hashes::H256::from(value.0))" }
+        } },
+        { "name": "map", "description": "", "bindings": { "rust": { "expression": "Vec<(String, String)>" } } }
+    ],
+    "domains": [
+        {
+            "domain_name": "ExecutionPlatform",
+            "domain_code": 1,
+            "identifier_encoding": "ep",
+            "components": [
+                {
+                    "component_name": "EraVM",
+                    "component_code": 1,
+                    "identifier_encoding": "eravm",
+                    "bindings": { "solidity": "EraVm" },
+                    "errors": [
+                        {
+                            "name": "Reverted",
+                            "code": 1,
+                            "message": "Reverted at {hash}: {reason}",
+                            "fields": [
+                                { "name": "hash", "type": "hash" },
+                                { "name": "reason", "type": "string" }
+                            ],
+                            "bindings": { "solidity": { "name": "EraVmReverted" } }
+                        },
+                        {
+                            "name": "Mapped",
+                            "code": 2,
+                            "message": "Mapped {entries:?}",
+                            "fields": [ { "name": "entries", "type": "map" } ]
+                        }
+                    ]
+                }
+            ]
+        }
+    ]
+}"#;
+
+#[test]
+fn test_solidity_declarations() {
+    let model = build_model_from_str(DESCRIPTION).expect("Model should be valid");
+    let config = SolidityBackendConfig::parse_arguments(
+        [("pragma".to_owned(), "^0.8.20".to_owned())].into_iter(),
+    )
+    .expect("Arguments should be valid");
+    let files = SolidityBackend::new(config, &model)
+        .generate()
+        .expect("Generation should succeed");
+
+    let content = file(&files, "ExecutionPlatform/IEraVmErrors.sol");
+    assert!(content.contains("pragma solidity ^0.8.20;"));
+    assert!(content.contains("interface IEraVmErrors {"));
+    assert!(content.contains("/// @notice [ep-eravm-1] Reverted at {hash}: {reason}"));
+    assert!(content.contains("error EraVmReverted(bytes32 hash, string reason);"));
+    assert!(content.contains("error GenericEraVmError(string message);"));
+    assert!(content.contains(
+        "// [ep-eravm-2] Mapped is not declared: the type `map` of its field `entries` has no Solidity binding."
+    ));
+}
+
+#[test]
+fn test_revert_decoder() {
+    let model = build_model_from_str(DESCRIPTION).expect("Model should be valid");
+    let files = generate_rust(&model, &[("generate_cargo_toml", "true")])
+        .expect("Generation should succeed");
+
+    let revert = file(&files, "src/revert.rs");
+    assert!(revert.contains("interface IEraVmErrors"));
+    assert!(revert.contains("error EraVmReverted (bytes32 hash , string reason) ;"));
+    assert!(revert.contains("Decoded::EraVmReverted(error) => EraVM::Reverted {"));
+    assert!(revert.contains("Box::new(hashes::H256::from(value.0))"));
+    assert!(!revert.contains("Mapped"));
+
+    let manifest = file(&files, "Cargo.example.toml");
+    assert!(manifest.contains(r#"revert_decoding = ["dep:alloy-sol-types"]"#));
+    assert!(manifest.contains(r#"hashes = { version = "*" }"#));
+}
+
+const REVERT_DECODING_TEST: &str = r#"
+use std::str::FromStr as _;
+
+use alloy_sol_types::SolError as _;
+use zksync_error::Kind;
+use zksync_error::ZksyncError;
+use zksync_error::core::seq::SequencerError;
+use zksync_error::revert::FromRevert as _;
+use zksync_error::revert::RevertDecodingError;
+use zksync_error::revert::decode;
+
+alloy_sol_types::sol! {
+    error Halted(string reason);
+    error GenericSequencerError(string message);
+    error GenericError(string message);
+}
+
+#[test]
+fn decodes_abi_payloads() {
+    let data = Halted { reason: "stalled".into() }.abi_encode();
+    let expected = SequencerError::Halted { reason: "stalled".into() };
+    assert_eq!(SequencerError::from_revert(&data), Ok(expected.clone()));
+    let kind = Kind::from_str("core-seq").unwrap();
+    assert_eq!(decode(kind, &data), Ok(ZksyncError::from(expected)));
+
+    let data = GenericSequencerError { message: "failed".into() }.abi_encode();
+    assert_eq!(
+        SequencerError::from_revert(&data),
+        Ok(SequencerError::GenericError { message: "failed".into() })
+    );
+}
+
+#[test]
+fn rejects_selectors_of_other_components() {
+    let data = GenericError { message: "failed".into() }.abi_encode();
+    assert!(matches!(
+        SequencerError::from_revert(&data),
+        Err(RevertDecodingError::InvalidData(_))
+    ));
+}
+"#;
+
+#[test]
+fn test_revert_decoding_compiles() {
+    let model = Description::default().with_error(HALTED).model();
+    GeneratedCrate::new(&model)
+        .test("revert_decoding", REVERT_DECODING_TEST)
+        .cargo_arguments(&["--features", "revert_decoding"])
+        .run();
+}
@@ -0,0 +1,62 @@
+use zksync_error_codegen::loader::builder::error::ModelBuildingError;
+use zksync_error_model::error::ModelValidationError;
+
+use super::common::Description;
+use super::common::build_model_from_str;
+
+const H256: &str = r#"{ "name": "H256", "description": "", "bindings": { "rust": {
+    "expression": "H256",
+    "imports": [ "zksync_basic_types::H256" ],
+    "dependency": { "crate": "zksync_basic_types", "version": "0.1", "features": [ "serde" ], "use_serde": true }
+} } }"#;
+
+#[test]
+fn test_type_dependency_is_translated() {
+    let model = build_model_from_str(&Description::default().with_type(H256).to_string())
+        .expect("Model should be valid");
+
+    let binding = &model.types["H256"].bindings["rust"];
+    assert_eq!(binding.imports, vec!["zksync_basic_types::H256".to_owned()]);
+    let dependency = binding
+        .dependency
+        .as_ref()
+        .expect("Dependency should be set");
+    assert_eq!(dependency.name, "zksync_basic_types");
+    assert_eq!(dependency.version, "0.1");
+    assert_eq!(dependency.features, vec!["serde".to_owned()]);
+    assert!(dependency.use_serde);
+}
+
+#[test]
+fn test_conflicting_package_versions() {
+    let description = Description::default().with_type(H256).with_type(
+        r#"{ "name": "H160", "description": "", "bindings": { "rust": {
+            "expression": "zksync_basic_types::H160",
+            "dependency": { "crate": "zksync_basic_types", "version": "0.2" }
+        } } }"#,
+    );
+    let result = build_model_from_str(&description.to_string());
+    assert!(matches!(
+        result,
+        Err(ModelBuildingError::ModelValidationError(
+            ModelValidationError::ConflictingTypeRequirements { .. }
+        ))
+    ));
+}
+
+#[test]
+fn test_clashing_imports() {
+    let description = Description::default().with_type(H256).with_type(
+        r#"{ "name": "OtherH256", "description": "", "bindings": { "rust": {
+            "expression": "H256",
+            "imports": [ "other_types::H256" ]
+        } } }"#,
+    );
+    let result = build_model_from_str(&description.to_string());
+    assert!(matches!(
+        result,
+        Err(ModelBuildingError::ModelValidationError(
+            ModelValidationError::ConflictingTypeRequirements { .. }
+        ))
+    ));
+}
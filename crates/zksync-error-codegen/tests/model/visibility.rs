@@ -0,0 +1,87 @@
+use zksync_error_codegen::loader::builder::error::ModelBuildingError;
+use zksync_error_model::error::ModelValidationError;
+use zksync_error_model::inner::Visibility;
+use zksync_error_model::unpacked::flatten;
+
+use super::common::build_model_from_str;
+
+fn description(wrapper_visibility: &str) -> String {
+    format!(
+        r#"{{
+        "types": [
+            {{ "name": "string", "description": "", "bindings": {{ "rust": {{ "expression": "String" }} }} }}
+        ],
+        "domains": [
+            {{
+                "domain_name": "Core",
+                "domain_code": 1,
+                "identifier_encoding": "core",
+                "components": [
+                    {{
+                        "component_name": "Sequencer",
+                        "component_code": 1,
+                        "identifier_encoding": "seq",
+                        "errors": [
+                            {{ "name": "Halted", "code": 1, "message": "Halted" }},
+                            {{ "name": "Scratch", "code": 2, "message": "Scratch", "visibility": "internal" }},
+                            {{ "name": "Wrapper", "code": 3, "message": "Wrapper", "visibility": "{wrapper_visibility}", "fields": [ {{ "name": "inner", "type": "Mempool" }} ] }}
+                        ]
+                    }},
+                    {{
+                        "component_name": "Mempool",
+                        "component_code": 2,
+                        "identifier_encoding": "mempool",
+                        "visibility": "internal",
+                        "errors": [
+                            {{ "name": "Full", "code": 1, "message": "Full" }}
+                        ]
+                    }}
+                ]
+            }}
+        ]
+    }}"#
+    )
+}
+
+#[test]
+fn test_visibility_is_inherited() {
+    let model = build_model_from_str(&description("internal")).expect("Model should be valid");
+
+    let visibility = |name: &str| {
+        model
+            .errors()
+            .find(|error| error.name == name)
+            .unwrap()
+            .effective_visibility()
+    };
+    assert_eq!(visibility("Halted"), Visibility::Public);
+    assert_eq!(visibility("Scratch"), Visibility::Internal);
+    assert_eq!(visibility("Full"), Visibility::Internal);
+}
+
+#[test]
+fn test_public_error_exposes_internal_component() {
+    let result = build_model_from_str(&description("public"));
+    assert!(matches!(
+        result,
+        Err(ModelBuildingError::ModelValidationError(
+            ModelValidationError::InternalTypeExposed { ref error, ref r#type, .. }
+        )) if error == "Wrapper" && r#type == "Mempool"
+    ));
+}
+
+#[test]
+fn test_unpacked_model_without_internal() {
+    let model = build_model_from_str(&description("internal")).expect("Model should be valid");
+    let public = flatten(&model).without_internal();
+
+    assert_eq!(
+        public.components.keys().collect::<Vec<_>>(),
+        vec!["Sequencer"]
+    );
+    assert_eq!(public.domains["Core"].components, vec!["Sequencer"]);
+    assert!(!public.types.contains_key("Mempool"));
+    let mut errors: Vec<_> = public.errors.values().map(|e| e.name.as_str()).collect();
+    errors.sort();
+    assert_eq!(errors, vec!["GenericError", "Halted"]);
+}
@@ -31,6 +31,15 @@ impl From<inner::TypeDescription> for model::TypeDescription {
     }
 }
 
+impl From<zksync_error_model::inner::Visibility> for model::Visibility {
+    fn from(val: zksync_error_model::inner::Visibility) -> Self {
+        match val {
+            zksync_error_model::inner::Visibility::Public => model::Visibility::Public,
+            zksync_error_model::inner::Visibility::Internal => model::Visibility::Internal,
+        }
+    }
+}
+
 impl From<inner::DomainMetadata> for model::DomainMetadata {
     fn from(val: inner::DomainMetadata) -> Self {
         let inner::DomainMetadata {
@@ -43,6 +52,7 @@ impl From<inner::DomainMetadata> for model::DomainMetadata {
                     encoding,
                 },
             description,
+            visibility,
             origins,
         } = val;
         model::DomainMetadata {
@@ -50,6 +60,7 @@ impl From<inner::DomainMetadata> for model::DomainMetadata {
             bindings,
             identifier: encoding.to_owned(),
             description,
+            visibility: visibility.into(),
             name,
             code,
             origins: origins.clone(),
@@ -86,6 +97,7 @@ impl From<inner::ComponentMetadata> for model::ComponentMetadata {
                     encoding,
                 },
             description,
+            visibility,
             origins,
         } = val;
         model::ComponentMetadata {
@@ -94,6 +106,7 @@ impl From<inner::ComponentMetadata> for model::ComponentMetadata {
             domain_name,
             bindings,
             description,
+            visibility: visibility.into(),
             identifier: encoding.to_owned(),
             origins,
         }
@@ -112,9 +125,11 @@ impl From<inner::ErrorDescription> for model::ErrorDescription {
             fields,
             documentation,
             bindings,
+            visibility,
             origins,
         } = val;
         model::ErrorDescription {
+            visibility: visibility.into(),
             domain,
             component,
             name,
@@ -1,17 +1,35 @@
+#![cfg_attr(not(feature = "hierarchy"), no_std)]
+
+#[cfg(feature = "hierarchy")]
 pub mod identifier;
+#[cfg(feature = "hierarchy")]
 pub(crate) mod model;
+pub mod static_model;
 
 #[cfg(feature = "with_adapter")]
 pub mod adapter;
 
+#[cfg(feature = "hierarchy")]
 pub use model::ComponentMetadata;
+#[cfg(feature = "hierarchy")]
 pub use model::DomainMetadata;
+#[cfg(feature = "hierarchy")]
 pub use model::ErrorDescription;
+#[cfg(feature = "hierarchy")]
 pub use model::ErrorDocumentation;
+#[cfg(feature = "hierarchy")]
 pub use model::ErrorHierarchy;
+#[cfg(feature = "hierarchy")]
 pub use model::FieldDescription;
+#[cfg(feature = "hierarchy")]
 pub use model::LikelyCause;
+#[cfg(feature = "hierarchy")]
 pub use model::TargetLanguageType;
+#[cfg(feature = "hierarchy")]
 pub use model::TypeDescription;
+#[cfg(feature = "hierarchy")]
 pub use model::TypeMetadata;
+#[cfg(feature = "hierarchy")]
 pub use model::VersionedOwner;
+#[cfg(feature = "hierarchy")]
+pub use model::Visibility;
@@ -34,6 +34,26 @@ pub struct TypeDescription {
     pub bindings: BTreeMap<LanguageName, TargetLanguageType>,
 }
 
+/// Whether a domain, component or error is a part of the public API.
+#[derive(
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Clone,
+    Copy,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    #[default]
+    Public,
+    Internal,
+}
+
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DomainMetadata {
@@ -43,6 +63,8 @@ pub struct DomainMetadata {
     pub bindings: BTreeMap<LanguageName, String>,
     pub identifier: String,
     pub description: String,
+    #[serde(default)]
+    pub visibility: Visibility,
     pub origins: Origins,
 }
 #[non_exhaustive]
@@ -63,6 +85,8 @@ pub struct ComponentMetadata {
     pub bindings: BTreeMap<LanguageName, String>,
     pub identifier: String,
     pub description: String,
+    #[serde(default)]
+    pub visibility: Visibility,
     pub origins: Origins,
 }
 
@@ -78,6 +102,8 @@ pub struct ErrorDescription {
     pub fields: Vec<FieldDescription>,
     pub documentation: Option<ErrorDocumentation>,
     pub bindings: BTreeMap<LanguageName, TargetLanguageType>,
+    #[serde(default)]
+    pub visibility: Visibility,
     pub origins: Origins,
 }
 
@@ -136,9 +162,15 @@ impl ErrorHierarchy {
     }
 
     pub fn deserialize(serialized_model: &str) -> ErrorHierarchy {
-        let wrapped: WrappedErrorHierarchy = serde_json::from_str(serialized_model).expect(
+        Self::try_deserialize(serialized_model).expect(
             "Impossible to parse error hierarchy dump -- have you changed the dump JSON file?",
-        );
-        wrapped.model
+        )
+    }
+
+    /// Parses a dump of the error hierarchy, e.g. the file
+    /// `resources/error-model-dump.json` of a generated crate.
+    pub fn try_deserialize(serialized_model: &str) -> Result<ErrorHierarchy, serde_json::Error> {
+        let wrapped: WrappedErrorHierarchy = serde_json::from_str(serialized_model)?;
+        Ok(wrapped.model)
     }
 }
@@ -0,0 +1,27 @@
+//!
+//! Documentation of errors in a form that can be baked into the generated code
+//! as `'static` tables. Unlike `ErrorHierarchy`, these types require neither
+//! allocation nor deserialization, so they are available in `no_std`.
+//!
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ErrorDocumentation {
+    pub description: &'static str,
+    pub summary: &'static str,
+    pub likely_causes: &'static [LikelyCause],
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LikelyCause {
+    pub cause: &'static str,
+    pub fixes: &'static [&'static str],
+    pub report: &'static str,
+    pub owner: Option<VersionedOwner>,
+    pub references: &'static [&'static str],
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VersionedOwner {
+    pub name: &'static str,
+    pub version: &'static str,
+}
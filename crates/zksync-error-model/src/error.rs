@@ -54,4 +54,38 @@ Parent domain:
         Box<ComponentMetadata>,
         Box<DomainMetadata>,
     ),
+    #[error(
+        "Invalid conversion from {source_type} to the error {error} of component {component}: {reason}"
+    )]
+    InvalidConversion {
+        source_type: String,
+        component: String,
+        error: String,
+        reason: String,
+    },
+    #[error(
+        "Types {first_type} and {second_type} have conflicting requirements in language {language}: {reason}"
+    )]
+    ConflictingTypeRequirements {
+        language: String,
+        first_type: String,
+        second_type: String,
+        reason: String,
+    },
+    #[error("Filter entry {0} matches no domain or component.")]
+    UnknownFilterEntry(String),
+    #[error(
+        "Field {field} of the error {error} has the type {type}, which is dropped by the filter."
+    )]
+    DroppedTypeReferenced {
+        error: String,
+        field: String,
+        r#type: String,
+    },
+    #[error("Public error {error} exposes internal errors of {type} through its field {field}.")]
+    InternalTypeExposed {
+        error: String,
+        field: String,
+        r#type: String,
+    },
 }
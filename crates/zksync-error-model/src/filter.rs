@@ -0,0 +1,143 @@
+//!
+//! Selection of a subset of domains and components, e.g. to generate a
+//! standalone crate or documentation for a single domain.
+//!
+
+use std::collections::BTreeSet;
+
+use crate::error::ModelValidationError;
+use crate::inner::{ComponentMetadata, DomainMetadata, Model};
+
+///
+/// Lists of domains and components to keep in, or drop from, the model.
+///
+/// Domains are matched by name or identifier encoding, e.g. `Core` or `core`.
+/// Components are matched by name, e.g. `Sequencer`, or by the identifier
+/// encodings of their domain and of their own, e.g. `core-seq`.
+///
+/// Empty include lists keep everything. A domain left without components is
+/// dropped. Codes of the remaining domains, components and errors do not
+/// change.
+///
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ModelFilter {
+    pub include_domains: Vec<String>,
+    pub exclude_domains: Vec<String>,
+    pub include_components: Vec<String>,
+    pub exclude_components: Vec<String>,
+}
+
+fn domain_matches(domain: &DomainMetadata, pattern: &str) -> bool {
+    domain.identifier.name == pattern || domain.identifier.encoding == pattern
+}
+
+fn component_matches(component: &ComponentMetadata, pattern: &str) -> bool {
+    component.identifier.name == pattern
+        || format!(
+            "{}-{}",
+            component.domain.identifier.encoding, component.identifier.encoding
+        ) == pattern
+}
+
+impl ModelFilter {
+    pub fn is_empty(&self) -> bool {
+        self.include_domains.is_empty()
+            && self.exclude_domains.is_empty()
+            && self.include_components.is_empty()
+            && self.exclude_components.is_empty()
+    }
+
+    fn keeps_domain(&self, domain: &DomainMetadata) -> bool {
+        (self.include_domains.is_empty()
+            || self
+                .include_domains
+                .iter()
+                .any(|p| domain_matches(domain, p)))
+            && !self
+                .exclude_domains
+                .iter()
+                .any(|p| domain_matches(domain, p))
+    }
+
+    fn keeps_component(&self, component: &ComponentMetadata) -> bool {
+        (self.include_components.is_empty()
+            || self
+                .include_components
+                .iter()
+                .any(|p| component_matches(component, p)))
+            && !self
+                .exclude_components
+                .iter()
+                .any(|p| component_matches(component, p))
+    }
+
+    /// Ensures every entry of the filter refers to an existing domain or
+    /// component, so that a typo does not silently select nothing.
+    fn ensure_entries_known(&self, model: &Model) -> Result<(), ModelValidationError> {
+        for pattern in self.include_domains.iter().chain(&self.exclude_domains) {
+            if !model
+                .domains
+                .values()
+                .any(|domain| domain_matches(&domain.meta, pattern))
+            {
+                return Err(ModelValidationError::UnknownFilterEntry(pattern.clone()));
+            }
+        }
+        for pattern in self
+            .include_components
+            .iter()
+            .chain(&self.exclude_components)
+        {
+            if !model
+                .components()
+                .any(|component| component_matches(&component.meta, pattern))
+            {
+                return Err(ModelValidationError::UnknownFilterEntry(pattern.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+///
+/// Drops the domains and components not selected by the filter, together
+/// with the types of their errors. Fails if an error that remains has a field
+/// of a type that was dropped.
+///
+pub fn filter(model: &Model, filter: &ModelFilter) -> Result<Model, ModelValidationError> {
+    filter.ensure_entries_known(model)?;
+
+    let mut result = model.clone();
+    let mut dropped_types = BTreeSet::new();
+
+    result.domains.retain(|_, domain| {
+        let keep_domain = filter.keeps_domain(&domain.meta);
+        domain.components.retain(|_, component| {
+            let keep = keep_domain && filter.keeps_component(&component.meta);
+            if !keep {
+                dropped_types.insert(component.meta.identifier.name.clone());
+            }
+            keep
+        });
+        let keep = !domain.components.is_empty();
+        if !keep {
+            dropped_types.insert(domain.meta.identifier.name.clone());
+        }
+        keep
+    });
+
+    for error in result.components().flat_map(|component| &component.errors) {
+        for field in &error.fields {
+            if dropped_types.contains(&field.r#type) {
+                return Err(ModelValidationError::DroppedTypeReferenced {
+                    error: error.name.clone(),
+                    field: field.name.clone(),
+                    r#type: field.r#type.clone(),
+                });
+            }
+        }
+    }
+    result.types.retain(|name, _| !dropped_types.contains(name));
+
+    Ok(result)
+}
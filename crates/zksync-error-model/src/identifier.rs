@@ -1,4 +1,11 @@
-use crate::inner::{ErrorCode, ErrorDescription};
+use crate::inner::{ComponentCode, DomainCode, ErrorCode, ErrorDescription};
+
+/// Encodes the codes of an error, its component and its domain into a single
+/// number, the same as `Identifier::encode` of the generated Rust crates:
+/// `domain * 10000 + component * 1000 + error`.
+pub fn encode_code(domain: DomainCode, component: ComponentCode, error: ErrorCode) -> u32 {
+    domain * 10000 + component * 1000 + error
+}
 
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize)]
 pub struct PublicErrorIdentifier {
@@ -30,4 +37,13 @@ impl ErrorDescription {
             code: self.code,
         }
     }
+
+    /// Code of the error encoded with [`encode_code`].
+    pub fn encoded_code(&self) -> u32 {
+        encode_code(
+            self.domain.identifier.code,
+            self.component.identifier.code,
+            self.code,
+        )
+    }
 }
@@ -22,6 +22,44 @@ pub type Origins = Vec<String>;
 #[derive(Debug, Default, Eq, PartialEq, Clone, serde::Serialize)]
 pub struct FullyQualifiedTargetLanguageType {
     pub expression: String,
+    /// Paths to import for `expression` to resolve, e.g. `zksync_basic_types::H256`.
+    pub imports: Vec<String>,
+    /// Package that provides the type.
+    pub dependency: Option<TypeDependency>,
+    /// Traits implemented by the type, allowing to derive them for the
+    /// errors that contain it, e.g. `Hash`.
+    pub implements: Vec<String>,
+    /// Expression of a property-testing strategy generating values of the
+    /// type, e.g. `any::<u8>().prop_map(Box::new)`.
+    pub strategy: Option<String>,
+    /// Expression converting `value`, a value decoded from the Solidity ABI,
+    /// into the type, e.g. `Box::new(H256::from(value.0))`.
+    pub from_abi: Option<String>,
+    /// Expression converting `value`, a reference to a value of the type,
+    /// into its protobuf representation, e.g. `value.as_bytes().to_vec()`.
+    pub to_proto: Option<String>,
+    /// Expression converting `value`, a decoded protobuf field, into a
+    /// `Result` of the type, e.g. `U256::from_dec_str(&value).map(Box::new)`.
+    pub from_proto: Option<String>,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Clone, serde::Serialize)]
+pub struct TypeDependency {
+    /// Name of the package, e.g. `zksync_basic_types`.
+    pub name: String,
+    pub version: String,
+    pub features: Vec<String>,
+    /// The type supports serialization only if the package is built with its
+    /// feature `serde`.
+    pub use_serde: bool,
+}
+
+impl TypeDependency {
+    /// Name of the package as it appears in paths, e.g. `zksync_basic_types`
+    /// for the package `zksync-basic-types`.
+    pub fn crate_name(&self) -> String {
+        self.name.replace('-', "_")
+    }
 }
 #[derive(Debug, Default, Eq, PartialEq, Clone, serde::Serialize)]
 pub struct TargetLanguageType {
@@ -66,11 +104,55 @@ impl Model {
     }
 }
 
+///
+/// Whether a domain, component or error is a part of the public API.
+///
+/// Internal items are implementation details: they are generated as usual, but
+/// may be hidden from the published documentation and are not covered by the
+/// compatibility guarantees.
+///
+#[derive(
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Clone,
+    Copy,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    #[default]
+    Public,
+    Internal,
+}
+
+impl Visibility {
+    pub fn is_public(self) -> bool {
+        self == Visibility::Public
+    }
+}
+
+///
+/// Extra derives and attributes of a generated type or variant, e.g.
+/// `Hash` and `serde(tag = "kind")`.
+///
+#[derive(Debug, Default, Eq, PartialEq, Clone, serde::Serialize)]
+pub struct Annotations {
+    pub derives: Vec<String>,
+    pub attributes: Vec<String>,
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize)]
 pub struct DomainMetadata {
     pub identifier: domain::Identifier,
     pub bindings: BTreeMap<LanguageName, String>,
+    pub annotations: BTreeMap<LanguageName, Annotations>,
     pub description: String,
+    pub visibility: Visibility,
     pub origins: Origins,
 }
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize)]
@@ -84,14 +166,51 @@ pub struct ComponentMetadata {
     pub identifier: component::Identifier,
     pub domain: Rc<DomainMetadata>,
     pub bindings: BTreeMap<LanguageName, String>,
+    pub annotations: BTreeMap<LanguageName, Annotations>,
     pub description: String,
+    pub visibility: Visibility,
     pub origins: Origins,
 }
 
+impl ComponentMetadata {
+    /// Visibility of the component, taking its domain into account.
+    pub fn effective_visibility(&self) -> Visibility {
+        self.visibility.max(self.domain.visibility)
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize)]
 pub struct ComponentDescription {
     pub meta: Rc<ComponentMetadata>,
     pub errors: Vec<ErrorDescription>,
+    pub conversions: Vec<ForeignConversion>,
+}
+
+///
+/// Conversion from a type defined outside of the hierarchy, e.g.
+/// `std::io::Error`, to one of the errors of a component.
+///
+#[derive(Debug, Eq, PartialEq, Clone, serde::Serialize)]
+pub struct ForeignConversion {
+    pub bindings: BTreeMap<LanguageName, FullyQualifiedTargetLanguageType>,
+    pub error: ErrorName,
+    pub fields: BTreeMap<FieldName, FieldInitializer>,
+    /// Cargo feature or a similar switch that the conversion depends on.
+    pub feature: Option<String>,
+}
+
+///
+/// Describes how to fill a field of the target error from the converted value.
+///
+#[derive(Debug, Eq, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldInitializer {
+    /// Formatted value, as if printed with `{}`.
+    Display,
+    /// Formatted value, as if printed with `{:?}`.
+    Debug,
+    /// Default value of the field type.
+    Default,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize)]
@@ -104,9 +223,18 @@ pub struct ErrorDescription {
     pub fields: Vec<FieldDescription>,
     pub documentation: Option<ErrorDocumentation>,
     pub bindings: BTreeMap<LanguageName, TargetLanguageType>,
+    pub annotations: BTreeMap<LanguageName, Annotations>,
+    pub visibility: Visibility,
     pub origins: Origins,
 }
 
+impl ErrorDescription {
+    /// Visibility of the error, taking its component and domain into account.
+    pub fn effective_visibility(&self) -> Visibility {
+        self.visibility.max(self.component.effective_visibility())
+    }
+}
+
 impl From<TargetLanguageType> for FullyQualifiedTargetLanguageType {
     fn from(value: TargetLanguageType) -> Self {
         Self::from(value.expression.as_str())
@@ -117,6 +245,7 @@ impl From<&str> for FullyQualifiedTargetLanguageType {
     fn from(value: &str) -> Self {
         FullyQualifiedTargetLanguageType {
             expression: value.into(),
+            ..Default::default()
         }
     }
 }
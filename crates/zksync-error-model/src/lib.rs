@@ -1,4 +1,5 @@
 pub mod error;
+pub mod filter;
 pub mod identifier;
 pub mod inner;
 pub mod link;
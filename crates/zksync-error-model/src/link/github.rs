@@ -76,6 +76,43 @@ impl GithubLink {
             }
         }
     }
+
+    /// Parses a URL produced by [`GithubLink::to_url`].
+    pub fn from_url(url: &str) -> Option<Self> {
+        let rest = url.strip_prefix("https://raw.githubusercontent.com/")?;
+        let mut parts = rest.splitn(3, '/');
+        let repo = format!("{}/{}", parts.next()?, parts.next()?);
+        let rest = parts.next()?;
+        let link = match rest.strip_prefix("refs/heads/") {
+            Some(rest) => {
+                let (branch, path) = rest.split_once('/')?;
+                Self::new_with_branch(repo, path.to_owned(), BranchName(branch.to_owned()))
+            }
+            None => {
+                let (commit, path) = rest.split_once('/')?;
+                Self::new_with_commit(repo, path.to_owned(), CommitHash(commit.to_owned()))
+            }
+        };
+        Some(link)
+    }
+
+    /// URL of the page of the file on GitHub: the editor for branches, the
+    /// viewer for commits.
+    pub fn to_edit_url(&self) -> String {
+        let Self {
+            repo,
+            path,
+            reference,
+        } = self;
+        match reference {
+            ReferenceType::Branch { branch } => {
+                format!("https://github.com/{repo}/edit/{}/{path}", branch.0)
+            }
+            ReferenceType::Commit { commit } => {
+                format!("https://github.com/{repo}/blob/{}/{path}", commit.0)
+            }
+        }
+    }
 }
 
 impl fmt::Display for GithubLink {
@@ -1,10 +1,11 @@
 use crate::identifier::PublicErrorIdentifier;
+use crate::identifier::encode_code;
 use crate::inner::component::Identifier as ComponentIdentifier;
 use crate::inner::domain::Identifier as DomainIdentifier;
 
 use crate::inner::{
     ComponentName, DomainName, ErrorCode, ErrorMessageTemplate, ErrorName, FieldName, LanguageName,
-    Model, Origins, Semver, TypeName,
+    Model, Origins, Semver, TypeName, Visibility,
 };
 use std::collections::BTreeMap;
 
@@ -33,6 +34,7 @@ pub struct DomainMetadata {
     pub bindings: BTreeMap<LanguageName, String>,
     pub identifier: DomainIdentifier,
     pub description: String,
+    pub visibility: Visibility,
     pub origins: Origins,
 }
 
@@ -50,6 +52,7 @@ pub struct ComponentMetadata {
     pub bindings: BTreeMap<LanguageName, String>,
     pub identifier: ComponentIdentifier,
     pub description: String,
+    pub visibility: Visibility,
     pub origins: Origins,
 }
 
@@ -64,6 +67,7 @@ pub struct ErrorDescription {
     pub fields: Vec<FieldDescription>,
     pub documentation: Option<ErrorDocumentation>,
     pub bindings: BTreeMap<LanguageName, TargetLanguageType>,
+    pub visibility: Visibility,
     pub origins: Origins,
 }
 
@@ -101,8 +105,10 @@ fn translate_domain_metadata(
 ) -> DomainMetadata {
     let crate::inner::DomainMetadata {
         bindings,
+        annotations: _,
         identifier,
         description,
+        visibility,
         origins,
     } = meta.clone();
     DomainMetadata {
@@ -110,16 +116,20 @@ fn translate_domain_metadata(
         identifier,
         description,
         components,
+        visibility,
         origins,
     }
 }
 
 fn translate_component_metadata(meta: &crate::inner::ComponentMetadata) -> ComponentMetadata {
+    let visibility = meta.effective_visibility();
     let crate::inner::ComponentMetadata {
         bindings,
+        annotations: _,
         identifier,
         description,
         domain,
+        visibility: _,
         origins,
     } = meta.clone();
     ComponentMetadata {
@@ -127,6 +137,7 @@ fn translate_component_metadata(meta: &crate::inner::ComponentMetadata) -> Compo
         identifier,
         description,
         domain_name: domain.identifier.name.to_string(),
+        visibility,
         origins,
     }
 }
@@ -144,6 +155,8 @@ fn translate_error(meta: &crate::inner::ErrorDescription) -> ErrorDescription {
         fields,
         documentation,
         bindings,
+        annotations: _,
+        visibility: _,
         origins,
     } = meta;
     let new_bindings: BTreeMap<_, _> = bindings
@@ -173,6 +186,7 @@ fn translate_error(meta: &crate::inner::ErrorDescription) -> ErrorDescription {
         fields: fields.iter().map(translate_field).collect(),
         documentation: documentation.clone().map(|d| translate_documentation(&d)),
         bindings: new_bindings,
+        visibility: meta.effective_visibility(),
         origins: origins.clone(),
     }
 }
@@ -270,3 +284,46 @@ pub fn flatten(model: &Model) -> UnpackedModel {
 
     result
 }
+
+impl UnpackedModel {
+    ///
+    /// Code of an error of the model encoded with [`encode_code`].
+    ///
+    pub fn encoded_code(&self, error: &ErrorDescription) -> u32 {
+        let domain = &self.domains[&error.domain].identifier;
+        let component = &self.components[&error.component].identifier;
+        encode_code(domain.code, component.code, error.code)
+    }
+
+    ///
+    /// Drops internal domains, components and errors, together with the types
+    /// of internal domain and component errors.
+    ///
+    pub fn without_internal(mut self) -> Self {
+        let internal_names: Vec<String> = self
+            .domains
+            .values()
+            .map(|domain| (&domain.identifier.name, domain.visibility))
+            .chain(
+                self.components
+                    .values()
+                    .map(|component| (&component.identifier.name, component.visibility)),
+            )
+            .filter(|(_, visibility)| !visibility.is_public())
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        self.domains
+            .retain(|_, domain| domain.visibility.is_public());
+        self.components
+            .retain(|_, component| component.visibility.is_public());
+        self.errors.retain(|_, error| error.visibility.is_public());
+        self.types.retain(|name, _| !internal_names.contains(name));
+        for domain in self.domains.values_mut() {
+            domain
+                .components
+                .retain(|component| !internal_names.contains(component));
+        }
+        self
+    }
+}
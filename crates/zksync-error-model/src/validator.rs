@@ -1,4 +1,9 @@
-use crate::inner::{ComponentDescription, DomainDescription};
+use std::collections::BTreeMap;
+
+use crate::inner::{
+    ComponentDescription, DomainDescription, FieldInitializer, ForeignConversion,
+    FullyQualifiedTargetLanguageType,
+};
 
 use super::error::ModelValidationError;
 use super::inner::Model;
@@ -29,11 +34,14 @@ where
 ///
 pub fn validate(model: &Model) -> Result<(), ModelValidationError> {
     ensure_unique_domains(model)?;
+    ensure_consistent_type_requirements(model)?;
+    ensure_public_errors_expose_no_internals(model)?;
 
     for domain in model.domains.values() {
         ensure_unique_components(domain)?;
         for component in domain.components.values() {
             ensure_unique_errors(component)?;
+            ensure_valid_conversions(model, component)?;
         }
     }
     Ok(())
@@ -90,3 +98,187 @@ fn ensure_unique_errors(component: &ComponentDescription) -> Result<(), ModelVal
         Ok(())
     }
 }
+
+/// Rust types that `display` and `debug` field initializers, producing a
+/// formatted `String`, may initialize.
+const STRING_TYPES: &[&str] = &["String", "std::string::String", "alloc::string::String"];
+
+fn ensure_valid_conversions(
+    model: &Model,
+    component: &ComponentDescription,
+) -> Result<(), ModelValidationError> {
+    let invalid = |conversion: &ForeignConversion, reason: String| {
+        let source_type = conversion
+            .bindings
+            .values()
+            .map(|binding| binding.expression.clone())
+            .collect::<Vec<_>>()
+            .join(", ");
+        ModelValidationError::InvalidConversion {
+            source_type,
+            component: component.meta.identifier.name.clone(),
+            error: conversion.error.clone(),
+            reason,
+        }
+    };
+
+    for conversion in &component.conversions {
+        let Some(error) = component.errors.iter().find(|e| e.name == conversion.error) else {
+            return Err(invalid(
+                conversion,
+                "the component has no such error".into(),
+            ));
+        };
+        for field in conversion.fields.keys() {
+            if !error.fields.iter().any(|f| &f.name == field) {
+                return Err(invalid(
+                    conversion,
+                    format!("the error has no field `{field}`"),
+                ));
+            }
+        }
+        for field in &error.fields {
+            let Some(initializer) = conversion.fields.get(&field.name) else {
+                return Err(invalid(
+                    conversion,
+                    format!("the field `{}` is not initialized", field.name),
+                ));
+            };
+            let formatted = matches!(
+                initializer,
+                FieldInitializer::Display | FieldInitializer::Debug
+            );
+            let rust_type = model
+                .types
+                .get(&field.r#type)
+                .and_then(|typ| typ.bindings.get("rust"))
+                .map(|binding| binding.expression.as_str());
+            if formatted && !rust_type.is_some_and(|typ| STRING_TYPES.contains(&typ)) {
+                return Err(invalid(
+                    conversion,
+                    format!(
+                        "the field `{}` of type `{}` is initialized with a formatted string, but the type does not bind to `String` in Rust",
+                        field.name, field.r#type
+                    ),
+                ));
+            }
+        }
+    }
+
+    for (language, _) in component.conversions.iter().flat_map(|c| c.bindings.iter()) {
+        if let Some((c1, _)) = find_duplicate_by(
+            component
+                .conversions
+                .iter()
+                .filter(|c| c.bindings.contains_key(language)),
+            |c| &c.bindings[language],
+        ) {
+            return Err(invalid(
+                c1,
+                format!("multiple conversions from the same {language} type"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Public errors are a part of the API, so their fields must not hold errors
+/// of internal domains or components.
+fn ensure_public_errors_expose_no_internals(model: &Model) -> Result<(), ModelValidationError> {
+    let internal_error_types: Vec<&str> = model
+        .domains
+        .values()
+        .filter(|domain| !domain.meta.visibility.is_public())
+        .map(|domain| domain.meta.identifier.name.as_str())
+        .chain(
+            model
+                .components()
+                .filter(|component| !component.meta.effective_visibility().is_public())
+                .map(|component| component.meta.identifier.name.as_str()),
+        )
+        .collect();
+
+    for error in model
+        .errors()
+        .filter(|error| error.effective_visibility().is_public())
+    {
+        if let Some(field) = error
+            .fields
+            .iter()
+            .find(|field| internal_error_types.contains(&field.r#type.as_str()))
+        {
+            return Err(ModelValidationError::InternalTypeExposed {
+                error: error.name.clone(),
+                field: field.name.clone(),
+                r#type: field.r#type.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Types bound to the same package must agree on its version, and imports of
+/// different types must not bring the same name into scope.
+fn ensure_consistent_type_requirements(model: &Model) -> Result<(), ModelValidationError> {
+    let mut bindings: BTreeMap<&str, Vec<(&str, &FullyQualifiedTargetLanguageType)>> =
+        BTreeMap::new();
+    for typ in model.types.values() {
+        for (language, binding) in &typ.bindings {
+            bindings
+                .entry(language)
+                .or_default()
+                .push((&typ.name, binding));
+        }
+    }
+
+    for (language, bindings) in bindings {
+        let conflict = |first_type: &str, second_type: &str, reason: String| {
+            ModelValidationError::ConflictingTypeRequirements {
+                language: language.to_owned(),
+                first_type: first_type.to_owned(),
+                second_type: second_type.to_owned(),
+                reason,
+            }
+        };
+
+        let mut versions: BTreeMap<&str, (&str, &str)> = BTreeMap::new();
+        let mut imported_names: BTreeMap<&str, (&str, &str)> = BTreeMap::new();
+        for (type_name, binding) in bindings {
+            if let Some(dependency) = &binding.dependency {
+                match versions.get(dependency.name.as_str()) {
+                    Some((other_type, version)) if *version != dependency.version => {
+                        return Err(conflict(
+                            other_type,
+                            type_name,
+                            format!(
+                                "package `{}` is required in versions `{version}` and `{}`",
+                                dependency.name, dependency.version
+                            ),
+                        ));
+                    }
+                    Some(_) => {}
+                    None => {
+                        versions.insert(&dependency.name, (type_name, &dependency.version));
+                    }
+                }
+            }
+            for import in &binding.imports {
+                let name = import.rsplit("::").next().unwrap_or(import).trim();
+                match imported_names.get(name) {
+                    Some((other_type, other_import)) if *other_import != import => {
+                        return Err(conflict(
+                            other_type,
+                            type_name,
+                            format!("imports `{other_import}` and `{import}` clash"),
+                        ));
+                    }
+                    Some(_) => {}
+                    None => {
+                        imported_names.insert(name, (type_name, import));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}